@@ -5,8 +5,8 @@
 use std::ops::Deref;
 use std::time::Duration;
 
-use bevy_app::{App, CoreSet, Plugin};
-use bevy_asset::{AddAsset, Assets, Handle};
+use bevy_app::{App, CoreSet, Plugin, PluginDependency};
+use bevy_asset::{AddAsset, AssetPlugin, Assets, Handle};
 use bevy_core::Name;
 use bevy_ecs::prelude::*;
 use bevy_hierarchy::{Children, Parent};
@@ -556,4 +556,8 @@ impl Plugin for AnimationPlugin {
                     .before(TransformSystem::TransformPropagate),
             );
     }
+
+    fn dependencies(&self) -> Vec<PluginDependency> {
+        vec![PluginDependency::required::<AssetPlugin>()]
+    }
 }
@@ -1,4 +1,7 @@
-use crate::{CoreSchedule, CoreSet, Plugin, PluginGroup, StartupSet};
+use crate::{
+    sub_app::SubAppThread, CoreSchedule, CoreSet, Plugin, PluginDependencyError, PluginGroup,
+    StartupSet, SubAppCadence,
+};
 pub use bevy_derive::AppLabel;
 use bevy_ecs::{
     prelude::*,
@@ -9,7 +12,7 @@ use bevy_ecs::{
     },
 };
 use bevy_utils::{tracing::debug, HashMap, HashSet};
-use std::fmt::Debug;
+use std::{any::TypeId, fmt::Debug, sync::Arc};
 
 #[cfg(feature = "trace")]
 use bevy_utils::tracing::info_span;
@@ -75,7 +78,8 @@ pub struct App {
     /// This is initially set to [`CoreSchedule::Outer`].
     pub outer_schedule_label: BoxedScheduleLabel,
     sub_apps: HashMap<AppLabelId, SubApp>,
-    plugin_registry: Vec<Box<dyn Plugin>>,
+    sub_app_threads: HashMap<AppLabelId, SubAppThread>,
+    plugin_registry: Vec<Arc<dyn Plugin>>,
     plugin_name_added: HashSet<String>,
     /// A private marker to prevent incorrect calls to `App::run()` from `Plugin::build()`
     is_building_plugin: bool,
@@ -95,6 +99,14 @@ impl Debug for App {
 /// This is useful for situations where data and data processing should be kept completely separate
 /// from the main application. The primary use of this feature in bevy is to enable pipelined rendering.
 ///
+/// An app can have any number of sub apps, identified by their [`AppLabel`]. By default, each one
+/// is extracted and run right after the main schedule on every [`App::update`]. Heavy simulations
+/// can instead run at their own [cadence](SubApp::with_cadence), or on their own
+/// [thread](SubApp::threaded) while the main app keeps updating. Data flows from the main world
+/// to the sub app in the [`extract`](SubApp::new) function and back in the
+/// [`sync_back`](SubApp::with_sync_back) function, or through typed
+/// [channels](App::add_extract_channel) that systems of both worlds can use at any time.
+///
 /// # Example
 ///
 /// ```rust
@@ -143,8 +155,17 @@ pub struct SubApp {
     /// A function that allows access to both the [`SubApp`] [`World`] and the main [`App`]. This is
     /// useful for moving data between the sub app and the main app.
     extract: Box<dyn Fn(&mut World, &mut App) + Send>,
+
+    /// A function that moves data from the sub app back to the main [`World`] after the sub app ran.
+    sync_back: Option<SyncBackFn>,
+
+    cadence: SubAppCadence,
+    frames_since_run: u32,
+    threaded: bool,
 }
 
+type SyncBackFn = Box<dyn Fn(&mut App, &mut World) + Send>;
+
 impl SubApp {
     /// Creates a new [`SubApp`].
     ///
@@ -156,9 +177,58 @@ impl SubApp {
         Self {
             app,
             extract: Box::new(extract),
+            sync_back: None,
+            cadence: SubAppCadence::EveryFrame,
+            frames_since_run: 0,
+            threaded: false,
         }
     }
 
+    /// Sets a function to move data from the sub app back to the main [`World`].
+    ///
+    /// It is called after the sub app's schedule has run, before the next extraction. For a
+    /// [threaded](Self::threaded) sub app, this happens during the first [`App::update`] after the
+    /// sub app has finished running.
+    #[must_use]
+    pub fn with_sync_back(
+        mut self,
+        sync_back: impl Fn(&mut App, &mut World) + Send + 'static,
+    ) -> Self {
+        self.sync_back = Some(Box::new(sync_back));
+        self
+    }
+
+    /// Sets how often the sub app is extracted and run by [`App::update`].
+    #[must_use]
+    pub fn with_cadence(mut self, cadence: SubAppCadence) -> Self {
+        self.cadence = cadence;
+        self
+    }
+
+    /// Runs the sub app's schedule on a dedicated thread.
+    ///
+    /// After being extracted, the sub app is moved to its thread and [`App::update`] returns
+    /// without waiting for it. The sub app is moved back once its schedule has finished, during a
+    /// later update, and isn't extracted again until then. While it runs,
+    /// [`App::get_sub_app`] returns an error; [`App::get_sub_app_mut`] waits for it to finish.
+    ///
+    /// On `wasm32`, threaded sub apps run on the main thread.
+    #[must_use]
+    pub fn threaded(mut self) -> Self {
+        self.threaded = true;
+        self
+    }
+
+    /// Returns how often the sub app is run.
+    pub fn cadence(&self) -> SubAppCadence {
+        self.cadence
+    }
+
+    /// Returns `true` if the sub app runs on its own thread.
+    pub fn is_threaded(&self) -> bool {
+        self.threaded && cfg!(not(target_arch = "wasm32"))
+    }
+
     /// Runs the `SubApp`'s default schedule.
     pub fn run(&mut self) {
         self.app
@@ -171,6 +241,30 @@ impl SubApp {
     pub fn extract(&mut self, main_world: &mut World) {
         (self.extract)(main_world, &mut self.app);
     }
+
+    /// Moves data from this sub-app back to the main world.
+    pub fn sync_back(&mut self, main_world: &mut World) {
+        if let Some(sync_back) = &self.sync_back {
+            sync_back(&mut self.app, main_world);
+        }
+    }
+
+    /// Advances the frame counter of the sub app, returning `true` if it should run this frame.
+    fn tick(&mut self) -> bool {
+        match self.cadence {
+            SubAppCadence::EveryFrame => true,
+            SubAppCadence::EveryNthFrame(frames) => {
+                self.frames_since_run += 1;
+                if self.frames_since_run >= frames.max(1) {
+                    self.frames_since_run = 0;
+                    true
+                } else {
+                    false
+                }
+            }
+            SubAppCadence::Manual => false,
+        }
+    }
 }
 
 impl Debug for SubApp {
@@ -185,13 +279,8 @@ impl Debug for SubApp {
 
 impl Default for App {
     fn default() -> Self {
-        let mut app = App::empty();
-        #[cfg(feature = "bevy_reflect")]
-        app.init_resource::<AppTypeRegistry>();
-
-        app.add_default_schedules();
-
-        app.add_event::<AppExit>();
+        #[allow(unused_mut)]
+        let mut app = App::with_default_structure();
 
         #[cfg(feature = "bevy_ci_testing")]
         {
@@ -211,6 +300,20 @@ impl App {
         App::default()
     }
 
+    /// Creates a new [`App`] with the default structure of [`App::new`], without the setup of
+    /// the `bevy_ci_testing` feature.
+    pub(crate) fn with_default_structure() -> App {
+        let mut app = App::empty();
+        #[cfg(feature = "bevy_reflect")]
+        app.init_resource::<AppTypeRegistry>();
+
+        app.add_default_schedules();
+
+        app.add_event::<AppExit>();
+
+        app
+    }
+
     /// Creates a new empty [`App`] with minimal default configuration.
     ///
     /// This constructor should be used if you wish to provide custom scheduling, exit handling, cleanup, etc.
@@ -221,6 +324,7 @@ impl App {
             world,
             runner: Box::new(run_once),
             sub_apps: HashMap::default(),
+            sub_app_threads: HashMap::default(),
             plugin_registry: Vec::default(),
             plugin_name_added: Default::default(),
             default_schedule_label: Box::new(CoreSchedule::Main),
@@ -247,14 +351,105 @@ impl App {
             let _bevy_frame_update_span = info_span!("main app").entered();
             self.world.run_schedule_ref(&*self.outer_schedule_label);
         }
-        for (_label, sub_app) in self.sub_apps.iter_mut() {
+        self.update_sub_apps();
+
+        self.world.clear_trackers();
+    }
+
+    fn update_sub_apps(&mut self) {
+        // collect the threaded sub apps that have finished running
+        for (label, thread) in self.sub_app_threads.iter_mut() {
+            if let Some(mut sub_app) = thread.try_receive() {
+                sub_app.sync_back(&mut self.world);
+                self.sub_apps.insert(*label, sub_app);
+            }
+        }
+
+        let mut dispatched = Vec::new();
+        for (label, sub_app) in self.sub_apps.iter_mut() {
+            if !sub_app.tick() {
+                continue;
+            }
             #[cfg(feature = "trace")]
-            let _sub_app_span = info_span!("sub app", name = ?_label).entered();
+            let _sub_app_span = info_span!("sub app", name = ?label).entered();
             sub_app.extract(&mut self.world);
-            sub_app.run();
+            if sub_app.is_threaded() {
+                dispatched.push(*label);
+            } else {
+                sub_app.run();
+                sub_app.sync_back(&mut self.world);
+            }
         }
 
-        self.world.clear_trackers();
+        for label in dispatched {
+            let sub_app = self.sub_apps.remove(&label).unwrap();
+            self.sub_app_threads
+                .entry(label)
+                .or_insert_with(|| SubAppThread::spawn(format!("sub app {}", label.as_str())))
+                .send(sub_app);
+        }
+    }
+
+    /// Waits for the sub app with the given label to finish running on its thread, if it is
+    /// running, and moves it back into this app.
+    fn reclaim_sub_app(&mut self, label: AppLabelId) {
+        if let Some(mut sub_app) = self
+            .sub_app_threads
+            .get_mut(&label)
+            .and_then(SubAppThread::receive)
+        {
+            sub_app.sync_back(&mut self.world);
+            self.sub_apps.insert(label, sub_app);
+        }
+    }
+
+    /// Extracts and runs a sub app immediately, on the current thread, then syncs its data back.
+    ///
+    /// This is how sub apps with a [`SubAppCadence::Manual`] cadence are run, but it can be used
+    /// with any sub app. If the sub app is running on its own thread, this waits for it to finish
+    /// first.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `SubApp` doesn't exist.
+    pub fn run_sub_app(&mut self, label: impl AppLabel) -> &mut Self {
+        let label = label.as_label();
+        self.reclaim_sub_app(label);
+        let Some(sub_app) = self.sub_apps.get_mut(&label) else {
+            panic!("Sub-App with label '{:?}' does not exist", label.as_str());
+        };
+        sub_app.extract(&mut self.world);
+        sub_app.run();
+        sub_app.sync_back(&mut self.world);
+        self
+    }
+
+    /// Creates a [`world_channel`](crate::world_channel) from the main world to a sub app.
+    ///
+    /// The [`WorldSender<T>`](crate::WorldSender) is inserted as a resource of the main world, and
+    /// the [`WorldReceiver<T>`](crate::WorldReceiver) as a resource of the sub app.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `SubApp` doesn't exist.
+    pub fn add_extract_channel<T: Send + 'static>(&mut self, label: impl AppLabel) -> &mut Self {
+        let (sender, receiver) = crate::world_channel::<T>();
+        self.sub_app_mut(label).insert_resource(receiver);
+        self.insert_resource(sender)
+    }
+
+    /// Creates a [`world_channel`](crate::world_channel) from a sub app to the main world.
+    ///
+    /// The [`WorldSender<T>`](crate::WorldSender) is inserted as a resource of the sub app, and
+    /// the [`WorldReceiver<T>`](crate::WorldReceiver) as a resource of the main world.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `SubApp` doesn't exist.
+    pub fn add_sync_back_channel<T: Send + 'static>(&mut self, label: impl AppLabel) -> &mut Self {
+        let (sender, receiver) = crate::world_channel::<T>();
+        self.sub_app_mut(label).insert_resource(sender);
+        self.insert_resource(receiver)
     }
 
     /// Starts the application by calling the app's [runner function](Self::set_runner).
@@ -298,15 +493,103 @@ impl App {
         (runner)(app);
     }
 
-    /// Run [`Plugin::setup`] for each plugin. This is usually called by [`App::run`], but can
-    /// be useful for situations where you want to use [`App::update`].
+    /// Validates the [dependencies](Plugin::dependencies) of every plugin, then runs
+    /// [`Plugin::finish`], [`Plugin::setup`] and [`Plugin::cleanup`] for each plugin, one phase
+    /// at a time. This is usually called by [`App::run`], but can be useful for situations where
+    /// you want to use [`App::update`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if [`validate_plugin_dependencies`](Self::validate_plugin_dependencies) reports any error.
     pub fn setup(&mut self) {
-        // temporarily remove the plugin registry to run each plugin's setup function on app.
-        let plugin_registry = std::mem::take(&mut self.plugin_registry);
-        for plugin in &plugin_registry {
-            plugin.setup(self);
+        if let Err(errors) = self.validate_plugin_dependencies() {
+            let errors: String = errors
+                .iter()
+                .map(|error| format!("\n  - {error}"))
+                .collect();
+            panic!("Invalid plugin dependencies:{errors}");
+        }
+
+        // Plugins added during one phase only take part in the following phases.
+        let phases: [fn(&dyn Plugin, &mut App); 3] = [
+            |plugin, app| plugin.finish(app),
+            |plugin, app| plugin.setup(app),
+            |plugin, app| plugin.cleanup(app),
+        ];
+        for phase in phases {
+            let plugins = self.plugin_registry.clone();
+            for plugin in &plugins {
+                phase(&**plugin, self);
+            }
+        }
+    }
+
+    /// Checks that the [dependencies](Plugin::dependencies) of every added [`Plugin`] were added
+    /// before it, and that no plugins depend on each other in a cycle.
+    ///
+    /// Returns every error found. This is called by [`App::setup`].
+    pub fn validate_plugin_dependencies(&self) -> Result<(), Vec<PluginDependencyError>> {
+        let dependencies: Vec<Vec<usize>> = self
+            .plugin_registry
+            .iter()
+            .map(|plugin| {
+                plugin
+                    .dependencies()
+                    .iter()
+                    .flat_map(|dependency| self.plugin_indices(dependency.plugin_type_id()))
+                    .collect()
+            })
+            .collect();
+
+        let mut errors = Vec::new();
+        let cycles = find_cycles(&dependencies);
+        let in_cycle: HashSet<usize> = cycles.iter().flatten().copied().collect();
+        for cycle in cycles {
+            errors.push(PluginDependencyError::Cycle {
+                plugins: cycle
+                    .iter()
+                    .map(|&index| self.plugin_registry[index].name().to_string())
+                    .collect(),
+            });
+        }
+
+        for (index, plugin) in self.plugin_registry.iter().enumerate() {
+            for dependency in plugin.dependencies() {
+                let dependency_indices: Vec<usize> =
+                    self.plugin_indices(dependency.plugin_type_id()).collect();
+                if dependency_indices.is_empty() {
+                    if !dependency.is_optional() {
+                        errors.push(PluginDependencyError::Missing {
+                            plugin: plugin.name().to_string(),
+                            dependency: dependency.name(),
+                        });
+                    }
+                } else if dependency_indices.iter().all(|&i| i > index)
+                    && !(in_cycle.contains(&index)
+                        && dependency_indices.iter().all(|i| in_cycle.contains(i)))
+                {
+                    errors.push(PluginDependencyError::AddedAfter {
+                        plugin: plugin.name().to_string(),
+                        dependency: dependency.name(),
+                    });
+                }
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
         }
-        self.plugin_registry = plugin_registry;
+    }
+
+    /// The indices in the plugin registry of the plugins of the given type.
+    fn plugin_indices(&self, type_id: TypeId) -> impl Iterator<Item = usize> + '_ {
+        self.plugin_registry
+            .iter()
+            .enumerate()
+            .filter(move |(_, plugin)| (***plugin).as_any().type_id() == type_id)
+            .map(|(index, _)| index)
     }
 
     /// Adds [`State<S>`] and [`NextState<S>`] resources, [`OnEnter`] and [`OnExit`] schedules
@@ -768,7 +1051,7 @@ impl App {
         self.is_building_plugin = true;
         plugin.build(self);
         self.is_building_plugin = false;
-        self.plugin_registry.push(plugin);
+        self.plugin_registry.push(plugin.into());
         Ok(self)
     }
 
@@ -892,6 +1175,59 @@ impl App {
         self
     }
 
+    /// Registers a reflected function under the given name in the
+    /// [`TypeRegistry`](bevy_reflect::TypeRegistry) resource.
+    ///
+    /// See [`bevy_reflect::TypeRegistry::register_function`].
+    #[cfg(feature = "bevy_reflect")]
+    pub fn register_function<Marker>(
+        &mut self,
+        name: impl Into<String>,
+        function: impl bevy_reflect::IntoFunction<Marker>,
+    ) -> &mut Self {
+        {
+            let registry = self.world.resource_mut::<AppTypeRegistry>();
+            registry.write().register_function(name, function);
+        }
+        self
+    }
+
+    /// Registers a reflected method of type `T` under the given name in the
+    /// [`TypeRegistry`](bevy_reflect::TypeRegistry) resource.
+    ///
+    /// # Example
+    /// ```rust
+    /// use bevy_app::App;
+    /// use bevy_reflect::Reflect;
+    ///
+    /// #[derive(Reflect, Default)]
+    /// struct Health(f32);
+    ///
+    /// impl Health {
+    ///     fn heal(&mut self, amount: f32) {
+    ///         self.0 += amount;
+    ///     }
+    /// }
+    ///
+    /// App::new()
+    ///     .register_type::<Health>()
+    ///     .register_method::<Health, _>("heal", Health::heal);
+    /// ```
+    ///
+    /// See [`bevy_reflect::TypeRegistry::register_method`].
+    #[cfg(feature = "bevy_reflect")]
+    pub fn register_method<T: bevy_reflect::Reflect, Marker>(
+        &mut self,
+        name: impl Into<String>,
+        method: impl bevy_reflect::IntoMethod<T, Marker>,
+    ) -> &mut Self {
+        {
+            let registry = self.world.resource_mut::<AppTypeRegistry>();
+            registry.write().register_method(name, method);
+        }
+        self
+    }
+
     /// Retrieves a `SubApp` stored inside this [`App`].
     ///
     /// # Panics
@@ -906,8 +1242,11 @@ impl App {
 
     /// Retrieves a `SubApp` inside this [`App`] with the given label, if it exists. Otherwise returns
     /// an [`Err`] containing the given label.
+    ///
+    /// If the sub app is running on its own thread, this waits for it to finish.
     pub fn get_sub_app_mut(&mut self, label: impl AppLabel) -> Result<&mut App, AppLabelId> {
         let label = label.as_label();
+        self.reclaim_sub_app(label);
         self.sub_apps
             .get_mut(&label)
             .map(|sub_app| &mut sub_app.app)
@@ -926,18 +1265,28 @@ impl App {
         }
     }
 
-    /// Inserts an existing sub app into the app
+    /// Inserts an existing sub app into the app, replacing any sub app with the same label.
     pub fn insert_sub_app(&mut self, label: impl AppLabel, sub_app: SubApp) {
-        self.sub_apps.insert(label.as_label(), sub_app);
+        let label = label.as_label();
+        // a replaced threaded sub app is dropped by its thread once it has finished running
+        self.sub_app_threads.remove(&label);
+        self.sub_apps.insert(label, sub_app);
     }
 
     /// Removes a sub app from the app. Returns [`None`] if the label doesn't exist.
+    ///
+    /// If the sub app is running on its own thread, this waits for it to finish.
     pub fn remove_sub_app(&mut self, label: impl AppLabel) -> Option<SubApp> {
-        self.sub_apps.remove(&label.as_label())
+        let label = label.as_label();
+        self.reclaim_sub_app(label);
+        self.sub_app_threads.remove(&label);
+        self.sub_apps.remove(&label)
     }
 
     /// Retrieves a `SubApp` inside this [`App`] with the given label, if it exists. Otherwise returns
     /// an [`Err`] containing the given label.
+    ///
+    /// A threaded sub app can't be accessed while it is running.
     pub fn get_sub_app(&self, label: impl AppLabel) -> Result<&App, impl AppLabel> {
         self.sub_apps
             .get(&label.as_label())
@@ -1008,6 +1357,48 @@ fn run_once(mut app: App) {
     app.update();
 }
 
+/// Finds the cycles in a dependency graph given as adjacency lists, each cycle being reported once.
+fn find_cycles(dependencies: &[Vec<usize>]) -> Vec<Vec<usize>> {
+    #[derive(Clone, Copy, PartialEq, Eq)]
+    enum Visit {
+        New,
+        InProgress,
+        Done,
+    }
+
+    fn visit(
+        node: usize,
+        dependencies: &[Vec<usize>],
+        state: &mut [Visit],
+        stack: &mut Vec<usize>,
+        cycles: &mut Vec<Vec<usize>>,
+    ) {
+        state[node] = Visit::InProgress;
+        stack.push(node);
+        for &dependency in &dependencies[node] {
+            match state[dependency] {
+                Visit::New => visit(dependency, dependencies, state, stack, cycles),
+                Visit::InProgress => {
+                    let start = stack.iter().position(|&n| n == dependency).unwrap();
+                    cycles.push(stack[start..].to_vec());
+                }
+                Visit::Done => {}
+            }
+        }
+        stack.pop();
+        state[node] = Visit::Done;
+    }
+
+    let mut state = vec![Visit::New; dependencies.len()];
+    let mut cycles = Vec::new();
+    for node in 0..dependencies.len() {
+        if state[node] == Visit::New {
+            visit(node, dependencies, &mut state, &mut Vec::new(), &mut cycles);
+        }
+    }
+    cycles
+}
+
 /// An event that indicates the [`App`] should exit. This will fully exit the app process at the
 /// start of the next tick of the schedule.
 ///
@@ -1023,7 +1414,12 @@ pub struct AppExit;
 
 #[cfg(test)]
 mod tests {
-    use crate::{App, Plugin};
+    use bevy_ecs::prelude::*;
+
+    use crate::{
+        App, Plugin, PluginDependency, PluginDependencyError, SubApp, SubAppCadence, WorldReceiver,
+        WorldSender,
+    };
 
     struct PluginA;
     impl Plugin for PluginA {
@@ -1077,4 +1473,253 @@ mod tests {
         }
         App::new().add_plugin(PluginRun);
     }
+
+    struct DependsOnA;
+    impl Plugin for DependsOnA {
+        fn build(&self, _app: &mut crate::App) {}
+        fn dependencies(&self) -> Vec<PluginDependency> {
+            vec![PluginDependency::required::<PluginA>()]
+        }
+    }
+
+    struct OptionallyDependsOnB;
+    impl Plugin for OptionallyDependsOnB {
+        fn build(&self, _app: &mut crate::App) {}
+        fn dependencies(&self) -> Vec<PluginDependency> {
+            vec![PluginDependency::optional::<PluginB>()]
+        }
+    }
+
+    struct CycleA;
+    impl Plugin for CycleA {
+        fn build(&self, _app: &mut crate::App) {}
+        fn dependencies(&self) -> Vec<PluginDependency> {
+            vec![PluginDependency::required::<CycleB>()]
+        }
+    }
+
+    struct CycleB;
+    impl Plugin for CycleB {
+        fn build(&self, _app: &mut crate::App) {}
+        fn dependencies(&self) -> Vec<PluginDependency> {
+            vec![PluginDependency::required::<CycleA>()]
+        }
+    }
+
+    #[test]
+    fn dependencies_added_before_are_valid() {
+        let mut app = App::new();
+        app.add_plugin(PluginA)
+            .add_plugin(DependsOnA)
+            .add_plugin(OptionallyDependsOnB);
+        assert_eq!(app.validate_plugin_dependencies(), Ok(()));
+        app.setup();
+    }
+
+    #[test]
+    fn missing_dependency_is_reported() {
+        let mut app = App::new();
+        app.add_plugin(DependsOnA);
+        assert_eq!(
+            app.validate_plugin_dependencies(),
+            Err(vec![PluginDependencyError::Missing {
+                plugin: std::any::type_name::<DependsOnA>().to_string(),
+                dependency: std::any::type_name::<PluginA>(),
+            }])
+        );
+    }
+
+    #[test]
+    fn dependency_added_after_is_reported() {
+        let mut app = App::new();
+        app.add_plugin(DependsOnA)
+            .add_plugin(OptionallyDependsOnB)
+            .add_plugin(PluginA)
+            .add_plugin(PluginB);
+        assert_eq!(
+            app.validate_plugin_dependencies(),
+            Err(vec![
+                PluginDependencyError::AddedAfter {
+                    plugin: std::any::type_name::<DependsOnA>().to_string(),
+                    dependency: std::any::type_name::<PluginA>(),
+                },
+                PluginDependencyError::AddedAfter {
+                    plugin: std::any::type_name::<OptionallyDependsOnB>().to_string(),
+                    dependency: std::any::type_name::<PluginB>(),
+                },
+            ])
+        );
+    }
+
+    #[test]
+    fn dependency_cycle_is_reported() {
+        let mut app = App::new();
+        app.add_plugin(CycleA).add_plugin(CycleB);
+        assert_eq!(
+            app.validate_plugin_dependencies(),
+            Err(vec![PluginDependencyError::Cycle {
+                plugins: vec![
+                    std::any::type_name::<CycleA>().to_string(),
+                    std::any::type_name::<CycleB>().to_string(),
+                ],
+            }])
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid plugin dependencies")]
+    fn setup_panics_on_missing_dependency() {
+        App::new().add_plugin(DependsOnA).setup();
+    }
+
+    #[test]
+    fn finish_and_cleanup_run_after_all_plugins_are_built() {
+        #[derive(Resource, Default)]
+        struct Phases(Vec<&'static str>);
+
+        struct Settings(&'static str);
+        impl Plugin for Settings {
+            fn build(&self, _app: &mut crate::App) {}
+        }
+
+        struct ReadsSettings;
+        impl Plugin for ReadsSettings {
+            fn build(&self, app: &mut crate::App) {
+                app.init_resource::<Phases>();
+            }
+            fn finish(&self, app: &mut crate::App) {
+                let setting = app.get_added_plugins::<Settings>()[0].0;
+                app.world.resource_mut::<Phases>().0.push(setting);
+            }
+            fn setup(&self, app: &mut crate::App) {
+                app.world.resource_mut::<Phases>().0.push("setup");
+            }
+            fn cleanup(&self, app: &mut crate::App) {
+                app.world.resource_mut::<Phases>().0.push("cleanup");
+            }
+        }
+
+        let mut app = App::new();
+        // `Settings` is added after the plugin reading it.
+        app.add_plugin(ReadsSettings).add_plugin(Settings("finish"));
+        app.setup();
+
+        assert_eq!(
+            app.world.resource::<Phases>().0,
+            vec!["finish", "setup", "cleanup"]
+        );
+    }
+
+    #[derive(Resource, Default)]
+    struct Runs(u32);
+
+    fn counting_sub_app() -> App {
+        let mut sub_app = App::empty();
+        sub_app.add_simple_outer_schedule();
+        sub_app.init_schedule(crate::CoreSchedule::Main);
+        sub_app
+            .init_resource::<Runs>()
+            .add_system(|mut runs: ResMut<Runs>| runs.0 += 1);
+        sub_app
+    }
+
+    #[test]
+    fn sub_apps_run_at_their_cadence() {
+        let mut app = App::new();
+        app.insert_sub_app(
+            "every_third",
+            SubApp::new(counting_sub_app(), |_, _| {})
+                .with_cadence(SubAppCadence::EveryNthFrame(3)),
+        );
+        app.insert_sub_app(
+            "manual",
+            SubApp::new(counting_sub_app(), |_, _| {}).with_cadence(SubAppCadence::Manual),
+        );
+
+        for _ in 0..7 {
+            app.update();
+        }
+        assert_eq!(app.sub_app("every_third").world.resource::<Runs>().0, 2);
+        assert_eq!(app.sub_app("manual").world.resource::<Runs>().0, 0);
+
+        app.run_sub_app("manual");
+        assert_eq!(app.sub_app("manual").world.resource::<Runs>().0, 1);
+    }
+
+    #[test]
+    fn sub_apps_exchange_data_through_channels() {
+        #[derive(Resource, Default)]
+        struct Total(u32);
+
+        let mut sub_app = App::empty();
+        sub_app.add_simple_outer_schedule();
+        sub_app.init_schedule(crate::CoreSchedule::Main);
+        sub_app.add_system(
+            |receiver: Res<WorldReceiver<u32>>, sender: Res<WorldSender<String>>| {
+                for value in receiver.try_iter() {
+                    sender.send(format!("doubled {}", value * 2));
+                }
+            },
+        );
+
+        let mut app = App::new();
+        app.insert_sub_app("worker", SubApp::new(sub_app, |_, _| {}));
+        app.add_extract_channel::<u32>("worker")
+            .add_sync_back_channel::<String>("worker")
+            .add_system(|sender: Res<WorldSender<u32>>| sender.send(21));
+
+        app.update();
+        assert_eq!(
+            app.world.resource::<WorldReceiver<String>>().latest(),
+            Some("doubled 42".to_string())
+        );
+
+        // `sync_back` runs after the sub app
+        app.init_resource::<Total>();
+        let sub_app = app.remove_sub_app("worker").unwrap();
+        app.insert_sub_app(
+            "worker",
+            sub_app.with_sync_back(|_, main_world| main_world.resource_mut::<Total>().0 += 1),
+        );
+        app.update();
+        assert_eq!(app.world.resource::<Total>().0, 1);
+    }
+
+    #[test]
+    fn threaded_sub_app_runs_without_blocking() {
+        #[derive(Resource, Default)]
+        struct SyncedRuns(u32);
+
+        let mut app = App::new();
+        app.init_resource::<SyncedRuns>().insert_sub_app(
+            "threaded",
+            SubApp::new(counting_sub_app(), |_, _| {})
+                .with_sync_back(|sub_app, main_world| {
+                    main_world.resource_mut::<SyncedRuns>().0 = sub_app.world.resource::<Runs>().0;
+                })
+                .threaded(),
+        );
+
+        app.update();
+        // the sub app is on its thread until a later update collects it
+        assert!(app.get_sub_app("threaded").is_err());
+        assert_eq!(app.world.resource::<SyncedRuns>().0, 0);
+
+        // waits for the sub app to finish, and syncs it back
+        assert_eq!(app.sub_app_mut("threaded").world.resource::<Runs>().0, 1);
+        assert_eq!(app.world.resource::<SyncedRuns>().0, 1);
+
+        for _ in 0..3 {
+            app.update();
+        }
+        let runs = app
+            .remove_sub_app("threaded")
+            .unwrap()
+            .app
+            .world
+            .resource::<Runs>()
+            .0;
+        assert!((2..=4).contains(&runs));
+        assert_eq!(app.world.resource::<SyncedRuns>().0, runs);
+    }
 }
@@ -0,0 +1,379 @@
+use crate::{app::AppExit, App, AppTypeRegistry, CoreSet};
+use serde::{Deserialize, Serialize};
+
+use bevy_ecs::{
+    prelude::Resource,
+    reflect::{ReflectComponent, ReflectEvent, ReflectResource},
+    schedule::IntoSystemConfig,
+    world::World,
+};
+use bevy_reflect::{
+    serde::{ReflectSerializer, UntypedReflectDeserializer},
+    Reflect, TypeRegistry,
+};
+use bevy_utils::tracing::info;
+use serde::de::DeserializeSeed;
+
+/// A configuration struct for automated CI testing.
+///
+/// It gets used when the `bevy_ci_testing` feature is enabled to automatically
+/// exit a Bevy app when run through the CI. This is needed because otherwise
+/// Bevy apps would be stuck in the game loop and wouldn't allow the CI to progress.
+///
+/// The configuration can also script a list of [`CiTestingEvent`]s, which makes it possible to
+/// drive an app and capture its state without a GPU:
+///
+/// ```ron
+/// (
+///     exit_after: None,
+///     events: [
+///         (10, SendEvent(r#"{
+///             "bevy_input::keyboard::KeyboardInput": (
+///                 scan_code: 57,
+///                 key_code: Some(Space),
+///                 state: Pressed,
+///             ),
+///         }"#)),
+///         (20, SetResource(r#"{ "my_game::Score": (0) }"#)),
+///         (60, DumpWorld(
+///             path: "world_60.ron",
+///             components: ["my_game::Player"],
+///             resources: ["my_game::Score"],
+///         )),
+///         (61, AppExit),
+///     ],
+/// )
+/// ```
+#[derive(Deserialize, Resource)]
+pub struct CiTestingConfig {
+    /// The number of frames after which Bevy should exit.
+    pub exit_after: Option<u32>,
+    /// Actions to perform at specific frames.
+    #[serde(default)]
+    pub events: Vec<CiTestingEvent>,
+}
+
+/// An action to perform at a given frame, counted from `0`.
+///
+/// Actions run at the start of their frame, before the systems in [`CoreSet::PreUpdate`].
+#[derive(Deserialize, Debug)]
+pub struct CiTestingEvent(pub u32, pub CiTestingEventType);
+
+/// The actions that can be scripted in a [`CiTestingConfig`].
+///
+/// Reflected values are written in the format of
+/// [`ReflectSerializer`], as a RON map from the type path to the value. They are stored as
+/// strings because they can only be deserialized once all types have been registered.
+#[derive(Deserialize, Debug)]
+pub enum CiTestingEventType {
+    /// Sends an [`AppExit`] event.
+    AppExit,
+    /// Sends a reflected event. The event type must be registered with [`ReflectEvent`] type data.
+    SendEvent(String),
+    /// Inserts a reflected resource, or applies it to the existing value. The resource type must
+    /// be registered with [`ReflectResource`] type data.
+    SetResource(String),
+    /// Writes a RON serialization of the given resources, and of every entity with at least one
+    /// of the given components, to the file at `path`.
+    DumpWorld {
+        /// Where to write the dump. On `wasm32`, the dump is logged instead.
+        path: String,
+        /// The type paths of the components to dump.
+        #[serde(default)]
+        components: Vec<String>,
+        /// The type paths of the resources to dump.
+        #[serde(default)]
+        resources: Vec<String>,
+    },
+}
+
+/// The serialized contents of a [`CiTestingEventType::DumpWorld`] action.
+#[derive(Serialize)]
+struct WorldDump<'a> {
+    frame: u32,
+    resources: Vec<ReflectSerializer<'a>>,
+    entities: Vec<EntityDump<'a>>,
+}
+
+#[derive(Serialize)]
+struct EntityDump<'a> {
+    entity: u32,
+    components: Vec<ReflectSerializer<'a>>,
+}
+
+fn ci_testing_exit_after(
+    mut current_frame: bevy_ecs::prelude::Local<u32>,
+    ci_testing_config: bevy_ecs::prelude::Res<CiTestingConfig>,
+    mut app_exit_events: bevy_ecs::event::EventWriter<AppExit>,
+) {
+    if let Some(exit_after) = ci_testing_config.exit_after {
+        if *current_frame > exit_after {
+            app_exit_events.send(AppExit);
+            info!("Exiting after {} frames. Test successful!", exit_after);
+        }
+    }
+    *current_frame += 1;
+}
+
+fn ci_testing_run_events(world: &mut World, mut current_frame: bevy_ecs::prelude::Local<u32>) {
+    let frame = *current_frame;
+    *current_frame += 1;
+
+    world.resource_scope(|world, config: bevy_ecs::world::Mut<CiTestingConfig>| {
+        for CiTestingEvent(_, event) in config.events.iter().filter(|event| event.0 == frame) {
+            info!("Running CI testing event at frame {}: {:?}", frame, event);
+            run_event(world, frame, event);
+        }
+    });
+}
+
+fn run_event(world: &mut World, frame: u32, event: &CiTestingEventType) {
+    match event {
+        CiTestingEventType::AppExit => world.send_event(AppExit),
+        CiTestingEventType::SendEvent(value) => {
+            let registry = world.resource::<AppTypeRegistry>().clone();
+            let registry = registry.read();
+            let (value, registration) = deserialize_reflect(&registry, value);
+            let Some(reflect_event) = registration.data::<ReflectEvent>() else {
+                panic!(
+                    "`{}` is not registered with `ReflectEvent`",
+                    registration.type_name()
+                );
+            };
+            reflect_event.send(world, &*value);
+        }
+        CiTestingEventType::SetResource(value) => {
+            let registry = world.resource::<AppTypeRegistry>().clone();
+            let registry = registry.read();
+            let (value, registration) = deserialize_reflect(&registry, value);
+            let Some(reflect_resource) = registration.data::<ReflectResource>() else {
+                panic!(
+                    "`{}` is not registered with `ReflectResource`",
+                    registration.type_name()
+                );
+            };
+            reflect_resource.apply_or_insert(world, &*value);
+        }
+        CiTestingEventType::DumpWorld {
+            path,
+            components,
+            resources,
+        } => {
+            let dump = dump_world(world, frame, components, resources);
+            #[cfg(not(target_arch = "wasm32"))]
+            std::fs::write(path, dump).expect("error writing CI testing world dump");
+            #[cfg(target_arch = "wasm32")]
+            info!("World dump for {}:\n{}", path, dump);
+        }
+    }
+}
+
+fn deserialize_reflect<'a>(
+    registry: &'a TypeRegistry,
+    value: &str,
+) -> (Box<dyn Reflect>, &'a bevy_reflect::TypeRegistration) {
+    let mut deserializer =
+        ron::Deserializer::from_str(value).expect("error parsing CI testing reflected value");
+    let value = UntypedReflectDeserializer::new(registry)
+        .deserialize(&mut deserializer)
+        .expect("error deserializing CI testing reflected value");
+    let registration = registry
+        .get_with_name(value.type_name())
+        .expect("deserialized types are always registered");
+    (value, registration)
+}
+
+fn dump_world(world: &World, frame: u32, components: &[String], resources: &[String]) -> String {
+    let registry = world.resource::<AppTypeRegistry>().read();
+    let get_registration = |type_name: &str| {
+        registry
+            .get_with_type_path(type_name)
+            .or_else(|| registry.get_with_name(type_name))
+            .unwrap_or_else(|| panic!("`{type_name}` is not registered"))
+    };
+
+    let resources = resources
+        .iter()
+        .map(|type_name| {
+            let Some(reflect_resource) = get_registration(type_name).data::<ReflectResource>()
+            else {
+                panic!("`{type_name}` is not registered with `ReflectResource`");
+            };
+            let resource = reflect_resource
+                .reflect(world)
+                .unwrap_or_else(|| panic!("resource `{type_name}` does not exist"));
+            ReflectSerializer::new(resource, &registry)
+        })
+        .collect();
+
+    let components = components
+        .iter()
+        .map(
+            |type_name| match get_registration(type_name).data::<ReflectComponent>() {
+                Some(reflect_component) => reflect_component,
+                None => panic!("`{type_name}` is not registered with `ReflectComponent`"),
+            },
+        )
+        .collect::<Vec<_>>();
+
+    let mut entities = world
+        .iter_entities()
+        .filter_map(|entity| {
+            let components = components
+                .iter()
+                .filter_map(|reflect_component| reflect_component.reflect(entity))
+                .map(|component| ReflectSerializer::new(component, &registry))
+                .collect::<Vec<_>>();
+            (!components.is_empty()).then(|| EntityDump {
+                entity: entity.id().index(),
+                components,
+            })
+        })
+        .collect::<Vec<_>>();
+    entities.sort_by_key(|entity| entity.entity);
+
+    ron::ser::to_string_pretty(
+        &WorldDump {
+            frame,
+            resources,
+            entities,
+        },
+        ron::ser::PrettyConfig::default(),
+    )
+    .expect("error serializing CI testing world dump")
+}
+
+pub(crate) fn setup_app(app: &mut App) -> &mut App {
+    #[cfg(not(target_arch = "wasm32"))]
+    let config: CiTestingConfig = {
+        let filename = std::env::var("CI_TESTING_CONFIG")
+            .unwrap_or_else(|_| "ci_testing_config.ron".to_string());
+        ron::from_str(
+            &std::fs::read_to_string(filename)
+                .expect("error reading CI testing configuration file"),
+        )
+        .expect("error deserializing CI testing configuration file")
+    };
+    #[cfg(target_arch = "wasm32")]
+    let config: CiTestingConfig = {
+        let config = include_str!("../../../../ci_testing_config.ron");
+        ron::from_str(config).expect("error deserializing CI testing configuration file")
+    };
+
+    add_config(app, config)
+}
+
+/// Adds the systems running the given configuration to the app.
+fn add_config(app: &mut App, config: CiTestingConfig) -> &mut App {
+    app.insert_resource(config)
+        .add_system(ci_testing_exit_after)
+        .add_system(ci_testing_run_events.in_base_set(CoreSet::First));
+
+    app
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{add_config, CiTestingConfig};
+    use crate::ci_testing::TestApp;
+    use bevy_ecs::prelude::*;
+    use bevy_reflect::{FromReflect, Reflect};
+
+    #[derive(Reflect, FromReflect, Debug, PartialEq)]
+    #[reflect(Event)]
+    struct Ping(u32);
+
+    #[derive(Resource, Reflect, Default, Debug, PartialEq)]
+    #[reflect(Resource)]
+    struct Score(u32);
+
+    #[derive(Resource, Default)]
+    struct ReceivedPings(Vec<u32>);
+
+    #[derive(Component, Reflect, Default)]
+    #[reflect(Component)]
+    struct Player {
+        health: u32,
+    }
+
+    #[derive(Component, Reflect, Default)]
+    #[reflect(Component)]
+    struct Enemy;
+
+    fn receive_pings(mut pings: EventReader<Ping>, mut received: ResMut<ReceivedPings>) {
+        received.0.extend(pings.iter().map(|ping| ping.0));
+    }
+
+    fn test_app(config: &str) -> TestApp {
+        let config: CiTestingConfig = ron::from_str(config).unwrap();
+        let mut app = TestApp::new();
+        app.register_type::<Ping>()
+            .register_type::<Score>()
+            .register_type::<Player>()
+            .register_type::<Enemy>()
+            .add_event::<Ping>()
+            .init_resource::<ReceivedPings>()
+            .add_system(receive_pings);
+        add_config(&mut app, config);
+        app
+    }
+
+    #[test]
+    fn send_event() {
+        let path = module_path!();
+        let mut app = test_app(&format!(
+            r#"(exit_after: None, events: [(1, SendEvent("{{ \"{path}::Ping\": (7) }}"))])"#
+        ));
+
+        app.step();
+        assert!(app.world.resource::<ReceivedPings>().0.is_empty());
+        app.step();
+        assert_eq!(vec![7], app.world.resource::<ReceivedPings>().0);
+    }
+
+    #[test]
+    fn set_resource() {
+        let path = module_path!();
+        let mut app = test_app(&format!(
+            r#"(exit_after: None, events: [
+                (0, SetResource("{{ \"{path}::Score\": (1) }}")),
+                (2, SetResource("{{ \"{path}::Score\": (5) }}")),
+            ])"#
+        ));
+
+        app.step();
+        app.assert_resource_eq(&Score(1));
+        app.step_frames(2);
+        app.assert_resource_eq(&Score(5));
+    }
+
+    #[test]
+    fn dump_world() {
+        let path = module_path!();
+        let dump_path = std::env::temp_dir().join(format!(
+            "bevy_ci_testing_dump_world_{}.ron",
+            std::process::id()
+        ));
+        let mut app = test_app(&format!(
+            r#"(exit_after: None, events: [
+                (1, DumpWorld(
+                    path: {dump_path:?},
+                    components: ["{path}::Player"],
+                    resources: ["{path}::Score"],
+                )),
+            ])"#
+        ));
+        app.insert_resource(Score(3));
+        app.world.spawn(Player { health: 42 });
+        app.world.spawn(Enemy);
+
+        app.step_frames(2);
+        let dump = std::fs::read_to_string(&dump_path).unwrap();
+        std::fs::remove_file(&dump_path).unwrap();
+        assert!(dump.contains("frame: 1"));
+        assert!(dump.contains(&format!("\"{path}::Score\": (3)")));
+        assert!(dump.contains(&format!("\"{path}::Player\": (")));
+        assert!(dump.contains("health: 42"));
+        assert!(!dump.contains("Enemy"));
+    }
+}
@@ -0,0 +1,380 @@
+use crate::{App, AppExit};
+use bevy_ecs::{
+    event::{Event, Events, ManualEventReader},
+    prelude::{Entity, Resource},
+    query::ReadOnlyWorldQuery,
+    world::World,
+};
+use std::{
+    fmt::Debug,
+    ops::{Deref, DerefMut},
+};
+
+/// A headless wrapper around an [`App`] that advances it one frame at a time.
+///
+/// Unlike [`App::run`], a [`TestApp`] never hands control to a runner: each call to
+/// [`step`](TestApp::step) runs a single [`App::update`], so tests decide exactly how many frames
+/// elapse and can inspect the [`World`] in between. [`Plugin::setup`](crate::Plugin::setup) is run
+/// once, right before the first frame.
+///
+/// [`TestApp`] dereferences to [`App`], so plugins, systems and resources are added as usual.
+///
+/// # Deterministic time
+///
+/// `bevy_time` reads the wall clock by default. For reproducible tests, insert
+/// `TimeUpdateStrategy::ManualDuration` so that every frame advances `Time` by the same fixed
+/// amount:
+///
+/// ```ignore
+/// let mut app = TestApp::new();
+/// app.add_plugin(TimePlugin)
+///     .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(16)));
+/// app.step_frames(10);
+/// ```
+///
+/// # Injecting input
+///
+/// Input resources such as `Input<KeyCode>` are driven by their raw events, so the most faithful
+/// way to simulate input is to [`send_event`](TestApp::send_event) a `KeyboardInput` (or any other
+/// event) before stepping.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_app::ci_testing::TestApp;
+/// # use bevy_ecs::prelude::*;
+/// #[derive(Resource, Default, Debug, PartialEq)]
+/// struct Counter(u32);
+///
+/// let mut app = TestApp::new();
+/// app.init_resource::<Counter>()
+///     .add_system(|mut counter: ResMut<Counter>| counter.0 += 1);
+///
+/// app.step_frames(3);
+/// app.assert_resource_eq(&Counter(3));
+///
+/// let frames = app.step_until(10, |world| world.resource::<Counter>().0 == 5);
+/// assert_eq!(frames, Some(2));
+/// ```
+pub struct TestApp {
+    app: App,
+    frame_count: u32,
+    is_setup: bool,
+    app_exit_reader: ManualEventReader<AppExit>,
+    exit_requested: bool,
+}
+
+impl Default for TestApp {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TestApp {
+    /// Creates a [`TestApp`] wrapping an app with the same structure as [`App::new`].
+    ///
+    /// Unlike [`App::new`], the app doesn't read a CI testing configuration file when the
+    /// `bevy_ci_testing` feature is enabled, so tests behave the same with and without it.
+    pub fn new() -> Self {
+        Self::from_app(App::with_default_structure())
+    }
+
+    /// Wraps an existing [`App`].
+    ///
+    /// The app's runner is never called.
+    pub fn from_app(app: App) -> Self {
+        Self {
+            app,
+            frame_count: 0,
+            is_setup: false,
+            app_exit_reader: ManualEventReader::default(),
+            exit_requested: false,
+        }
+    }
+
+    /// Returns the wrapped [`App`], consuming the [`TestApp`].
+    pub fn into_app(self) -> App {
+        self.app
+    }
+
+    /// The number of frames stepped so far.
+    pub fn frame_count(&self) -> u32 {
+        self.frame_count
+    }
+
+    /// Returns `true` if an [`AppExit`] event has been sent during any of the stepped frames.
+    pub fn exit_requested(&self) -> bool {
+        self.exit_requested
+    }
+
+    /// Runs a single frame of the app.
+    pub fn step(&mut self) -> &mut Self {
+        if !self.is_setup {
+            self.app.setup();
+            self.is_setup = true;
+        }
+
+        self.app.update();
+        self.frame_count += 1;
+
+        if let Some(app_exit_events) = self.app.world.get_resource::<Events<AppExit>>() {
+            if self.app_exit_reader.iter(app_exit_events).last().is_some() {
+                self.exit_requested = true;
+            }
+        }
+
+        self
+    }
+
+    /// Runs `frames` frames of the app.
+    pub fn step_frames(&mut self, frames: u32) -> &mut Self {
+        for _ in 0..frames {
+            self.step();
+        }
+        self
+    }
+
+    /// Steps the app until `predicate` returns `true`, checking after every frame.
+    ///
+    /// Returns the number of frames that were stepped, or [`None`] if the predicate still did not
+    /// hold after `max_frames` frames.
+    pub fn step_until(
+        &mut self,
+        max_frames: u32,
+        mut predicate: impl FnMut(&mut World) -> bool,
+    ) -> Option<u32> {
+        for frame in 1..=max_frames {
+            self.step();
+            if predicate(&mut self.app.world) {
+                return Some(frame);
+            }
+        }
+        None
+    }
+
+    /// Sends an event, which will be visible to systems during the next frame.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the event type was not added with [`App::add_event`].
+    pub fn send_event<E: Event>(&mut self, event: E) -> &mut Self {
+        self.events_mut::<E>().send(event);
+        self
+    }
+
+    /// Sends a batch of events, which will be visible to systems during the next frame.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the event type was not added with [`App::add_event`].
+    pub fn send_events<E: Event>(&mut self, events: impl IntoIterator<Item = E>) -> &mut Self {
+        self.events_mut::<E>().extend(events);
+        self
+    }
+
+    fn events_mut<E: Event>(&mut self) -> bevy_ecs::world::Mut<'_, Events<E>> {
+        match self.app.world.get_resource_mut::<Events<E>>() {
+            Some(events) => events,
+            None => panic!(
+                "Unable to send event `{}`: it was not added with `App::add_event`",
+                std::any::type_name::<E>()
+            ),
+        }
+    }
+
+    /// Returns the number of entities matching the query filter `F`.
+    pub fn count<F: ReadOnlyWorldQuery>(&mut self) -> usize {
+        self.app
+            .world
+            .query_filtered::<Entity, F>()
+            .iter(&self.app.world)
+            .count()
+    }
+
+    /// Asserts that the resource `R` satisfies `predicate`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the resource does not exist or the predicate returns `false`.
+    #[track_caller]
+    pub fn assert_resource<R: Resource + Debug>(&self, predicate: impl FnOnce(&R) -> bool) {
+        let resource = self.resource::<R>();
+        assert!(
+            predicate(resource),
+            "resource `{}` did not satisfy the predicate after {} frames: {:?}",
+            std::any::type_name::<R>(),
+            self.frame_count,
+            resource,
+        );
+    }
+
+    /// Asserts that the resource `R` is equal to `expected`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the resource does not exist or is not equal to `expected`.
+    #[track_caller]
+    pub fn assert_resource_eq<R: Resource + PartialEq + Debug>(&self, expected: &R) {
+        let resource = self.resource::<R>();
+        assert_eq!(
+            resource,
+            expected,
+            "resource `{}` had an unexpected value after {} frames",
+            std::any::type_name::<R>(),
+            self.frame_count,
+        );
+    }
+
+    #[track_caller]
+    fn resource<R: Resource>(&self) -> &R {
+        match self.app.world.get_resource::<R>() {
+            Some(resource) => resource,
+            None => panic!(
+                "resource `{}` does not exist in the test app",
+                std::any::type_name::<R>()
+            ),
+        }
+    }
+}
+
+impl Deref for TestApp {
+    type Target = App;
+
+    fn deref(&self) -> &Self::Target {
+        &self.app
+    }
+}
+
+impl DerefMut for TestApp {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.app
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TestApp;
+    use crate::{AppExit, Plugin};
+    use bevy_ecs::prelude::*;
+
+    #[derive(Resource, Default, Debug, PartialEq)]
+    struct Counter(u32);
+
+    #[derive(Resource, Default, Debug, PartialEq)]
+    struct Received(Vec<u32>);
+
+    #[derive(Debug)]
+    struct Ping(u32);
+
+    #[derive(Component)]
+    struct Marker;
+
+    fn count_frames(mut counter: ResMut<Counter>) {
+        counter.0 += 1;
+    }
+
+    #[test]
+    fn steps_frames() {
+        let mut app = TestApp::new();
+        app.init_resource::<Counter>().add_system(count_frames);
+
+        app.step().step_frames(4);
+
+        assert_eq!(app.frame_count(), 5);
+        app.assert_resource_eq(&Counter(5));
+        app.assert_resource(|counter: &Counter| counter.0 > 4);
+    }
+
+    #[test]
+    fn step_until_stops_when_predicate_holds() {
+        let mut app = TestApp::new();
+        app.init_resource::<Counter>().add_system(count_frames);
+
+        assert_eq!(
+            app.step_until(10, |world| world.resource::<Counter>().0 == 3),
+            Some(3)
+        );
+        assert_eq!(
+            app.step_until(2, |world| world.resource::<Counter>().0 == 100),
+            None
+        );
+        assert_eq!(app.frame_count(), 5);
+    }
+
+    #[test]
+    fn injected_events_are_received() {
+        let mut app = TestApp::new();
+        app.add_event::<Ping>()
+            .init_resource::<Received>()
+            .add_system(
+                |mut events: EventReader<Ping>, mut received: ResMut<Received>| {
+                    received.0.extend(events.iter().map(|ping| ping.0));
+                },
+            );
+
+        app.send_event(Ping(1)).step();
+        app.send_events([Ping(2), Ping(3)]).step().step();
+
+        app.assert_resource_eq(&Received(vec![1, 2, 3]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn sending_unregistered_event_panics() {
+        TestApp::new().send_event(Ping(0));
+    }
+
+    #[test]
+    fn counts_entities() {
+        let mut app = TestApp::new();
+        app.add_startup_system(|mut commands: Commands| {
+            commands.spawn(Marker);
+            commands.spawn(Marker);
+            commands.spawn_empty();
+        });
+
+        assert_eq!(app.count::<With<Marker>>(), 0);
+        app.step();
+        assert_eq!(app.count::<With<Marker>>(), 2);
+    }
+
+    #[test]
+    fn records_exit_requests() {
+        let mut app = TestApp::new();
+        app.init_resource::<Counter>()
+            .add_system(count_frames)
+            .add_system(
+                (|counter: Res<Counter>, mut exit: EventWriter<AppExit>| {
+                    if counter.0 == 2 {
+                        exit.send(AppExit);
+                    }
+                })
+                .after(count_frames),
+            );
+
+        app.step();
+        assert!(!app.exit_requested());
+        app.step();
+        assert!(app.exit_requested());
+    }
+
+    #[test]
+    fn runs_plugin_setup_once() {
+        struct SetupPlugin;
+        impl Plugin for SetupPlugin {
+            fn build(&self, app: &mut crate::App) {
+                app.init_resource::<Counter>();
+            }
+
+            fn setup(&self, app: &mut crate::App) {
+                app.world.resource_mut::<Counter>().0 += 10;
+            }
+        }
+
+        let mut app = TestApp::new();
+        app.add_plugin(SetupPlugin);
+        app.step_frames(3);
+
+        app.assert_resource_eq(&Counter(10));
+    }
+}
@@ -0,0 +1,18 @@
+//! Utilities for running Bevy apps headlessly and deterministically, both in CI and in tests.
+//!
+//! [`TestApp`] drives an [`App`](crate::App) frame by frame so integration tests can step it,
+//! inject events and assert on the resulting world state without a window or a runner.
+//!
+//! When the `bevy_ci_testing` feature is enabled, every [`App`](crate::App) additionally reads a
+//! [`CiTestingConfig`] at startup, which is used to automatically exit example apps in CI.
+
+#[cfg(feature = "bevy_ci_testing")]
+mod config;
+mod harness;
+
+#[cfg(feature = "bevy_ci_testing")]
+pub use config::CiTestingConfig;
+pub use harness::*;
+
+#[cfg(feature = "bevy_ci_testing")]
+pub(crate) use config::setup_app;
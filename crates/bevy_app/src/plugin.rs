@@ -1,7 +1,10 @@
 use downcast_rs::{impl_downcast, Downcast};
 
 use crate::App;
-use std::any::Any;
+use std::{
+    any::{Any, TypeId},
+    fmt,
+};
 
 /// A collection of Bevy app logic and configuration.
 ///
@@ -13,17 +16,46 @@ use std::any::Any;
 /// should be overridden to return `false`. Plugins are considered duplicate if they have the same
 /// [`name()`](Self::name). The default `name()` implementation returns the type name, which means
 /// generic plugins with different type parameters will not be considered duplicates.
+///
+/// Plugins that rely on other plugins should list them in [`dependencies()`](Self::dependencies).
+/// When the app is set up, [`App::setup`] checks that every required dependency was added, and
+/// added before the plugin depending on it, so misconfigured apps fail early with a clear message.
+///
+/// After all plugins are built, [`App::setup`] runs three more phases, each one for every plugin
+/// in insertion order before moving on to the next: [`finish()`](Self::finish),
+/// [`setup()`](Self::setup) and [`cleanup()`](Self::cleanup).
 pub trait Plugin: Downcast + Any + Send + Sync {
     /// Configures the [`App`] to which this plugin is added.
     fn build(&self, app: &mut App);
 
-    /// Runs after all plugins are built, but before the app runner is called.
+    /// The other plugins this plugin relies on.
+    ///
+    /// Required dependencies must be added to the [`App`] before this plugin. Optional
+    /// dependencies may be missing, but if present they must also be added before this plugin.
+    fn dependencies(&self) -> Vec<PluginDependency> {
+        Vec::new()
+    }
+
+    /// Runs after all plugins are built.
+    /// This can be used to read the configuration (resources or [plugin settings](App::get_added_plugins))
+    /// of other plugins, regardless of the order in which they were added.
+    fn finish(&self, _app: &mut App) {
+        // do nothing
+    }
+
+    /// Runs after all plugins are built and finished, but before the app runner is called.
     /// This can be useful if you have some resource that other plugins need during their build step,
     /// but after build you want to remove it and send it to another thread.
     fn setup(&self, _app: &mut App) {
         // do nothing
     }
 
+    /// Runs after every plugin has been set up, right before the app runner is called.
+    /// This can be used to remove resources that were only needed to configure other plugins.
+    fn cleanup(&self, _app: &mut App) {
+        // do nothing
+    }
+
     /// Configures a name for the [`Plugin`] which is primarily used for checking plugin
     /// uniqueness and debugging.
     fn name(&self) -> &str {
@@ -39,6 +71,99 @@ pub trait Plugin: Downcast + Any + Send + Sync {
 
 impl_downcast!(Plugin);
 
+/// A dependency of a [`Plugin`] on another plugin type, returned by [`Plugin::dependencies`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PluginDependency {
+    plugin_type_id: TypeId,
+    name: &'static str,
+    optional: bool,
+}
+
+impl PluginDependency {
+    /// A dependency on `T` that must be added to the [`App`].
+    pub fn required<T: Plugin>() -> Self {
+        Self {
+            plugin_type_id: TypeId::of::<T>(),
+            name: std::any::type_name::<T>(),
+            optional: false,
+        }
+    }
+
+    /// A dependency on `T` that may be missing from the [`App`].
+    pub fn optional<T: Plugin>() -> Self {
+        Self {
+            optional: true,
+            ..Self::required::<T>()
+        }
+    }
+
+    /// The [`TypeId`] of the plugin type that is depended upon.
+    pub fn plugin_type_id(&self) -> TypeId {
+        self.plugin_type_id
+    }
+
+    /// The type name of the plugin type that is depended upon.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns `true` if the dependency may be missing.
+    pub fn is_optional(&self) -> bool {
+        self.optional
+    }
+}
+
+/// An error found when validating the [dependencies](Plugin::dependencies) of the plugins added
+/// to an [`App`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PluginDependencyError {
+    /// A required dependency was not added.
+    Missing {
+        /// The name of the plugin declaring the dependency.
+        plugin: String,
+        /// The name of the missing plugin.
+        dependency: &'static str,
+    },
+    /// A dependency was added after the plugin depending on it.
+    AddedAfter {
+        /// The name of the plugin declaring the dependency.
+        plugin: String,
+        /// The name of the plugin that was added too late.
+        dependency: &'static str,
+    },
+    /// Plugins depend on each other in a cycle.
+    Cycle {
+        /// The names of the plugins in the cycle, in dependency order.
+        plugins: Vec<String>,
+    },
+}
+
+impl fmt::Display for PluginDependencyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PluginDependencyError::Missing { plugin, dependency } => {
+                write!(
+                    f,
+                    "plugin {plugin} requires {dependency}, which was not added"
+                )
+            }
+            PluginDependencyError::AddedAfter { plugin, dependency } => write!(
+                f,
+                "plugin {plugin} depends on {dependency}, which must be added before it"
+            ),
+            PluginDependencyError::Cycle { plugins } => {
+                write!(f, "plugins depend on each other in a cycle: ")?;
+                for plugin in plugins {
+                    write!(f, "{plugin} -> ")?;
+                }
+                write!(f, "{}", plugins[0])
+            }
+        }
+    }
+}
+
+impl std::error::Error for PluginDependencyError {}
+
 /// A type representing an unsafe function that returns a mutable pointer to a [`Plugin`].
 /// It is used for dynamically loading plugins.
 ///
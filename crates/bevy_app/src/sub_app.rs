@@ -0,0 +1,140 @@
+use crate::SubApp;
+use bevy_ecs::system::Resource;
+use crossbeam_channel::{Receiver, Sender, TryRecvError};
+
+/// How often a [`SubApp`] is extracted and run by [`App::update`](crate::App::update).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SubAppCadence {
+    /// The sub app runs on every update of the main app.
+    #[default]
+    EveryFrame,
+    /// The sub app runs once every `n` updates of the main app. `0` is treated as `1`.
+    EveryNthFrame(u32),
+    /// The sub app only runs when [`App::run_sub_app`](crate::App::run_sub_app) is called.
+    Manual,
+}
+
+/// Creates a typed channel to move values between two [`World`](bevy_ecs::world::World)s.
+///
+/// Insert the [`WorldSender`] in one world and the [`WorldReceiver`] in the other, so that their
+/// systems can exchange data without waiting on each other, even when a [`SubApp`] runs on its own
+/// thread. [`App::add_extract_channel`](crate::App::add_extract_channel) and
+/// [`App::add_sync_back_channel`](crate::App::add_sync_back_channel) do this for the main world
+/// and a sub app.
+///
+/// Since the channel ends are resources, a world can hold a single sender and a single receiver
+/// per value type. Use distinct types for distinct channels.
+pub fn world_channel<T: Send + 'static>() -> (WorldSender<T>, WorldReceiver<T>) {
+    let (sender, receiver) = crossbeam_channel::unbounded();
+    (WorldSender(sender), WorldReceiver(receiver))
+}
+
+/// The sending end of a [`world_channel`].
+#[derive(Resource)]
+pub struct WorldSender<T: Send + 'static>(Sender<T>);
+
+impl<T: Send + 'static> WorldSender<T> {
+    /// Sends a value to the other world.
+    ///
+    /// The value is dropped if the [`WorldReceiver`] no longer exists.
+    pub fn send(&self, value: T) {
+        // the receiving world may have been dropped, in which case nobody cares about the value
+        let _ = self.0.send(value);
+    }
+}
+
+impl<T: Send + 'static> Clone for WorldSender<T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+/// The receiving end of a [`world_channel`].
+#[derive(Resource)]
+pub struct WorldReceiver<T: Send + 'static>(Receiver<T>);
+
+impl<T: Send + 'static> WorldReceiver<T> {
+    /// Receives the oldest value that has not been received yet, without blocking.
+    pub fn try_recv(&self) -> Option<T> {
+        self.0.try_recv().ok()
+    }
+
+    /// Returns an iterator over all the values sent so far, without blocking.
+    pub fn try_iter(&self) -> impl Iterator<Item = T> + '_ {
+        self.0.try_iter()
+    }
+
+    /// Receives all the values sent so far, and returns the most recent one.
+    pub fn latest(&self) -> Option<T> {
+        self.0.try_iter().last()
+    }
+}
+
+/// A thread that runs a threaded [`SubApp`].
+///
+/// The sub app is moved to the thread after being extracted, and moved back to the main app once
+/// its schedule has finished running.
+pub(crate) struct SubAppThread {
+    to_thread: Sender<SubApp>,
+    from_thread: Receiver<SubApp>,
+    in_flight: bool,
+}
+
+impl SubAppThread {
+    pub(crate) fn spawn(name: String) -> Self {
+        let (to_thread, thread_receiver) = crossbeam_channel::bounded::<SubApp>(1);
+        let (thread_sender, from_thread) = crossbeam_channel::bounded::<SubApp>(1);
+
+        std::thread::Builder::new()
+            .name(name)
+            .spawn(move || {
+                // the loop ends when the main app drops its end of the channels
+                while let Ok(mut sub_app) = thread_receiver.recv() {
+                    sub_app.run();
+                    if thread_sender.send(sub_app).is_err() {
+                        break;
+                    }
+                }
+            })
+            .expect("failed to spawn sub app thread");
+
+        Self {
+            to_thread,
+            from_thread,
+            in_flight: false,
+        }
+    }
+
+    pub(crate) fn send(&mut self, sub_app: SubApp) {
+        debug_assert!(!self.in_flight);
+        self.to_thread
+            .send(sub_app)
+            .expect("sub app thread has stopped");
+        self.in_flight = true;
+    }
+
+    /// Returns the sub app if it has finished running.
+    pub(crate) fn try_receive(&mut self) -> Option<SubApp> {
+        if !self.in_flight {
+            return None;
+        }
+        match self.from_thread.try_recv() {
+            Ok(sub_app) => {
+                self.in_flight = false;
+                Some(sub_app)
+            }
+            Err(TryRecvError::Empty) => None,
+            Err(TryRecvError::Disconnected) => panic!("sub app thread panicked"),
+        }
+    }
+
+    /// Waits for the sub app to finish running, if it is running.
+    pub(crate) fn receive(&mut self) -> Option<SubApp> {
+        if !self.in_flight {
+            return None;
+        }
+        let sub_app = self.from_thread.recv().expect("sub app thread panicked");
+        self.in_flight = false;
+        Some(sub_app)
+    }
+}
@@ -1,17 +1,28 @@
 use crate::{
+    load_queue::{LoadQueue, QueuedLoad},
+    meta::SettingsOverride,
     path::{AssetPath, AssetPathId, SourcePathId},
-    Asset, AssetIo, AssetIoError, AssetLifecycle, AssetLifecycleChannel, AssetLifecycleEvent,
-    AssetLoader, Assets, Handle, HandleId, HandleUntyped, LabelId, LoadContext, LoadState,
-    RefChange, RefChangeChannel, SourceInfo, SourceMeta,
+    processor::{process_source, ProcessedAssetCache, ProcessedSource, RegisteredProcessor},
+    Asset, AssetEvent, AssetIo, AssetIoError, AssetLifecycle, AssetLifecycleChannel,
+    AssetLifecycleEvent, AssetLoadLimits, AssetLoader, AssetProcessor, AssetSaver, Assets, Handle,
+    HandleId, HandleUntyped, LabelId, LoadContext, LoadPriority, LoadState, LoaderMeta, RefChange,
+    RefChangeChannel, SourceInfo, SourceMeta,
 };
 use anyhow::Result;
+use bevy_ecs::event::EventWriter;
 use bevy_ecs::system::{Res, ResMut, Resource};
 use bevy_log::warn;
+use bevy_reflect::TypeUuid;
 use bevy_tasks::IoTaskPool;
-use bevy_utils::{Entry, HashMap, Uuid};
-use crossbeam_channel::TryRecvError;
+use bevy_utils::{HashMap, HashSet, Uuid};
+use crossbeam_channel::{Receiver, Sender, TryRecvError};
 use parking_lot::{Mutex, RwLock};
-use std::{path::Path, sync::Arc};
+use serde::de::DeserializeOwned;
+use std::{
+    any::Any,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
 use thiserror::Error;
 
 /// Errors that occur while loading assets with an `AssetServer`.
@@ -21,6 +32,14 @@ pub enum AssetServerError {
     #[error("asset folder path is not a directory: {0}")]
     AssetFolderNotADirectory(String),
 
+    /// No asset saver was added for the type of the saved asset.
+    #[error("no `AssetSaver` found for assets of type {0}")]
+    MissingAssetSaver(&'static str),
+
+    /// No asset source was added with the name of the source of an asset path.
+    #[error("no asset source named `{0}` was added")]
+    MissingAssetSource(String),
+
     /// No asset loader was found for the specified extensions.
     #[error("no `AssetLoader` found{}", format_missing_asset_ext(.extensions))]
     MissingAssetLoader {
@@ -30,6 +49,16 @@ pub enum AssetServerError {
         extensions: Vec<String>,
     },
 
+    /// No asset was loaded at the provided asset path, which may have a label that the asset
+    /// loader didn't set.
+    #[error("no asset was loaded at {0:?}")]
+    MissingAsset(AssetPath<'static>),
+
+    /// An asset was loaded with [`LoadContext::load_direct`] while it was already being loaded,
+    /// which would never finish.
+    #[error("asset {0:?} loads itself directly, possibly through other assets")]
+    CyclicLoadDirect(AssetPath<'static>),
+
     /// The handle type does not match the type of the loaded asset.
     #[error("the given type does not match the type of the loaded asset")]
     IncorrectHandleType,
@@ -38,11 +67,47 @@ pub enum AssetServerError {
     #[error("encountered an error while loading an asset: {0}")]
     AssetLoaderError(anyhow::Error),
 
+    /// Encountered an error while processing an asset.
+    #[error("encountered an error while processing an asset: {0}")]
+    AssetProcessorError(anyhow::Error),
+
+    /// The `.meta` file of an asset could not be parsed.
+    #[error("invalid meta file for asset {}: {error}", .path.display())]
+    InvalidMetaFile {
+        /// The path of the asset source.
+        path: PathBuf,
+        /// The error encountered while parsing the meta file.
+        error: ron::error::SpannedError,
+    },
+
     /// Encountered an error while reading an asset from disk.
     #[error("encountered an error while reading an asset: {0}")]
     AssetIoError(#[from] AssetIoError),
 }
 
+/// An event sent when an asset fails to load.
+#[derive(Debug, Clone)]
+pub struct AssetLoadFailedEvent {
+    /// The id of the asset.
+    pub id: HandleId,
+    /// The path of the asset.
+    pub path: AssetPath<'static>,
+    /// The error that made the asset fail to load.
+    pub error: Arc<AssetServerError>,
+}
+
+/// The error of a recursive dependency of an asset that failed to load.
+///
+/// See [`AssetServer::get_dependency_load_error`].
+#[derive(Debug, Clone)]
+pub struct DependencyLoadError {
+    /// The chain of dependencies leading to the asset that failed to load, starting with a direct
+    /// dependency and ending with the failed asset.
+    pub chain: Vec<AssetPath<'static>>,
+    /// The error that made the asset fail to load.
+    pub error: Arc<AssetServerError>,
+}
+
 fn format_missing_asset_ext(exts: &[String]) -> String {
     if !exts.is_empty() {
         format!(
@@ -66,13 +131,33 @@ pub(crate) struct AssetRefCounter {
 ///
 /// [`AssetServer`] is the public API for interacting with the asset server.
 pub struct AssetServerInternal {
-    pub(crate) asset_io: Box<dyn AssetIo>,
+    pub(crate) asset_io: Arc<dyn AssetIo>,
+    pub(crate) named_sources: RwLock<HashMap<String, Arc<dyn AssetIo>>>,
+    override_sources: RwLock<Vec<String>>,
     pub(crate) asset_ref_counter: AssetRefCounter,
     pub(crate) asset_sources: Arc<RwLock<HashMap<SourcePathId, SourceInfo>>>,
     pub(crate) asset_lifecycles: Arc<RwLock<HashMap<Uuid, Box<dyn AssetLifecycle>>>>,
     loaders: RwLock<Vec<Arc<dyn AssetLoader>>>,
     extension_to_loader_index: RwLock<HashMap<String, usize>>,
+    processors: RwLock<Vec<RegisteredProcessor>>,
+    extension_to_processor_index: RwLock<HashMap<String, usize>>,
+    processed_asset_cache: RwLock<Option<Arc<ProcessedAssetCache>>>,
+    settings_overrides: RwLock<HashMap<SourcePathId, SettingsOverride>>,
+    /// The `Arc<dyn AssetSaver<Asset = T>>` of each asset type.
+    savers: RwLock<HashMap<Uuid, Box<dyn Any + Send + Sync>>>,
+    /// The assets queued with [`AssetServer::save`], by asset type.
+    pending_saves: Mutex<HashMap<Uuid, Vec<(HandleId, AssetPath<'static>)>>>,
+    load_failed_sender: Sender<AssetLoadFailedEvent>,
+    load_failed_receiver: Receiver<AssetLoadFailedEvent>,
+    /// The direct dependencies of each loaded source.
+    dependencies: RwLock<HashMap<SourcePathId, Vec<AssetPath<'static>>>>,
+    /// The assets loaded with [`LoadContext::load_direct`] by each loaded source, by the path
+    /// of the source.
+    direct_dependencies: RwLock<HashMap<AssetPath<'static>, Vec<AssetPath<'static>>>>,
+    /// The assets waiting for their recursive dependencies to load, by asset type.
+    pending_loaded_with_dependencies: Mutex<HashMap<Uuid, HashSet<AssetPathId>>>,
     handle_to_path: Arc<RwLock<HashMap<HandleId, AssetPath<'static>>>>,
+    load_queue: Mutex<LoadQueue>,
 }
 
 /// Loads assets from the filesystem in the background.
@@ -97,7 +182,7 @@ pub struct AssetServerInternal {
 /// use bevy_asset::{AssetServer, Handle};
 /// use bevy_ecs::prelude::{Commands, Res};
 ///
-/// # #[derive(Debug, bevy_reflect::TypeUuid)]
+/// # #[derive(Debug, bevy_reflect::TypePath, bevy_reflect::TypeUuid)]
 /// # #[uuid = "00000000-0000-0000-0000-000000000000"]
 /// # struct Image;
 ///
@@ -124,24 +209,100 @@ impl AssetServer {
 
     /// Creates a new asset server with a boxed asset I/O.
     pub fn with_boxed_io(asset_io: Box<dyn AssetIo>) -> Self {
+        let (load_failed_sender, load_failed_receiver) = crossbeam_channel::unbounded();
         AssetServer {
             server: Arc::new(AssetServerInternal {
                 loaders: Default::default(),
                 extension_to_loader_index: Default::default(),
+                processors: Default::default(),
+                extension_to_processor_index: Default::default(),
+                processed_asset_cache: Default::default(),
+                settings_overrides: Default::default(),
+                savers: Default::default(),
+                pending_saves: Default::default(),
+                load_failed_sender,
+                load_failed_receiver,
+                dependencies: Default::default(),
+                direct_dependencies: Default::default(),
+                pending_loaded_with_dependencies: Default::default(),
                 asset_sources: Default::default(),
                 asset_ref_counter: Default::default(),
                 handle_to_path: Default::default(),
+                load_queue: Default::default(),
                 asset_lifecycles: Default::default(),
-                asset_io,
+                asset_io: asset_io.into(),
+                named_sources: Default::default(),
+                override_sources: Default::default(),
             }),
         }
     }
 
     /// Returns the associated asset I/O.
+    ///
+    /// This is the default asset source, which asset paths without a source are read from.
     pub fn asset_io(&self) -> &dyn AssetIo {
         &*self.server.asset_io
     }
 
+    /// Adds a named asset source, which assets are loaded from when their path starts with
+    /// `name://`.
+    ///
+    /// Adding a source with the same name as an existing source replaces it.
+    pub fn add_source<T: AssetIo>(&self, name: impl Into<String>, asset_io: T) {
+        self.server
+            .named_sources
+            .write()
+            .insert(name.into(), Arc::new(asset_io));
+    }
+
+    /// Gets the asset I/O of the named asset source.
+    pub fn get_source(&self, name: &str) -> Option<Arc<dyn AssetIo>> {
+        self.server.named_sources.read().get(name).cloned()
+    }
+
+    /// Sets the named asset sources that can override the assets of the default source.
+    ///
+    /// When loading an asset path without a source, the override sources are searched in order
+    /// and the asset is read from the first one containing a file at that path, falling back to
+    /// the default source. This lets a `mods` source replace the assets of the base game.
+    pub fn set_override_sources<S: Into<String>>(&self, names: impl IntoIterator<Item = S>) {
+        *self.server.override_sources.write() = names.into_iter().map(Into::into).collect();
+    }
+
+    #[cfg(all(
+        feature = "filesystem_watcher",
+        all(not(target_arch = "wasm32"), not(target_os = "android"))
+    ))]
+    pub(crate) fn is_override_source(&self, name: &str) -> bool {
+        self.server
+            .override_sources
+            .read()
+            .iter()
+            .any(|source| source == name)
+    }
+
+    /// Returns the asset I/O that the asset at the provided path is read from.
+    fn resolve_asset_io(
+        &self,
+        asset_path: &AssetPath,
+    ) -> Result<Arc<dyn AssetIo>, AssetServerError> {
+        let named_sources = self.server.named_sources.read();
+        if let Some(source) = asset_path.source() {
+            return named_sources
+                .get(source)
+                .cloned()
+                .ok_or_else(|| AssetServerError::MissingAssetSource(source.to_string()));
+        }
+        for source in self.server.override_sources.read().iter() {
+            if let Some(asset_io) = named_sources.get(source) {
+                if asset_io.is_file(asset_path.path()) {
+                    return Ok(asset_io.clone());
+                }
+            }
+        }
+        Ok(self.server.asset_io.clone())
+    }
+
     pub(crate) fn register_asset_type<T: Asset>(&self) -> Assets<T> {
         if self
             .server
@@ -175,6 +336,126 @@ impl AssetServer {
         loaders.push(Arc::new(loader));
     }
 
+    /// Adds the provided asset processor to the server.
+    ///
+    /// If `processor` has one or more supported extensions in conflict with processors that came
+    /// before it, it will replace them.
+    pub fn add_processor<T>(&self, processor: T)
+    where
+        T: AssetProcessor,
+    {
+        let mut processors = self.server.processors.write();
+        let processor_index = processors.len();
+        for extension in processor.extensions() {
+            self.server
+                .extension_to_processor_index
+                .write()
+                .insert(extension.to_string(), processor_index);
+        }
+        processors.push(RegisteredProcessor {
+            processor: Arc::new(processor),
+            type_name: std::any::type_name::<T>(),
+        });
+    }
+
+    /// Sets the folder on the local filesystem where the output of [`AssetProcessor`]s is cached.
+    ///
+    /// Without a processed asset folder, assets are processed again every time they are loaded.
+    pub fn set_processed_asset_folder<P: Into<PathBuf>>(&self, path: P) {
+        *self.server.processed_asset_cache.write() = Some(Arc::new(ProcessedAssetCache {
+            root_path: path.into(),
+        }));
+    }
+
+    /// Returns the folder where the output of [`AssetProcessor`]s is cached, if any.
+    pub fn processed_asset_folder(&self) -> Option<PathBuf> {
+        self.server
+            .processed_asset_cache
+            .read()
+            .as_ref()
+            .map(|cache| cache.root_path.clone())
+    }
+
+    /// Adds the provided asset saver to the server.
+    ///
+    /// Returns `false` if it replaced the saver of the same asset type.
+    pub(crate) fn add_saver<T: AssetSaver>(&self, saver: T) -> bool {
+        let saver: Arc<dyn AssetSaver<Asset = T::Asset>> = Arc::new(saver);
+        self.server
+            .savers
+            .write()
+            .insert(<T::Asset as TypeUuid>::TYPE_UUID, Box::new(saver))
+            .is_none()
+    }
+
+    pub(crate) fn get_saver<T: Asset>(&self) -> Option<Arc<dyn AssetSaver<Asset = T>>> {
+        self.server
+            .savers
+            .read()
+            .get(&T::TYPE_UUID)?
+            .downcast_ref::<Arc<dyn AssetSaver<Asset = T>>>()
+            .cloned()
+    }
+
+    /// Queues the asset of the provided handle to be saved at the provided path, using the
+    /// [`AssetSaver`] of its type.
+    ///
+    /// The asset is serialized at the end of the frame and then written to the asset source of
+    /// the path in the background. If the path has no extension, the extension of the saver is
+    /// appended to it. Saving an asset into a watched [`FileAssetIo`](crate::FileAssetIo) doesn't
+    /// reload it.
+    ///
+    /// # Errors
+    ///
+    /// If no saver was added for the type of the asset, it will fail with
+    /// [`AssetServerError::MissingAssetSaver`].
+    pub fn save<'a, T: Asset, P: Into<AssetPath<'a>>>(
+        &self,
+        handle: &Handle<T>,
+        path: P,
+    ) -> Result<(), AssetServerError> {
+        let Some(saver) = self.get_saver::<T>() else {
+            return Err(AssetServerError::MissingAssetSaver(
+                std::any::type_name::<T>(),
+            ));
+        };
+        let path: AssetPath = path.into();
+        let mut file_path = path.path().to_owned();
+        if file_path.extension().is_none() {
+            file_path.set_extension(saver.extension());
+        }
+        let mut asset_path = AssetPath::new(file_path, None);
+        if let Some(source) = path.source() {
+            asset_path = asset_path.with_source(source.to_string());
+        }
+        self.server
+            .pending_saves
+            .lock()
+            .entry(T::TYPE_UUID)
+            .or_default()
+            .push((handle.id(), asset_path));
+        Ok(())
+    }
+
+    pub(crate) fn take_pending_saves<T: Asset>(&self) -> Vec<(HandleId, AssetPath<'static>)> {
+        self.server
+            .pending_saves
+            .lock()
+            .remove(&T::TYPE_UUID)
+            .unwrap_or_default()
+    }
+
+    /// Writes the bytes of a saved asset to the asset source of its path.
+    pub(crate) async fn write_asset(
+        &self,
+        asset_path: &AssetPath<'_>,
+        bytes: &[u8],
+    ) -> Result<(), AssetServerError> {
+        let asset_io = self.resolve_asset_io(asset_path)?;
+        asset_io.save_path(asset_path.path(), bytes).await?;
+        Ok(())
+    }
+
     /// Gets a strong handle for an asset with the provided id.
     pub fn get_handle<T: Asset, I: Into<HandleId>>(&self, id: I) -> Handle<T> {
         let sender = self.server.asset_ref_counter.channel.sender.clone();
@@ -230,6 +511,115 @@ impl AssetServer {
         })
     }
 
+    fn get_path_asset_processor<P: AsRef<Path>>(&self, path: P) -> Option<RegisteredProcessor> {
+        let file_name = path.as_ref().file_name()?.to_str()?.to_lowercase();
+        let map = self.server.extension_to_processor_index.read();
+        let mut ext = file_name.as_str();
+        while let Some(idx) = ext.find('.') {
+            ext = &ext[idx + 1..];
+            if let Some(&index) = map.get(ext) {
+                return Some(self.server.processors.read()[index].clone());
+            }
+        }
+        None
+    }
+
+    /// Reads the bytes of the asset source at `path`, running them through its
+    /// [`AssetProcessor`] if it has one.
+    async fn read_source(
+        &self,
+        asset_io: &dyn AssetIo,
+        asset_path: &AssetPath<'_>,
+    ) -> Result<ProcessedSource, AssetServerError> {
+        let path = asset_path.path();
+        let bytes = asset_io.load_path(path).await?;
+        let Some(processor) = self.get_path_asset_processor(path) else {
+            return Ok(ProcessedSource {
+                path: path.to_owned(),
+                bytes,
+                processed: false,
+            });
+        };
+
+        // the assets of named sources are cached in a subfolder named after the source
+        let cache = self.server.processed_asset_cache.read().clone();
+        let cache = match (cache, asset_path.source()) {
+            (Some(cache), Some(source)) => Some(Arc::new(ProcessedAssetCache {
+                root_path: cache.root_path.join(source),
+            })),
+            (cache, _) => cache,
+        };
+        process_source(&processor, path, bytes, asset_io, cache.as_deref())
+            .await
+            .map_err(AssetServerError::AssetProcessorError)
+    }
+
+    /// Runs the [`AssetProcessor`]s on the assets in the specified folder recursively, waiting
+    /// for them to finish.
+    ///
+    /// Assets whose processed form is already cached in the
+    /// [processed asset folder](AssetServer::set_processed_asset_folder) are skipped, so this can
+    /// be called at startup, or from a separate tool, to process assets ahead of loading them.
+    /// Returns the paths of the assets that were processed.
+    ///
+    /// # Errors
+    ///
+    /// - If the provided path is not a directory, it will fail with
+    /// [`AssetServerError::AssetFolderNotADirectory`].
+    /// - If an asset fails to be read or processed, the first error is returned once all the
+    /// other assets are processed.
+    pub fn process_folder<'a, P: Into<AssetPath<'a>>>(
+        &self,
+        path: P,
+    ) -> Result<Vec<PathBuf>, AssetServerError> {
+        let path: AssetPath = path.into();
+        let asset_io = self.resolve_asset_io(&path)?;
+        let mut paths = Vec::new();
+        self.collect_processed_paths(&*asset_io, path.path(), &mut paths)?;
+
+        let source = path.source();
+        let asset_io = &*asset_io;
+        let results = IoTaskPool::get().scope(|scope| {
+            for path in &paths {
+                scope.spawn(async move {
+                    let mut asset_path = AssetPath::new_ref(path, None);
+                    if let Some(source) = source {
+                        asset_path = asset_path.with_source(source);
+                    }
+                    let processed_source = self.read_source(asset_io, &asset_path).await?;
+                    Ok::<_, AssetServerError>(processed_source.processed.then(|| path.clone()))
+                });
+            }
+        });
+        let mut processed = Vec::new();
+        for result in results {
+            processed.extend(result?);
+        }
+        Ok(processed)
+    }
+
+    fn collect_processed_paths(
+        &self,
+        asset_io: &dyn AssetIo,
+        path: &Path,
+        paths: &mut Vec<PathBuf>,
+    ) -> Result<(), AssetServerError> {
+        if !asset_io.is_dir(path) {
+            return Err(AssetServerError::AssetFolderNotADirectory(
+                path.to_str().unwrap().to_string(),
+            ));
+        }
+
+        for child_path in asset_io.read_directory(path)? {
+            if asset_io.is_dir(&child_path) {
+                self.collect_processed_paths(asset_io, &child_path, paths)?;
+            } else if self.get_path_asset_processor(&child_path).is_some() {
+                paths.push(child_path);
+            }
+        }
+        Ok(())
+    }
+
     /// Gets the source path of an asset from the provided handle.
     pub fn get_handle_path<H: Into<HandleId>>(&self, handle: H) -> Option<AssetPath<'_>> {
         self.server
@@ -252,15 +642,78 @@ impl AssetServer {
         }
     }
 
+    /// Gets the error that made an asset fail to load from the provided handle.
+    ///
+    /// Returns `None` if the asset has not failed to load, or if it is being loaded again.
+    pub fn get_load_error<H: Into<HandleId>>(&self, handle: H) -> Option<Arc<AssetServerError>> {
+        match handle.into() {
+            HandleId::AssetPathId(id) => self
+                .server
+                .asset_sources
+                .read()
+                .get(&id.source_path_id())
+                .and_then(|info| info.load_error.clone()),
+            HandleId::Id(_, _) => None,
+        }
+    }
+
+    /// Gets the error of a recursive dependency of an asset that failed to load, from the
+    /// provided handle.
+    ///
+    /// Dependencies are only known once an asset is loaded, so this returns `None` until then.
+    pub fn get_dependency_load_error<H: Into<HandleId>>(
+        &self,
+        handle: H,
+    ) -> Option<DependencyLoadError> {
+        let HandleId::AssetPathId(id) = handle.into() else {
+            return None;
+        };
+        let asset_sources = self.server.asset_sources.read();
+        let dependencies = self.server.dependencies.read();
+        let mut visited = HashSet::default();
+        visited.insert(id.source_path_id());
+        let mut chain = Vec::new();
+        find_failed_dependency(
+            &asset_sources,
+            &dependencies,
+            id.source_path_id(),
+            &mut visited,
+            &mut chain,
+        )
+        .map(|error| DependencyLoadError { chain, error })
+    }
+
+    /// Gets the load state of an asset and of all of its recursive dependencies from the provided
+    /// handle.
+    ///
+    /// Unlike [`get_load_state`](AssetServer::get_load_state), this only returns
+    /// [`LoadState::Loaded`] once the asset and every asset it depends on, directly or through
+    /// other dependencies, are loaded. It returns [`LoadState::Failed`] if any of them failed to
+    /// load.
+    pub fn get_recursive_dependency_load_state<H: Into<HandleId>>(&self, handle: H) -> LoadState {
+        let HandleId::AssetPathId(id) = handle.into() else {
+            return LoadState::NotLoaded;
+        };
+        let asset_sources = self.server.asset_sources.read();
+        let dependencies = self.server.dependencies.read();
+        recursive_dependency_load_state(&asset_sources, &dependencies, id.source_path_id())
+    }
+
     /// Gets the overall load state of a group of assets from the provided handles.
     ///
     /// This method will only return [`LoadState::Loaded`] if all assets in the
-    /// group were loaded successfully.
+    /// group were loaded successfully. It returns [`LoadState::Failed`] if any asset in the
+    /// group, or any of their recursive dependencies, failed to load.
     pub fn get_group_load_state(&self, handles: impl IntoIterator<Item = HandleId>) -> LoadState {
         let mut load_state = LoadState::Loaded;
         for handle_id in handles {
             match handle_id {
                 HandleId::AssetPathId(id) => match self.get_load_state(id) {
+                    LoadState::Loaded | LoadState::Loading
+                        if self.get_dependency_load_error(id).is_some() =>
+                    {
+                        return LoadState::Failed
+                    }
                     LoadState::Loaded => continue,
                     LoadState::Loading => {
                         load_state = LoadState::Loading;
@@ -304,28 +757,120 @@ impl AssetServer {
         self.load_untyped(path).typed()
     }
 
+    /// Queues an [`Asset`] at the provided relative path for asynchronous loading with the
+    /// provided priority.
+    ///
+    /// The loads exceeding the [load limits](AssetServer::set_load_limits) are started by
+    /// priority. Dropping every strong handle to the asset before it is loaded cancels the load.
+    ///
+    /// See [`load`](AssetServer::load).
+    #[must_use = "not using the returned strong handle may result in the unexpected release of the asset"]
+    pub fn load_with_priority<'a, T: Asset, P: Into<AssetPath<'a>>>(
+        &self,
+        path: P,
+        priority: LoadPriority,
+    ) -> Handle<T> {
+        let handle_id = self.load_untracked_with_priority(path.into(), false, priority);
+        self.get_handle(handle_id)
+    }
+
+    /// Changes the priority of an asset whose load is queued, returning `false` if the load isn't
+    /// queued anymore.
+    ///
+    /// This is useful to reprioritize streamed content as it gets closer.
+    pub fn set_load_priority<H: Into<HandleId>>(&self, handle: H, priority: LoadPriority) -> bool {
+        match handle.into() {
+            HandleId::AssetPathId(id) => self.server.load_queue.lock().set_priority(id, priority),
+            HandleId::Id(..) => false,
+        }
+    }
+
+    /// Sets the limits on the loads running at the same time.
+    ///
+    /// The loads are unlimited by default. See [`AssetLoadLimits`].
+    pub fn set_load_limits(&self, limits: AssetLoadLimits) {
+        self.server.load_queue.lock().limits = limits;
+        self.start_queued_loads();
+    }
+
+    /// Returns the limits on the loads running at the same time.
+    pub fn load_limits(&self) -> AssetLoadLimits {
+        self.server.load_queue.lock().limits
+    }
+
+    /// Queues an [`Asset`] at the provided relative path for asynchronous loading, modifying the
+    /// settings of its loader.
+    ///
+    /// The settings are read from the `.meta` file of the asset as usual, and then passed to
+    /// `settings`, which only applies to loaders reading settings of type `S` with
+    /// [`LoadContext::settings`]. The function is kept for later reloads of the asset, and the
+    /// asset is reloaded if it was already loaded.
+    ///
+    /// See [`load`](AssetServer::load).
+    #[must_use = "not using the returned strong handle may result in the unexpected release of the asset"]
+    pub fn load_with_settings<'a, T, S, P>(
+        &self,
+        path: P,
+        settings: impl Fn(&mut S) + Send + Sync + 'static,
+    ) -> Handle<T>
+    where
+        T: Asset,
+        S: DeserializeOwned + Default + 'static,
+        P: Into<AssetPath<'a>>,
+    {
+        let asset_path = path.into();
+        let settings_override: SettingsOverride = Arc::new(move |value: &mut dyn Any| {
+            if let Some(value) = value.downcast_mut::<S>() {
+                settings(value);
+            }
+        });
+        self.server
+            .settings_overrides
+            .write()
+            .insert(asset_path.get_id().source_path_id(), settings_override);
+        let handle_id = self.load_untracked(asset_path, true);
+        self.get_handle(handle_id)
+    }
+
+    /// Reads the `.meta` file of the asset source at `path`, if it has one.
+    async fn read_meta(
+        &self,
+        asset_io: &dyn AssetIo,
+        path: &Path,
+    ) -> Result<Option<LoaderMeta>, AssetServerError> {
+        let bytes = match asset_io.load_path(&LoaderMeta::path_for(path)).await {
+            Ok(bytes) => bytes,
+            Err(AssetIoError::NotFound(_)) => return Ok(None),
+            Err(err) => return Err(err.into()),
+        };
+        LoaderMeta::from_bytes(&bytes).map(Some).map_err(|error| {
+            AssetServerError::InvalidMetaFile {
+                path: path.to_owned(),
+                error,
+            }
+        })
+    }
+
+    /// Loads the asset at the provided path.
+    ///
+    /// Loads started from the load queue pass the version their asset source had when they were
+    /// queued, and are skipped if the source changed version since, as the load was cancelled or
+    /// replaced by a newer one.
     async fn load_async(
         &self,
         asset_path: AssetPath<'_>,
         force: bool,
-    ) -> Result<AssetPathId, AssetServerError> {
+        queued_version: Option<usize>,
+    ) -> Result<AssetPathId, Arc<AssetServerError>> {
         let asset_path_id: AssetPathId = asset_path.get_id();
 
         // load metadata and update source info. this is done in a scope to ensure we release the
         // locks before loading
         let version = {
             let mut asset_sources = self.server.asset_sources.write();
-            let source_info = match asset_sources.entry(asset_path_id.source_path_id()) {
-                Entry::Occupied(entry) => entry.into_mut(),
-                Entry::Vacant(entry) => entry.insert(SourceInfo {
-                    asset_types: Default::default(),
-                    committed_assets: Default::default(),
-                    load_state: LoadState::NotLoaded,
-                    meta: None,
-                    path: asset_path.path().to_owned(),
-                    version: 0,
-                }),
-            };
+            let source_info = asset_sources
+                .entry(asset_path_id.source_path_id())
+                .or_insert_with(|| SourceInfo::new(asset_path.path().to_owned()));
 
             // if asset is already loaded or is loading, don't load again
             if !force
@@ -336,55 +881,87 @@ impl AssetServer {
             {
                 return Ok(asset_path_id);
             }
+            if matches!(queued_version, Some(version) if version != source_info.version) {
+                return Ok(asset_path_id);
+            }
 
             source_info.load_state = LoadState::Loading;
+            source_info.load_error = None;
             source_info.committed_assets.clear();
             source_info.version += 1;
             source_info.meta = None;
             source_info.version
         };
 
-        let set_asset_failed = || {
+        let set_asset_failed = |err: AssetServerError| {
+            let err = Arc::new(err);
             let mut asset_sources = self.server.asset_sources.write();
             let source_info = asset_sources
                 .get_mut(&asset_path_id.source_path_id())
                 .expect("`AssetSource` should exist at this point.");
             source_info.load_state = LoadState::Failed;
+            source_info.load_error = Some(err.clone());
+            // The receiver is owned by the server, so this can't fail.
+            let _ = self.server.load_failed_sender.send(AssetLoadFailedEvent {
+                id: asset_path_id.into(),
+                path: asset_path.to_owned(),
+                error: err.clone(),
+            });
+            err
         };
 
-        // get the according asset loader
-        let asset_loader = match self.get_path_asset_loader(asset_path.path()) {
-            Ok(loader) => loader,
+        let asset_io = match self.resolve_asset_io(&asset_path) {
+            Ok(asset_io) => asset_io,
             Err(err) => {
-                set_asset_failed();
-                return Err(err);
+                return Err(set_asset_failed(err));
             }
         };
 
-        // load the asset bytes
-        let bytes = match self.asset_io().load_path(asset_path.path()).await {
-            Ok(bytes) => bytes,
-            Err(err) => {
-                set_asset_failed();
-                return Err(AssetServerError::AssetIoError(err));
-            }
-        };
+        let (source, meta, asset_loader) =
+            match self.read_source_and_loader(&*asset_io, &asset_path).await {
+                Ok(result) => result,
+                Err(err) => {
+                    return Err(set_asset_failed(err));
+                }
+            };
+
+        // don't run the asset loader if the load was cancelled or replaced by a newer one
+        let source_version = self
+            .server
+            .asset_sources
+            .read()
+            .get(&asset_path_id.source_path_id())
+            .map(|source_info| source_info.version);
+        if source_version != Some(version) {
+            return Ok(asset_path_id);
+        }
 
         // load the asset source using the corresponding AssetLoader
         let mut load_context = LoadContext::new(
             asset_path.path(),
             &self.server.asset_ref_counter.channel,
-            self.asset_io(),
+            &*asset_io,
+            self,
             version,
         );
+        load_context.processed_path = &source.path;
+        load_context.source = asset_path.source();
+        load_context.meta = meta.as_ref();
+        load_context.load_chain = vec![asset_path.get_id().source_path_id()];
+        let settings_override = self
+            .server
+            .settings_overrides
+            .read()
+            .get(&asset_path_id.source_path_id())
+            .cloned();
+        load_context.settings_override = settings_override.as_ref();
 
         if let Err(err) = asset_loader
-            .load(&bytes, &mut load_context)
+            .load(&source.bytes, &mut load_context)
             .await
             .map_err(AssetServerError::AssetLoaderError)
         {
-            set_asset_failed();
-            return Err(err);
+            return Err(set_asset_failed(err));
         }
 
         // if version has changed since we loaded and grabbed a lock, return. there is a newer
@@ -411,23 +988,131 @@ impl AssetServer {
             assets: load_context.get_asset_metas(),
         });
 
-        // load asset dependencies and prepare asset type hashmap
-        for (label, loaded_asset) in &mut load_context.labeled_assets {
-            let label_id = LabelId::from(label.as_ref().map(|label| label.as_str()));
+        // load asset dependencies with the priority of this asset and prepare asset type hashmap
+        let priority = self
+            .server
+            .load_queue
+            .lock()
+            .priority(asset_path_id.source_path_id())
+            .unwrap_or_default();
+        let mut dependencies = Vec::new();
+        let mut pending = self.server.pending_loaded_with_dependencies.lock();
+        for (label, loaded_asset) in &load_context.labeled_assets {
+            let label = label.as_deref();
             let type_uuid = loaded_asset.value.as_ref().unwrap().type_uuid();
-            source_info.asset_types.insert(label_id, type_uuid);
+            source_info
+                .asset_types
+                .insert(LabelId::from(label), type_uuid);
+            pending
+                .entry(type_uuid)
+                .or_default()
+                .insert(load_context.get_asset_path(label).get_id());
             for dependency in &loaded_asset.dependencies {
-                self.load_untracked(dependency.clone(), false);
+                if !dependencies.contains(dependency) {
+                    dependencies.push(dependency.clone());
+                }
             }
         }
+        drop(pending);
+        self.server
+            .dependencies
+            .write()
+            .insert(asset_path_id.source_path_id(), dependencies.clone());
+        let direct_dependencies = std::mem::take(&mut load_context.direct_dependencies);
+        self.server.direct_dependencies.write().insert(
+            load_context.get_asset_path(None).to_owned(),
+            direct_dependencies,
+        );
+        // queuing a load locks the asset sources
+        drop(asset_sources);
+        for dependency in dependencies {
+            self.load_untracked_with_priority(dependency, false, priority);
+        }
 
-        self.asset_io()
-            .watch_path_for_changes(asset_path.path())
-            .unwrap();
+        asset_io.watch_path_for_changes(asset_path.path()).unwrap();
         self.create_assets_in_load_context(&mut load_context);
         Ok(asset_path_id)
     }
 
+    /// Reads the source and the meta file of the asset at the provided path, and gets the asset
+    /// loader for it.
+    async fn read_source_and_loader(
+        &self,
+        asset_io: &dyn AssetIo,
+        asset_path: &AssetPath<'_>,
+    ) -> Result<(ProcessedSource, Option<LoaderMeta>, Arc<dyn AssetLoader>), AssetServerError> {
+        // load the asset bytes, processing them if needed
+        let source = self.read_source(asset_io, asset_path).await?;
+        let meta = self.read_meta(asset_io, asset_path.path()).await?;
+
+        // get the according asset loader, which may be overridden by the meta file
+        let asset_loader = match meta.as_ref().and_then(LoaderMeta::loader) {
+            Some(extension) => self.get_asset_loader(extension),
+            None => self.get_path_asset_loader(&source.path),
+        }?;
+        Ok((source, meta, asset_loader))
+    }
+
+    /// Loads the asset at the provided path without adding it to its asset storage, returning it
+    /// to the asset loader of the asset depending on it.
+    ///
+    /// The assets loaded directly by the nested asset loader are added to
+    /// `direct_dependencies`, so that changing any of them reloads the depending asset.
+    pub(crate) async fn load_direct_async<T: Asset>(
+        &self,
+        asset_path: &AssetPath<'_>,
+        load_chain: Vec<SourcePathId>,
+        direct_dependencies: &mut Vec<AssetPath<'static>>,
+    ) -> Result<T, AssetServerError> {
+        let asset_io = self.resolve_asset_io(asset_path)?;
+        let (source, meta, asset_loader) =
+            self.read_source_and_loader(&*asset_io, asset_path).await?;
+
+        let mut load_context = LoadContext::new(
+            asset_path.path(),
+            &self.server.asset_ref_counter.channel,
+            &*asset_io,
+            self,
+            0,
+        );
+        load_context.processed_path = &source.path;
+        load_context.source = asset_path.source();
+        load_context.meta = meta.as_ref();
+        load_context.load_chain = load_chain;
+        let settings_override = self
+            .server
+            .settings_overrides
+            .read()
+            .get(&asset_path.get_id().source_path_id())
+            .cloned();
+        load_context.settings_override = settings_override.as_ref();
+        asset_loader
+            .load(&source.bytes, &mut load_context)
+            .await
+            .map_err(AssetServerError::AssetLoaderError)?;
+
+        for dependency in load_context.direct_dependencies.drain(..) {
+            if !direct_dependencies.contains(&dependency) {
+                direct_dependencies.push(dependency);
+            }
+        }
+        let mut labeled_assets = std::mem::take(&mut load_context.labeled_assets);
+        let asset = labeled_assets
+            .remove(&asset_path.label().map(ToString::to_string))
+            .ok_or_else(|| AssetServerError::MissingAsset(asset_path.to_owned()))?;
+        // the handles held by the asset should point to loaded assets
+        for dependency in &asset.dependencies {
+            self.load_untracked(dependency.clone(), false);
+        }
+        let value = asset
+            .value
+            .ok_or_else(|| AssetServerError::MissingAsset(asset_path.to_owned()))?;
+        value
+            .downcast::<T>()
+            .map(|value| *value)
+            .map_err(|_| AssetServerError::IncorrectHandleType)
+    }
+
     /// Queues the [`Asset`] at the provided path for loading and returns an untyped handle.
     ///
     /// See [`load`](AssetServer::load).
@@ -441,20 +1126,64 @@ impl AssetServer {
     ///
     /// This is useful for custom hot-reloading or for supporting `watch_for_changes`
     /// in custom [`AssetIo`] implementations.
+    ///
+    /// The assets which loaded this asset with [`LoadContext::load_direct`] are reloaded too.
     pub fn reload_asset<'a, P: Into<AssetPath<'a>>>(&self, path: P) {
-        self.load_untracked(path.into(), true);
+        let mut reloaded = HashSet::default();
+        let mut queue = vec![path.into().to_owned()];
+        while let Some(asset_path) = queue.pop() {
+            let source_path_id = asset_path.get_id().source_path_id();
+            if !reloaded.insert(source_path_id) {
+                continue;
+            }
+            self.load_untracked(asset_path, true);
+            for (dependent, dependencies) in self.server.direct_dependencies.read().iter() {
+                if dependencies
+                    .iter()
+                    .any(|dependency| dependency.get_id().source_path_id() == source_path_id)
+                {
+                    queue.push(dependent.clone());
+                }
+            }
+        }
     }
 
     pub(crate) fn load_untracked(&self, asset_path: AssetPath<'_>, force: bool) -> HandleId {
-        let server = self.clone();
-        let owned_path = asset_path.to_owned();
-        IoTaskPool::get()
-            .spawn(async move {
-                if let Err(err) = server.load_async(owned_path, force).await {
-                    warn!("{}", err);
-                }
-            })
-            .detach();
+        self.load_untracked_with_priority(asset_path, force, LoadPriority::NORMAL)
+    }
+
+    pub(crate) fn load_untracked_with_priority(
+        &self,
+        asset_path: AssetPath<'_>,
+        force: bool,
+        priority: LoadPriority,
+    ) -> HandleId {
+        let asset_path_id = asset_path.get_id();
+        {
+            let mut asset_sources = self.server.asset_sources.write();
+            let source_info = asset_sources
+                .entry(asset_path_id.source_path_id())
+                .or_insert_with(|| SourceInfo::new(asset_path.path().to_owned()));
+
+            // if asset is already loaded, loading or queued, don't load again
+            if force
+                || !(source_info
+                    .committed_assets
+                    .contains(&asset_path_id.label_id())
+                    || source_info.load_state == LoadState::Loading)
+            {
+                // queued loads are reported as loading, and replace the previous loads
+                source_info.load_state = LoadState::Loading;
+                source_info.load_error = None;
+                source_info.version += 1;
+                self.server.load_queue.lock().push(
+                    asset_path.to_owned(),
+                    priority,
+                    source_info.version,
+                );
+            }
+        }
+        self.start_queued_loads();
 
         let handle_id = asset_path.get_id().into();
         self.server
@@ -466,53 +1195,158 @@ impl AssetServer {
         asset_path.into()
     }
 
-    /// Loads assets from the specified folder recursively.
+    /// Starts the queued loads with the highest priority, as long as the load limits allow it.
+    fn start_queued_loads(&self) {
+        let mut load_queue = self.server.load_queue.lock();
+        while load_queue.can_start() {
+            let Some(load) = load_queue.start_next() else {
+                break;
+            };
+            // the bytes are counted before the source is read, so that the loads started
+            // together are limited too
+            let bytes = self.source_size(&load.asset_path);
+            load_queue.bytes_in_flight += bytes;
+            let server = self.clone();
+            IoTaskPool::get()
+                .spawn(async move { server.run_queued_load(load, bytes).await })
+                .detach();
+        }
+    }
+
+    /// Runs a load started with [`LoadQueue::start_next`], then starts the next queued loads.
     ///
-    /// # Errors
+    /// `bytes` is the size of the source counted towards the bytes in flight when the load was
+    /// started.
+    async fn run_queued_load(&self, load: QueuedLoad, bytes: usize) {
+        let source_path_id = load.asset_path.get_id().source_path_id();
+        // the load is skipped if it was cancelled or replaced since it was queued
+        if let Err(err) = self
+            .load_async(load.asset_path, true, Some(load.version))
+            .await
+        {
+            warn!("{}", err);
+        }
+        let mut load_queue = self.server.load_queue.lock();
+        load_queue.finish(source_path_id);
+        load_queue.bytes_in_flight -= bytes;
+        drop(load_queue);
+        self.start_queued_loads();
+    }
+
+    /// Returns the size of the source of an asset, counted towards
+    /// [`AssetLoadLimits::max_bytes_in_flight`] while it is being loaded.
     ///
-    /// - If the provided path is not a directory, it will fail with
-    /// [`AssetServerError::AssetFolderNotADirectory`].
-    /// - If something unexpected happened while loading an asset, other
-    /// [`AssetServerError`]s may be returned.
-    #[must_use = "not using the returned strong handles may result in the unexpected release of the assets"]
-    pub fn load_folder<P: AsRef<Path>>(
+    /// Sources whose size isn't known from their [`Metadata`](crate::Metadata) count as empty.
+    fn source_size(&self, asset_path: &AssetPath) -> usize {
+        self.resolve_asset_io(asset_path)
+            .ok()
+            .and_then(|asset_io| asset_io.get_metadata(asset_path.path()).ok())
+            .and_then(|metadata| metadata.size())
+            .map_or(0, |size| size as usize)
+    }
+
+    /// Returns `true` if a strong handle to any asset of the asset source is left.
+    fn source_has_handles(&self, source_path_id: SourcePathId) -> bool {
+        self.server
+            .asset_ref_counter
+            .ref_counts
+            .read()
+            .iter()
+            .any(|(id, count)| {
+                *count > 0
+                    && matches!(id, HandleId::AssetPathId(id) if id.source_path_id() == source_path_id)
+            })
+    }
+
+    /// Cancels the load of an asset source.
+    ///
+    /// Queued loads are removed from the queue, and running loads stop before running their
+    /// asset loader, or before committing their assets. The load state of the source goes back to
+    /// [`LoadState::NotLoaded`].
+    fn cancel_load(&self, source_path_id: SourcePathId) {
+        self.server.load_queue.lock().remove(source_path_id);
+        if let Some(source_info) = self.server.asset_sources.write().get_mut(&source_path_id) {
+            if source_info.load_state == LoadState::Loading {
+                source_info.load_state = LoadState::NotLoaded;
+                source_info.version += 1;
+            }
+        }
+    }
+
+    /// Loads assets from the specified folder recursively.
+    ///
+    /// The folder is listed from the asset source of the path, or from the default source if the
+    /// path has no source.
+    ///
+    /// # Errors
+    ///
+    /// - If the provided path is not a directory, it will fail with
+    /// [`AssetServerError::AssetFolderNotADirectory`].
+    /// - If something unexpected happened while loading an asset, other
+    /// [`AssetServerError`]s may be returned.
+    #[must_use = "not using the returned strong handles may result in the unexpected release of the assets"]
+    pub fn load_folder<'a, P: Into<AssetPath<'a>>>(
         &self,
         path: P,
     ) -> Result<Vec<HandleUntyped>, AssetServerError> {
-        let path = path.as_ref();
-        if !self.asset_io().is_dir(path) {
+        let path: AssetPath = path.into();
+        let asset_io = match path.source() {
+            Some(_) => self.resolve_asset_io(&path)?,
+            None => self.server.asset_io.clone(),
+        };
+        let mut handles = Vec::new();
+        self.load_folder_from(&*asset_io, path.source(), path.path(), &mut handles)?;
+        Ok(handles)
+    }
+
+    fn load_folder_from(
+        &self,
+        asset_io: &dyn AssetIo,
+        source: Option<&str>,
+        path: &Path,
+        handles: &mut Vec<HandleUntyped>,
+    ) -> Result<(), AssetServerError> {
+        if !asset_io.is_dir(path) {
             return Err(AssetServerError::AssetFolderNotADirectory(
                 path.to_str().unwrap().to_string(),
             ));
         }
 
-        let mut handles = Vec::new();
-        for child_path in self.asset_io().read_directory(path.as_ref())? {
-            if self.asset_io().is_dir(&child_path) {
-                handles.extend(self.load_folder(&child_path)?);
+        for child_path in asset_io.read_directory(path)? {
+            if asset_io.is_dir(&child_path) {
+                self.load_folder_from(asset_io, source, &child_path, handles)?;
             } else {
-                if self.get_path_asset_loader(&child_path).is_err() {
+                if self.get_path_asset_loader(&child_path).is_err()
+                    && self.get_path_asset_processor(&child_path).is_none()
+                {
                     continue;
                 }
-                let handle =
-                    self.load_untyped(child_path.to_str().expect("Path should be a valid string."));
-                handles.push(handle);
+                let mut child_path = AssetPath::from(child_path);
+                if let Some(source) = source {
+                    child_path = child_path.with_source(source.to_string());
+                }
+                handles.push(self.load_untyped(child_path));
             }
         }
 
-        Ok(handles)
+        Ok(())
     }
 
     /// Frees unused assets, unloading them from memory.
     pub fn free_unused_assets(&self) {
         let mut potential_frees = self.server.asset_ref_counter.mark_unused_assets.lock();
 
+        let mut unused_sources = Vec::new();
         if !potential_frees.is_empty() {
             let ref_counts = self.server.asset_ref_counter.ref_counts.read();
             let asset_sources = self.server.asset_sources.read();
             let asset_lifecycles = self.server.asset_lifecycles.read();
             for potential_free in potential_frees.drain(..) {
                 if let Some(&0) = ref_counts.get(&potential_free) {
+                    if let HandleId::AssetPathId(id) = potential_free {
+                        unused_sources.push(id.source_path_id());
+                    }
+
                     let type_uuid = match potential_free {
                         HandleId::Id(type_uuid, _) => Some(type_uuid),
                         HandleId::AssetPathId(id) => asset_sources
@@ -528,6 +1362,21 @@ impl AssetServer {
                 }
             }
         }
+        drop(potential_frees);
+
+        // stop loading the asset sources whose handles were all dropped, and forget their
+        // dependencies
+        for source_path_id in unused_sources {
+            if self.source_has_handles(source_path_id) {
+                continue;
+            }
+            self.cancel_load(source_path_id);
+            self.server.dependencies.write().remove(&source_path_id);
+            self.server
+                .direct_dependencies
+                .write()
+                .retain(|dependent, _| dependent.get_id().source_path_id() != source_path_id);
+        }
     }
 
     /// Iterates through asset references and marks assets with no active handles as unused.
@@ -560,14 +1409,14 @@ impl AssetServer {
 
     fn create_assets_in_load_context(&self, load_context: &mut LoadContext) {
         let asset_lifecycles = self.server.asset_lifecycles.read();
-        for (label, asset) in &mut load_context.labeled_assets {
+        let mut labeled_assets = std::mem::take(&mut load_context.labeled_assets);
+        for (label, asset) in &mut labeled_assets {
             let asset_value = asset
                 .value
                 .take()
                 .expect("Asset should exist at this point.");
             if let Some(asset_lifecycle) = asset_lifecycles.get(&asset_value.type_uuid()) {
-                let asset_path =
-                    AssetPath::new_ref(load_context.path, label.as_ref().map(|l| l.as_str()));
+                let asset_path = load_context.get_asset_path(label.as_deref());
                 asset_lifecycle.create_asset(asset_path.into(), asset_value, load_context.version);
             } else {
                 panic!(
@@ -627,7 +1476,106 @@ impl AssetServer {
                 Err(TryRecvError::Disconnected) => panic!("AssetChannel disconnected."),
             }
         }
+        drop(asset_sources_guard);
+
+        // send `LoadedWithDependencies` events for the assets whose dependencies finished loading
+        let asset_sources = self.server.asset_sources.read();
+        let dependencies = self.server.dependencies.read();
+        let mut pending = self.server.pending_loaded_with_dependencies.lock();
+        if let Some(ids) = pending.get_mut(&T::TYPE_UUID) {
+            ids.retain(|id| {
+                match recursive_dependency_load_state(
+                    &asset_sources,
+                    &dependencies,
+                    id.source_path_id(),
+                ) {
+                    LoadState::Loaded => {
+                        assets.events.send(AssetEvent::LoadedWithDependencies {
+                            handle: Handle::weak((*id).into()),
+                        });
+                        false
+                    }
+                    LoadState::Loading => true,
+                    _ => false,
+                }
+            });
+        }
+    }
+}
+
+/// Returns the error of the first recursive dependency of the source that failed to load,
+/// pushing the path to it to `chain`.
+fn find_failed_dependency(
+    asset_sources: &HashMap<SourcePathId, SourceInfo>,
+    dependencies: &HashMap<SourcePathId, Vec<AssetPath<'static>>>,
+    source_path_id: SourcePathId,
+    visited: &mut HashSet<SourcePathId>,
+    chain: &mut Vec<AssetPath<'static>>,
+) -> Option<Arc<AssetServerError>> {
+    for dependency in dependencies.get(&source_path_id)? {
+        let dependency_id = dependency.get_id().source_path_id();
+        if !visited.insert(dependency_id) {
+            continue;
+        }
+        chain.push(dependency.clone());
+        if let Some(error) = asset_sources
+            .get(&dependency_id)
+            .and_then(|info| info.load_error.clone())
+        {
+            return Some(error);
+        }
+        if let Some(error) =
+            find_failed_dependency(asset_sources, dependencies, dependency_id, visited, chain)
+        {
+            return Some(error);
+        }
+        chain.pop();
+    }
+    None
+}
+
+/// Returns the load state of the source and all of its recursive dependencies.
+fn recursive_dependency_load_state(
+    asset_sources: &HashMap<SourcePathId, SourceInfo>,
+    dependencies: &HashMap<SourcePathId, Vec<AssetPath<'static>>>,
+    source_path_id: SourcePathId,
+) -> LoadState {
+    let get_load_state = |source_path_id| {
+        asset_sources
+            .get(&source_path_id)
+            .map_or(LoadState::NotLoaded, |info| info.load_state)
+    };
+    match get_load_state(source_path_id) {
+        LoadState::Loaded => {}
+        load_state => return load_state,
+    }
+
+    let mut load_state = LoadState::Loaded;
+    let mut visited = HashSet::default();
+    visited.insert(source_path_id);
+    let mut stack = vec![source_path_id];
+    while let Some(source_path_id) = stack.pop() {
+        for dependency in dependencies.get(&source_path_id).into_iter().flatten() {
+            let dependency_id = dependency.get_id().source_path_id();
+            if !visited.insert(dependency_id) {
+                continue;
+            }
+            match get_load_state(dependency_id) {
+                LoadState::Loaded => stack.push(dependency_id),
+                LoadState::Failed => return LoadState::Failed,
+                _ => load_state = LoadState::Loading,
+            }
+        }
     }
+    load_state
+}
+
+/// Sends an [`AssetLoadFailedEvent`] for each asset that failed to load since the last run.
+pub fn send_asset_load_failed_events(
+    asset_server: Res<AssetServer>,
+    mut events: EventWriter<AssetLoadFailedEvent>,
+) {
+    events.send_batch(asset_server.server.load_failed_receiver.try_iter());
 }
 
 fn free_unused_assets_system_impl(asset_server: &AssetServer) {
@@ -643,13 +1591,15 @@ pub fn free_unused_assets_system(asset_server: Res<AssetServer>) {
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::{loader::LoadedAsset, update_asset_storage_system};
+    use crate::{loader::LoadedAsset, update_asset_storage_system, ProcessContext};
     use bevy_app::App;
     use bevy_ecs::prelude::*;
-    use bevy_reflect::TypeUuid;
+    use bevy_reflect::{TypePath, TypeUuid};
     use bevy_utils::BoxedFuture;
+    use serde::Deserialize;
+    use std::sync::atomic::{AtomicUsize, Ordering};
 
-    #[derive(Debug, TypeUuid)]
+    #[derive(Debug, TypePath, TypeUuid)]
     #[uuid = "a5189b72-0572-4290-a2e0-96f73a491c44"]
     struct PngAsset;
 
@@ -699,12 +1649,214 @@ mod test {
         }
     }
 
+    #[derive(Debug, TypePath, TypeUuid)]
+    #[uuid = "5a5d8ee4-3e2d-4fbb-9a0f-f8b6c3d1b3a1"]
+    struct TextAsset(String);
+
+    /// Uppercases text files, counting how many times it ran.
+    #[derive(Default)]
+    struct UppercaseProcessor {
+        runs: Arc<AtomicUsize>,
+    }
+    impl AssetProcessor for UppercaseProcessor {
+        fn process<'a>(
+            &'a self,
+            bytes: &'a [u8],
+            _: &'a mut ProcessContext,
+        ) -> BoxedFuture<'a, Result<Vec<u8>, anyhow::Error>> {
+            self.runs.fetch_add(1, Ordering::SeqCst);
+            Box::pin(async move { Ok(bytes.to_ascii_uppercase()) })
+        }
+
+        fn extensions(&self) -> &[&str] {
+            &["txt"]
+        }
+
+        fn processed_extension(&self) -> Option<&str> {
+            Some("upper")
+        }
+    }
+
+    struct UppercaseLoader;
+    impl AssetLoader for UppercaseLoader {
+        fn load<'a>(
+            &'a self,
+            bytes: &'a [u8],
+            ctx: &'a mut LoadContext,
+        ) -> BoxedFuture<'a, Result<(), anyhow::Error>> {
+            Box::pin(async move {
+                let text = String::from_utf8(bytes.to_vec())?;
+                ctx.set_default_asset(LoadedAsset::new(TextAsset(text)));
+                Ok(())
+            })
+        }
+
+        fn extensions(&self) -> &[&str] {
+            &["upper"]
+        }
+    }
+
+    /// Converts comma separated lists to line separated ones.
+    struct CsvToLinesProcessor;
+    impl AssetProcessor for CsvToLinesProcessor {
+        fn process<'a>(
+            &'a self,
+            bytes: &'a [u8],
+            _: &'a mut ProcessContext,
+        ) -> BoxedFuture<'a, Result<Vec<u8>, anyhow::Error>> {
+            Box::pin(async move { Ok(std::str::from_utf8(bytes)?.replace(',', "\n").into_bytes()) })
+        }
+
+        fn extensions(&self) -> &[&str] {
+            &["csv"]
+        }
+
+        fn processed_extension(&self) -> Option<&str> {
+            Some("lines")
+        }
+    }
+
+    /// Loads both comma and line separated lists, picking the format from the extension.
+    struct ListLoader;
+    impl AssetLoader for ListLoader {
+        fn load<'a>(
+            &'a self,
+            bytes: &'a [u8],
+            ctx: &'a mut LoadContext,
+        ) -> BoxedFuture<'a, Result<(), anyhow::Error>> {
+            Box::pin(async move {
+                let separator = match ctx.processed_path().extension().unwrap().to_str() {
+                    Some("csv") => ',',
+                    _ => '\n',
+                };
+                let text = std::str::from_utf8(bytes)?;
+                let items: Vec<_> = text.split(separator).collect();
+                ctx.set_default_asset(LoadedAsset::new(TextAsset(items.join(" "))));
+                Ok(())
+            })
+        }
+
+        fn extensions(&self) -> &[&str] {
+            &["csv", "lines"]
+        }
+    }
+
+    #[derive(Deserialize)]
+    #[serde(default)]
+    struct TextSettings {
+        repeat: usize,
+        suffix: String,
+    }
+
+    impl Default for TextSettings {
+        fn default() -> Self {
+            Self {
+                repeat: 1,
+                suffix: String::new(),
+            }
+        }
+    }
+
+    /// Loads text files, repeating them and adding a suffix according to its settings.
+    struct TextLoader;
+    impl AssetLoader for TextLoader {
+        fn load<'a>(
+            &'a self,
+            bytes: &'a [u8],
+            ctx: &'a mut LoadContext,
+        ) -> BoxedFuture<'a, Result<(), anyhow::Error>> {
+            Box::pin(async move {
+                let settings = ctx.settings::<TextSettings>()?;
+                let text = std::str::from_utf8(bytes)?.repeat(settings.repeat) + &settings.suffix;
+                ctx.set_default_asset(LoadedAsset::new(TextAsset(text)));
+                Ok(())
+            })
+        }
+
+        fn extensions(&self) -> &[&str] {
+            &["text"]
+        }
+    }
+
+    /// Saves text assets as they are.
+    struct TextSaver;
+    impl AssetSaver for TextSaver {
+        type Asset = TextAsset;
+
+        fn save(&self, asset: &TextAsset) -> Result<Vec<u8>, anyhow::Error> {
+            Ok(asset.0.clone().into_bytes())
+        }
+
+        fn extension(&self) -> &str {
+            "text"
+        }
+    }
+
+    /// Moves the loaded text assets to `assets`, returning the one at `path` if it is loaded.
+    fn get_text(
+        asset_server: &AssetServer,
+        assets: &mut Assets<TextAsset>,
+        path: &AssetPath,
+    ) -> Option<String> {
+        let asset_lifecycles = asset_server.server.asset_lifecycles.read();
+        let channel = asset_lifecycles[&TextAsset::TYPE_UUID]
+            .downcast_ref::<AssetLifecycleChannel<TextAsset>>()
+            .unwrap();
+        while let Ok(AssetLifecycleEvent::Create(result)) = channel.receiver.try_recv() {
+            assets.set_untracked(result.id, *result.asset);
+        }
+        let handle = assets.get_handle(path.get_id());
+        assets.get(&handle).map(|text| text.0.clone())
+    }
+
+    /// Loads the text asset at `path`.
+    fn load_text(
+        asset_server: &AssetServer,
+        assets: &mut Assets<TextAsset>,
+        path: &str,
+    ) -> Result<String, Arc<AssetServerError>> {
+        let path: AssetPath = path.into();
+        futures_lite::future::block_on(asset_server.load_async(path.clone(), true, None))?;
+        Ok(get_text(asset_server, assets, &path).unwrap())
+    }
+
+    /// Waits for the text asset at `path`, which is being loaded in the background.
+    fn wait_for_text(
+        asset_server: &AssetServer,
+        assets: &mut Assets<TextAsset>,
+        path: &str,
+    ) -> String {
+        let path: AssetPath = path.into();
+        loop {
+            if let Some(text) = get_text(asset_server, assets, &path) {
+                return text;
+            }
+            assert_ne!(
+                LoadState::Failed,
+                asset_server.get_load_state(path.get_id())
+            );
+            std::thread::yield_now();
+        }
+    }
+
     fn setup(asset_path: impl AsRef<Path>) -> AssetServer {
         use crate::FileAssetIo;
         IoTaskPool::init(Default::default);
         AssetServer::new(FileAssetIo::new(asset_path, false))
     }
 
+    /// Waits until `condition` holds, panicking if it takes too long.
+    fn wait_until(mut condition: impl FnMut() -> bool) {
+        let start = std::time::Instant::now();
+        while !condition() {
+            assert!(
+                start.elapsed() < std::time::Duration::from_secs(10),
+                "timed out"
+            );
+            std::thread::yield_now();
+        }
+    }
+
     #[test]
     fn extensions() {
         let asset_server = setup(".");
@@ -797,11 +1949,11 @@ mod test {
         let path: AssetPath = "file.not-a-real-extension".into();
         let handle = asset_server.get_handle_untyped(path.get_id());
 
-        let err = futures_lite::future::block_on(asset_server.load_async(path.clone(), true))
+        let err = futures_lite::future::block_on(asset_server.load_async(path.clone(), true, None))
             .unwrap_err();
-        assert!(match err {
+        assert!(match &*err {
             AssetServerError::MissingAssetLoader { extensions } => {
-                extensions == ["not-a-real-extension"]
+                *extensions == ["not-a-real-extension"]
             }
             _ => false,
         });
@@ -817,9 +1969,9 @@ mod test {
         let path: AssetPath = "an/invalid/path.png".into();
         let handle = asset_server.get_handle_untyped(path.get_id());
 
-        let err = futures_lite::future::block_on(asset_server.load_async(path.clone(), true))
+        let err = futures_lite::future::block_on(asset_server.load_async(path.clone(), true, None))
             .unwrap_err();
-        assert!(matches!(err, AssetServerError::AssetIoError(_)));
+        assert!(matches!(*err, AssetServerError::AssetIoError(_)));
 
         assert_eq!(asset_server.get_load_state(handle), LoadState::Failed);
     }
@@ -833,11 +1985,291 @@ mod test {
         let path: AssetPath = "fake.fail".into();
         let handle = asset_server.get_handle_untyped(path.get_id());
 
-        let err = futures_lite::future::block_on(asset_server.load_async(path.clone(), true))
+        let err = futures_lite::future::block_on(asset_server.load_async(path.clone(), true, None))
             .unwrap_err();
-        assert!(matches!(err, AssetServerError::AssetLoaderError(_)));
+        assert!(matches!(*err, AssetServerError::AssetLoaderError(_)));
+
+        assert_eq!(asset_server.get_load_state(&handle), LoadState::Failed);
+        assert!(Arc::ptr_eq(
+            &err,
+            &asset_server.get_load_error(&handle).unwrap()
+        ));
+        let event = asset_server.server.load_failed_receiver.try_recv().unwrap();
+        assert_eq!(path, event.path);
+        assert_eq!(HandleId::from(&handle), event.id);
+        assert!(Arc::ptr_eq(&err, &event.error));
+    }
 
-        assert_eq!(asset_server.get_load_state(handle), LoadState::Failed);
+    /// Records the order in which assets are loaded, waiting for `released` before loading
+    /// `block.order`.
+    struct OrderLoader {
+        released: Arc<std::sync::atomic::AtomicBool>,
+        order: Arc<Mutex<Vec<PathBuf>>>,
+    }
+    impl AssetLoader for OrderLoader {
+        fn load<'a>(
+            &'a self,
+            _: &'a [u8],
+            ctx: &'a mut LoadContext,
+        ) -> BoxedFuture<'a, Result<(), anyhow::Error>> {
+            Box::pin(async move {
+                if ctx.path() == Path::new("block.order") {
+                    while !self.released.load(Ordering::SeqCst) {
+                        std::thread::yield_now();
+                    }
+                }
+                self.order.lock().push(ctx.path().to_owned());
+                ctx.set_default_asset(LoadedAsset::new(TextAsset(String::new())));
+                Ok(())
+            })
+        }
+
+        fn extensions(&self) -> &[&str] {
+            &["order"]
+        }
+    }
+
+    /// Loads text files as a labeled asset, recording the handles it gets to them.
+    struct LabeledLoader {
+        handles: Arc<Mutex<Vec<HandleId>>>,
+    }
+    impl AssetLoader for LabeledLoader {
+        fn load<'a>(
+            &'a self,
+            bytes: &'a [u8],
+            ctx: &'a mut LoadContext,
+        ) -> BoxedFuture<'a, Result<(), anyhow::Error>> {
+            Box::pin(async move {
+                let text = std::str::from_utf8(bytes)?.to_string();
+                ctx.set_labeled_asset("inner", LoadedAsset::new(TextAsset(text)));
+                let handle: Handle<TextAsset> = ctx.get_handle(ctx.get_asset_path(Some("inner")));
+                self.handles.lock().push(handle.id());
+                ctx.set_default_asset(LoadedAsset::new(TextAsset(String::new())));
+                Ok(())
+            })
+        }
+
+        fn extensions(&self) -> &[&str] {
+            &["labeled"]
+        }
+    }
+
+    /// Loads text files holding the path of a dependency.
+    struct DependentLoader;
+    impl AssetLoader for DependentLoader {
+        fn load<'a>(
+            &'a self,
+            bytes: &'a [u8],
+            ctx: &'a mut LoadContext,
+        ) -> BoxedFuture<'a, Result<(), anyhow::Error>> {
+            Box::pin(async move {
+                let dependency = std::str::from_utf8(bytes)?.to_string();
+                ctx.set_default_asset(
+                    LoadedAsset::new(TextAsset(dependency.clone()))
+                        .with_dependency(dependency.as_str().into()),
+                );
+                Ok(())
+            })
+        }
+
+        fn extensions(&self) -> &[&str] {
+            &["dependent"]
+        }
+    }
+
+    /// Loads text files holding the path of a text asset, which is loaded directly and followed
+    /// by `!`.
+    struct DirectLoader;
+    impl AssetLoader for DirectLoader {
+        fn load<'a>(
+            &'a self,
+            bytes: &'a [u8],
+            ctx: &'a mut LoadContext,
+        ) -> BoxedFuture<'a, Result<(), anyhow::Error>> {
+            Box::pin(async move {
+                let path = std::str::from_utf8(bytes)?;
+                let text = ctx.load_direct::<TextAsset>(path).await?.0 + "!";
+                ctx.set_default_asset(LoadedAsset::new(TextAsset(text)));
+                Ok(())
+            })
+        }
+
+        fn extensions(&self) -> &[&str] {
+            &["direct"]
+        }
+    }
+
+    #[test]
+    fn test_failed_dependency() {
+        let dir = create_dir_and_file("a.dependent");
+        std::fs::write(dir.path().join("a.dependent"), "b.dependent").unwrap();
+        std::fs::write(dir.path().join("b.dependent"), "missing.text").unwrap();
+        let asset_server = setup(dir.path());
+        asset_server.add_loader(DependentLoader);
+        asset_server.add_loader(TextLoader);
+        let _assets = asset_server.register_asset_type::<TextAsset>();
+
+        let path: AssetPath = "a.dependent".into();
+        futures_lite::future::block_on(asset_server.load_async(path.clone(), true, None)).unwrap();
+        let error = loop {
+            if let Some(error) = asset_server.get_dependency_load_error(path.get_id()) {
+                break error;
+            }
+            std::thread::yield_now();
+        };
+
+        assert_eq!(
+            vec![
+                AssetPath::from("b.dependent"),
+                AssetPath::from("missing.text")
+            ],
+            error.chain
+        );
+        assert!(matches!(*error.error, AssetServerError::AssetIoError(_)));
+        assert_eq!(
+            LoadState::Failed,
+            asset_server.get_group_load_state([path.get_id().into()])
+        );
+        assert!(asset_server.get_load_error(path.get_id()).is_none());
+    }
+
+    #[test]
+    fn test_named_sources() {
+        let base_dir = create_dir_and_file("a.text");
+        std::fs::write(base_dir.path().join("a.text"), "base").unwrap();
+        std::fs::write(base_dir.path().join("b.text"), "base").unwrap();
+        let mods_dir = create_dir_and_file("a.text");
+        std::fs::write(mods_dir.path().join("a.text"), "mod").unwrap();
+        let asset_server = setup(base_dir.path());
+        asset_server.add_loader(TextLoader);
+        asset_server.add_source("mods", crate::FileAssetIo::new(mods_dir.path(), false));
+        let mut assets = asset_server.register_asset_type::<TextAsset>();
+
+        let path = AssetPath::from("mods://a.text#label");
+        assert_eq!(Some("mods"), path.source());
+        assert_eq!(Path::new("a.text"), path.path());
+        assert_eq!(Some("label"), path.label());
+        assert_ne!(path.get_id(), AssetPath::from("a.text#label").get_id());
+
+        assert_eq!(
+            "mod",
+            load_text(&asset_server, &mut assets, "mods://a.text").unwrap()
+        );
+        assert_eq!(
+            "base",
+            load_text(&asset_server, &mut assets, "a.text").unwrap()
+        );
+        let err = load_text(&asset_server, &mut assets, "dlc://a.text").unwrap_err();
+        assert!(matches!(&*err, AssetServerError::MissingAssetSource(source) if source == "dlc"));
+
+        asset_server.set_override_sources(["mods"]);
+        assert_eq!(
+            "mod",
+            load_text(&asset_server, &mut assets, "a.text").unwrap()
+        );
+        assert_eq!(
+            "base",
+            load_text(&asset_server, &mut assets, "b.text").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_named_source_labeled_assets() {
+        let base_dir = tempfile::tempdir().unwrap();
+        let mods_dir = create_dir_and_file("a.labeled");
+        std::fs::write(mods_dir.path().join("a.labeled"), "mod").unwrap();
+        let asset_server = setup(base_dir.path());
+        let handles = Arc::new(Mutex::new(Vec::new()));
+        asset_server.add_loader(LabeledLoader {
+            handles: handles.clone(),
+        });
+        asset_server.add_source("mods", crate::FileAssetIo::new(mods_dir.path(), false));
+        let mut assets = asset_server.register_asset_type::<TextAsset>();
+
+        load_text(&asset_server, &mut assets, "mods://a.labeled").unwrap();
+        // handles to labeled assets point to the assets of the named source
+        let path = AssetPath::from("mods://a.labeled#inner");
+        assert_eq!(vec![HandleId::from(path.get_id())], *handles.lock());
+        assert_eq!(
+            Some("mod".to_string()),
+            get_text(&asset_server, &mut assets, &path)
+        );
+        assert_eq!(
+            None,
+            get_text(&asset_server, &mut assets, &"a.labeled#inner".into())
+        );
+    }
+
+    #[test]
+    fn test_memory_source() {
+        let asset_server = setup(".");
+        asset_server.add_loader(TextLoader);
+        let embedded = crate::MemoryAssetIo::new();
+        asset_server.add_source(crate::EMBEDDED_ASSET_SOURCE, embedded.clone());
+        embedded.insert("my_crate/a.text", b"a".as_slice());
+        embedded.insert("my_crate/dir/b.text", b"b".as_slice());
+        let mut assets = asset_server.register_asset_type::<TextAsset>();
+
+        assert_eq!(
+            "a",
+            load_text(&asset_server, &mut assets, "embedded://my_crate/a.text").unwrap()
+        );
+
+        let handles = asset_server.load_folder("embedded://my_crate").unwrap();
+        let mut paths: Vec<_> = handles
+            .iter()
+            .map(|handle| asset_server.get_handle_path(handle).unwrap().to_owned())
+            .collect();
+        paths.sort_by(|a, b| a.path().cmp(b.path()));
+        assert_eq!(
+            vec![
+                AssetPath::from("embedded://my_crate/a.text"),
+                AssetPath::from("embedded://my_crate/dir/b.text")
+            ],
+            paths
+        );
+    }
+
+    #[test]
+    fn test_recursive_dependency_load_state() {
+        let dir = create_dir_and_file("a.dependent");
+        std::fs::write(dir.path().join("a.dependent"), "b.dependent").unwrap();
+        std::fs::write(dir.path().join("b.dependent"), "c.text").unwrap();
+        std::fs::write(dir.path().join("c.text"), "c").unwrap();
+        let asset_server = setup(dir.path());
+        asset_server.add_loader(DependentLoader);
+        asset_server.add_loader(TextLoader);
+        let assets = asset_server.register_asset_type::<TextAsset>();
+
+        let mut app = App::new();
+        app.insert_resource(assets);
+        app.insert_resource(asset_server.clone());
+        app.add_system(update_asset_storage_system::<TextAsset>);
+
+        let path: AssetPath = "a.dependent".into();
+        futures_lite::future::block_on(asset_server.load_async(path.clone(), true, None)).unwrap();
+        assert_eq!(
+            LoadState::Loading,
+            asset_server.get_recursive_dependency_load_state(path.get_id())
+        );
+
+        while asset_server.get_recursive_dependency_load_state(path.get_id()) != LoadState::Loaded {
+            app.update();
+            std::thread::yield_now();
+        }
+        let assets = app.world.resource::<Assets<TextAsset>>();
+        let loaded: Vec<_> = assets
+            .events
+            .iter_current_update_events()
+            .filter_map(|event| match event {
+                AssetEvent::LoadedWithDependencies { handle } => Some(handle.id()),
+                _ => None,
+            })
+            .collect();
+        for path in ["a.dependent", "b.dependent", "c.text"] {
+            let id = HandleId::from(AssetPath::from(path));
+            assert_eq!(1, loaded.iter().filter(|loaded| **loaded == id).count());
+        }
     }
 
     #[test]
@@ -857,8 +2289,9 @@ mod test {
 
         fn load_asset(path: AssetPath, world: &World) -> HandleUntyped {
             let asset_server = world.resource::<AssetServer>();
-            let id = futures_lite::future::block_on(asset_server.load_async(path.clone(), true))
-                .unwrap();
+            let id =
+                futures_lite::future::block_on(asset_server.load_async(path.clone(), true, None))
+                    .unwrap();
             asset_server.get_handle_untyped(id)
         }
 
@@ -946,4 +2379,542 @@ mod test {
         let invalid_path = AssetPath::new("some/path.ext".into(), None);
         assert!(server.get_handle_path(invalid_path).is_none());
     }
+
+    #[test]
+    fn test_processed_asset() {
+        let dir = create_dir_and_file("text.txt");
+        std::fs::write(dir.path().join("text.txt"), "hello").unwrap();
+        let processed_dir = tempfile::tempdir().unwrap();
+        let asset_server = setup(dir.path());
+        asset_server.set_processed_asset_folder(processed_dir.path());
+        let processor = UppercaseProcessor::default();
+        let runs = processor.runs.clone();
+        asset_server.add_processor(processor);
+        asset_server.add_loader(UppercaseLoader);
+        let mut assets = asset_server.register_asset_type::<TextAsset>();
+
+        let mut load = || load_text(&asset_server, &mut assets, "text.txt").unwrap();
+
+        // the source is processed and cached on the first load
+        assert_eq!("HELLO", load());
+        assert_eq!(1, runs.load(Ordering::SeqCst));
+        assert_eq!(
+            "HELLO",
+            std::fs::read_to_string(processed_dir.path().join("text.txt.upper")).unwrap()
+        );
+
+        // the cached asset is loaded while the source is unchanged
+        assert_eq!("HELLO", load());
+        assert_eq!(1, runs.load(Ordering::SeqCst));
+
+        // changing the source processes it again
+        std::fs::write(dir.path().join("text.txt"), "world").unwrap();
+        assert_eq!("WORLD", load());
+        assert_eq!(2, runs.load(Ordering::SeqCst));
+    }
+
+    #[test]
+    fn test_processed_asset_format() {
+        let dir = create_dir_and_file("list.csv");
+        std::fs::write(dir.path().join("list.csv"), "a,b").unwrap();
+        let asset_server = setup(dir.path());
+        asset_server.add_loader(ListLoader);
+        let mut assets = asset_server.register_asset_type::<TextAsset>();
+        assert_eq!(
+            "a b",
+            load_text(&asset_server, &mut assets, "list.csv").unwrap()
+        );
+
+        // the loader reads the processed bytes in the format of the processed path
+        asset_server.add_processor(CsvToLinesProcessor);
+        assert_eq!(
+            "a b",
+            load_text(&asset_server, &mut assets, "list.csv").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_processor_change() {
+        let dir = create_dir_and_file("text.txt");
+        std::fs::write(dir.path().join("text.txt"), "a,b").unwrap();
+        let processed_dir = tempfile::tempdir().unwrap();
+        let asset_server = setup(dir.path());
+        asset_server.set_processed_asset_folder(processed_dir.path());
+        asset_server.add_processor(UppercaseProcessor::default());
+        asset_server.add_loader(UppercaseLoader);
+        let mut assets = asset_server.register_asset_type::<TextAsset>();
+        assert_eq!(
+            "A,B",
+            load_text(&asset_server, &mut assets, "text.txt").unwrap()
+        );
+
+        /// Processes text files like [`UppercaseProcessor`], with the same version.
+        struct CsvUppercaseProcessor;
+        impl AssetProcessor for CsvUppercaseProcessor {
+            fn process<'a>(
+                &'a self,
+                bytes: &'a [u8],
+                _: &'a mut ProcessContext,
+            ) -> BoxedFuture<'a, Result<Vec<u8>, anyhow::Error>> {
+                Box::pin(async move {
+                    let text = std::str::from_utf8(bytes)?.to_ascii_uppercase();
+                    Ok(text.replace(',', " ").into_bytes())
+                })
+            }
+
+            fn extensions(&self) -> &[&str] {
+                &["txt"]
+            }
+
+            fn processed_extension(&self) -> Option<&str> {
+                Some("upper")
+            }
+        }
+
+        // replacing the processor invalidates the cached asset
+        asset_server.add_processor(CsvUppercaseProcessor);
+        assert_eq!(
+            "A B",
+            load_text(&asset_server, &mut assets, "text.txt").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_process_folder() {
+        let dir = create_dir_and_file("a.txt");
+        std::fs::create_dir(dir.path().join("nested")).unwrap();
+        std::fs::write(dir.path().join("nested/b.txt"), "b").unwrap();
+        std::fs::write(dir.path().join("ignored.png"), []).unwrap();
+        let processed_dir = tempfile::tempdir().unwrap();
+        let asset_server = setup(dir.path());
+        asset_server.set_processed_asset_folder(processed_dir.path());
+        asset_server.add_processor(UppercaseProcessor::default());
+
+        let mut processed = asset_server.process_folder("").unwrap();
+        processed.sort();
+        assert_eq!(
+            vec![PathBuf::from("a.txt"), Path::new("nested").join("b.txt")],
+            processed
+        );
+        assert_eq!(
+            "B",
+            std::fs::read_to_string(processed_dir.path().join("nested/b.txt.upper")).unwrap()
+        );
+
+        // everything is already processed
+        assert!(asset_server.process_folder("").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_meta_file_settings() {
+        let dir = create_dir_and_file("a.text");
+        std::fs::write(dir.path().join("a.text"), "hi").unwrap();
+        std::fs::write(dir.path().join("b.text"), "hi").unwrap();
+        std::fs::write(dir.path().join("b.text.meta"), "(settings: (repeat: 2))").unwrap();
+        std::fs::write(dir.path().join("c.data"), "hi").unwrap();
+        std::fs::write(dir.path().join("c.data.meta"), r#"(loader: "text")"#).unwrap();
+        std::fs::write(dir.path().join("d.text"), "hi").unwrap();
+        std::fs::write(dir.path().join("d.text.meta"), "(settings: (repeat: -1))").unwrap();
+        std::fs::write(dir.path().join("e.text"), "hi").unwrap();
+        std::fs::write(dir.path().join("e.text.meta"), "(settings: (repeat: 2))").unwrap();
+        std::fs::write(dir.path().join("f.text"), "hi").unwrap();
+        let asset_server = setup(dir.path());
+        asset_server.add_loader(TextLoader);
+        let mut assets = asset_server.register_asset_type::<TextAsset>();
+
+        // without a meta file, the default settings are used
+        assert_eq!(
+            "hi",
+            load_text(&asset_server, &mut assets, "a.text").unwrap()
+        );
+        // settings are read from the meta file
+        assert_eq!(
+            "hihi",
+            load_text(&asset_server, &mut assets, "b.text").unwrap()
+        );
+        // the loader is picked from the meta file
+        assert_eq!(
+            "hi",
+            load_text(&asset_server, &mut assets, "c.data").unwrap()
+        );
+        // invalid settings make the loader fail
+        assert!(matches!(
+            *load_text(&asset_server, &mut assets, "d.text").unwrap_err(),
+            AssetServerError::AssetLoaderError(_)
+        ));
+
+        // settings from the meta file can be modified when loading
+        let _handle: Handle<TextAsset> =
+            asset_server.load_with_settings("e.text", |settings: &mut TextSettings| {
+                settings.suffix = "!".to_string();
+            });
+        assert_eq!("hihi!", wait_for_text(&asset_server, &mut assets, "e.text"));
+        // the settings are kept when reloading
+        assert_eq!(
+            "hihi!",
+            load_text(&asset_server, &mut assets, "e.text").unwrap()
+        );
+
+        // only loaders with settings of the same type are affected
+        let _handle: Handle<TextAsset> =
+            asset_server.load_with_settings("f.text", |repeat: &mut usize| *repeat = 3);
+        assert_eq!("hi", wait_for_text(&asset_server, &mut assets, "f.text"));
+    }
+
+    #[test]
+    fn test_invalid_meta_file() {
+        let dir = create_dir_and_file("a.text");
+        std::fs::write(dir.path().join("a.text.meta"), "(loader: 42)").unwrap();
+        std::fs::write(dir.path().join("b.text"), "").unwrap();
+        std::fs::write(dir.path().join("b.text.meta"), r#"(loader: "unknown")"#).unwrap();
+        let asset_server = setup(dir.path());
+        asset_server.add_loader(TextLoader);
+        let mut assets = asset_server.register_asset_type::<TextAsset>();
+
+        assert!(matches!(
+            *load_text(&asset_server, &mut assets, "a.text").unwrap_err(),
+            AssetServerError::InvalidMetaFile { .. }
+        ));
+        assert!(matches!(
+            &*load_text(&asset_server, &mut assets, "b.text").unwrap_err(),
+            AssetServerError::MissingAssetLoader { extensions } if *extensions == ["unknown"]
+        ));
+    }
+
+    #[test]
+    fn test_save() {
+        let dir = tempfile::tempdir().unwrap();
+        let asset_server = setup(dir.path());
+        asset_server.add_loader(TextLoader);
+        asset_server.add_saver(TextSaver);
+        let mut assets = asset_server.register_asset_type::<TextAsset>();
+        let handle = assets.add(TextAsset("saved".to_string()));
+
+        // the extension of the saver is appended to the path
+        asset_server.save(&handle, "dir/a").unwrap();
+        let mut app = App::new();
+        app.insert_resource(assets);
+        app.insert_resource(asset_server.clone());
+        app.add_system(crate::save_assets_system::<TextAsset>);
+        app.update();
+
+        let saved_path = dir.path().join("dir/a.text");
+        wait_until(|| std::fs::read(&saved_path).ok().as_deref() == Some(b"saved".as_slice()));
+        // the saved asset can be loaded back
+        let mut assets = app.world.resource_mut::<Assets<TextAsset>>();
+        let _loaded: Handle<TextAsset> = asset_server.load("dir/a.text");
+        assert_eq!(
+            "saved",
+            wait_for_text(&asset_server, &mut assets, "dir/a.text")
+        );
+    }
+
+    #[test]
+    fn test_save_errors() {
+        let dir = tempfile::tempdir().unwrap();
+        let asset_server = setup(dir.path());
+        let mut assets = asset_server.register_asset_type::<TextAsset>();
+        let handle = assets.add(TextAsset("saved".to_string()));
+
+        let err = asset_server.save(&handle, "dir/a").unwrap_err();
+        assert!(matches!(err, AssetServerError::MissingAssetSaver(_)));
+
+        // saving to a read-only source fails
+        asset_server.add_source("packed", crate::LayeredAssetIo::new());
+        let err = futures_lite::future::block_on(
+            asset_server.write_asset(&"packed://a.text".into(), b"a"),
+        )
+        .unwrap_err();
+        assert!(matches!(
+            err,
+            AssetServerError::AssetIoError(AssetIoError::WriteNotSupported(_))
+        ));
+    }
+
+    #[cfg(all(
+        feature = "filesystem_watcher",
+        all(not(target_arch = "wasm32"), not(target_os = "android"))
+    ))]
+    #[test]
+    fn test_saved_files_are_not_reloaded() {
+        use crate::{filesystem_watcher_system, FileAssetIo};
+
+        let dir = create_dir_and_file("a.text");
+        std::fs::write(dir.path().join("a.text"), "original").unwrap();
+        IoTaskPool::init(Default::default);
+        let asset_server = AssetServer::new(FileAssetIo::new(dir.path(), true));
+        asset_server.add_loader(TextLoader);
+        asset_server.add_saver(TextSaver);
+        let mut assets = asset_server.register_asset_type::<TextAsset>();
+        let handle: Handle<TextAsset> = asset_server.load("a.text");
+        assert_eq!(
+            "original",
+            wait_for_text(&asset_server, &mut assets, "a.text")
+        );
+        let source_path_id = AssetPath::from("a.text").get_id().source_path_id();
+        let version = |asset_server: &AssetServer| {
+            asset_server.server.asset_sources.read()[&source_path_id].version
+        };
+        let loaded_version = version(&asset_server);
+
+        assets.get_mut(&handle).unwrap().0 = "saved".to_string();
+        asset_server.save(&handle, "a.text").unwrap();
+        let mut app = App::new();
+        app.insert_resource(assets);
+        app.insert_resource(asset_server.clone());
+        app.add_system(crate::save_assets_system::<TextAsset>);
+        app.add_system(filesystem_watcher_system);
+        app.update();
+        let saved_path = dir.path().join("a.text");
+        wait_until(|| std::fs::read(&saved_path).ok().as_deref() == Some(b"saved".as_slice()));
+
+        // give the watcher time to report the write
+        let start = std::time::Instant::now();
+        while start.elapsed() < std::time::Duration::from_millis(500) {
+            app.update();
+            std::thread::yield_now();
+        }
+        assert_eq!(loaded_version, version(&asset_server));
+
+        // files changed by other programs are still reloaded
+        std::fs::write(&saved_path, "changed").unwrap();
+        wait_until(|| {
+            app.update();
+            version(&asset_server) != loaded_version
+        });
+        let mut assets = app.world.resource_mut::<Assets<TextAsset>>();
+        let path = AssetPath::from("a.text");
+        wait_until(|| get_text(&asset_server, &mut assets, &path).as_deref() == Some("changed"));
+    }
+
+    #[test]
+    fn test_free_unused_dependencies() {
+        let dir = create_dir_and_file("a.dependent");
+        std::fs::write(dir.path().join("a.dependent"), "b.text").unwrap();
+        std::fs::write(dir.path().join("b.text"), "b").unwrap();
+        let asset_server = setup(dir.path());
+        asset_server.add_loader(DependentLoader);
+        asset_server.add_loader(TextLoader);
+        let _assets = asset_server.register_asset_type::<TextAsset>();
+
+        let handle: Handle<TextAsset> = asset_server.load("a.dependent");
+        let source_path_id = AssetPath::from("a.dependent").get_id().source_path_id();
+        wait_until(|| {
+            asset_server
+                .server
+                .dependencies
+                .read()
+                .contains_key(&source_path_id)
+        });
+
+        // the dependencies of freed assets are forgotten
+        drop(handle);
+        asset_server.mark_unused_assets();
+        asset_server.free_unused_assets();
+        assert!(!asset_server
+            .server
+            .dependencies
+            .read()
+            .contains_key(&source_path_id));
+
+        // as well as their direct dependencies
+        std::fs::write(dir.path().join("a.direct"), "b.text").unwrap();
+        asset_server.add_loader(DirectLoader);
+        let handle: Handle<TextAsset> = asset_server.load("a.direct");
+        let path = AssetPath::from("a.direct");
+        wait_until(|| {
+            asset_server
+                .server
+                .direct_dependencies
+                .read()
+                .contains_key(&path)
+        });
+        drop(handle);
+        asset_server.mark_unused_assets();
+        asset_server.free_unused_assets();
+        assert!(!asset_server
+            .server
+            .direct_dependencies
+            .read()
+            .contains_key(&path));
+    }
+
+    #[test]
+    fn test_load_direct() {
+        let dir = create_dir_and_file("a.direct");
+        std::fs::write(dir.path().join("a.direct"), "b.direct").unwrap();
+        std::fs::write(dir.path().join("b.direct"), "c.text").unwrap();
+        std::fs::write(dir.path().join("c.text"), "c").unwrap();
+        std::fs::write(dir.path().join("d.direct"), "missing.text").unwrap();
+        std::fs::write(dir.path().join("e.direct"), "a.direct#label").unwrap();
+        std::fs::write(dir.path().join("f.direct"), "f.direct").unwrap();
+        std::fs::write(dir.path().join("g.direct"), "h.direct").unwrap();
+        std::fs::write(dir.path().join("h.direct"), "g.direct#label").unwrap();
+        let asset_server = setup(dir.path());
+        asset_server.add_loader(DirectLoader);
+        asset_server.add_loader(TextLoader);
+        let mut assets = asset_server.register_asset_type::<TextAsset>();
+
+        assert_eq!(
+            "c!!",
+            load_text(&asset_server, &mut assets, "a.direct").unwrap()
+        );
+        // directly loaded assets are not added to their asset storage
+        assert_eq!(
+            LoadState::NotLoaded,
+            asset_server.get_load_state(AssetPath::from("c.text").get_id())
+        );
+        assert_eq!(
+            vec![AssetPath::from("b.direct"), AssetPath::from("c.text")],
+            asset_server.server.direct_dependencies.read()[&AssetPath::from("a.direct")]
+        );
+
+        // changing a directly loaded asset reloads the assets depending on it
+        std::fs::write(dir.path().join("c.text"), "changed").unwrap();
+        asset_server.reload_asset("c.text");
+        let path = AssetPath::from("a.direct");
+        wait_until(|| get_text(&asset_server, &mut assets, &path).as_deref() == Some("changed!!"));
+
+        assert!(load_text(&asset_server, &mut assets, "d.direct").is_err());
+        let err = load_text(&asset_server, &mut assets, "e.direct").unwrap_err();
+        assert!(err.to_string().contains("no asset was loaded"));
+
+        // cyclic loads fail instead of never finishing
+        let err = load_text(&asset_server, &mut assets, "f.direct").unwrap_err();
+        let AssetServerError::AssetLoaderError(err) = &*err else {
+            panic!("unexpected error: {err}");
+        };
+        assert!(matches!(
+            err.downcast_ref(),
+            Some(AssetServerError::CyclicLoadDirect(path)) if *path == AssetPath::from("f.direct")
+        ));
+        let err = load_text(&asset_server, &mut assets, "g.direct").unwrap_err();
+        assert!(err.to_string().contains("loads itself directly"));
+    }
+
+    #[test]
+    fn test_load_queue() {
+        let dir = tempfile::tempdir().unwrap();
+        for path in ["block.order", "a.order", "b.order", "c.order"] {
+            std::fs::write(dir.path().join(path), "").unwrap();
+        }
+        let asset_server = setup(dir.path());
+        let released = Arc::new(std::sync::atomic::AtomicBool::new(false));
+        let order = Arc::new(Mutex::new(Vec::new()));
+        asset_server.add_loader(OrderLoader {
+            released: released.clone(),
+            order: order.clone(),
+        });
+        let _assets = asset_server.register_asset_type::<TextAsset>();
+        asset_server.set_load_limits(AssetLoadLimits {
+            max_concurrent_loads: 1,
+            ..Default::default()
+        });
+
+        let _block: Handle<TextAsset> = asset_server.load("block.order");
+        let a: Handle<TextAsset> = asset_server.load_with_priority("a.order", LoadPriority::LOW);
+        let b: Handle<TextAsset> = asset_server.load_with_priority("b.order", LoadPriority::LOW);
+        let c: Handle<TextAsset> = asset_server.load("c.order");
+        assert!(asset_server.set_load_priority(&b, LoadPriority::HIGH));
+        // queued assets are reported as loading
+        assert_eq!(LoadState::Loading, asset_server.get_load_state(&a));
+        assert_eq!(LoadState::Loading, asset_server.get_load_state(&c));
+
+        // dropping every handle to a queued asset cancels its load
+        let c_id = c.id();
+        drop(c);
+        asset_server.mark_unused_assets();
+        asset_server.free_unused_assets();
+        assert_eq!(LoadState::NotLoaded, asset_server.get_load_state(c_id));
+        assert_eq!(LoadState::Loading, asset_server.get_load_state(&a));
+
+        released.store(true, Ordering::SeqCst);
+        while order.lock().len() < 3 {
+            std::thread::yield_now();
+        }
+        assert_eq!(
+            vec![
+                PathBuf::from("block.order"),
+                PathBuf::from("b.order"),
+                PathBuf::from("a.order")
+            ],
+            *order.lock()
+        );
+        assert_eq!(
+            LoadState::NotLoaded,
+            asset_server.get_load_state(AssetPath::from("c.order").get_id())
+        );
+    }
+
+    #[test]
+    fn test_load_queue_bytes_limit() {
+        let dir = tempfile::tempdir().unwrap();
+        for path in ["block.order", "a.order", "b.order"] {
+            std::fs::write(dir.path().join(path), "1234").unwrap();
+        }
+        let asset_server = setup(dir.path());
+        let released = Arc::new(std::sync::atomic::AtomicBool::new(false));
+        let order = Arc::new(Mutex::new(Vec::new()));
+        asset_server.add_loader(OrderLoader {
+            released: released.clone(),
+            order: order.clone(),
+        });
+        let _assets = asset_server.register_asset_type::<TextAsset>();
+        asset_server.set_load_limits(AssetLoadLimits {
+            max_bytes_in_flight: 4,
+            ..Default::default()
+        });
+
+        // the loads queued together wait for the bytes of the blocked one
+        let _block: Handle<TextAsset> = asset_server.load("block.order");
+        let _a: Handle<TextAsset> = asset_server.load("a.order");
+        let _b: Handle<TextAsset> = asset_server.load("b.order");
+        assert_eq!(4, asset_server.server.load_queue.lock().bytes_in_flight);
+
+        released.store(true, Ordering::SeqCst);
+        wait_until(|| order.lock().len() == 3);
+        assert_eq!(
+            vec![
+                PathBuf::from("block.order"),
+                PathBuf::from("a.order"),
+                PathBuf::from("b.order")
+            ],
+            *order.lock()
+        );
+    }
+
+    #[test]
+    fn test_cancel_started_load() {
+        let dir = tempfile::tempdir().unwrap();
+        for path in ["block.order", "a.order"] {
+            std::fs::write(dir.path().join(path), "").unwrap();
+        }
+        let asset_server = setup(dir.path());
+        let released = Arc::new(std::sync::atomic::AtomicBool::new(false));
+        let order = Arc::new(Mutex::new(Vec::new()));
+        asset_server.add_loader(OrderLoader {
+            released: released.clone(),
+            order: order.clone(),
+        });
+        let _assets = asset_server.register_asset_type::<TextAsset>();
+        asset_server.set_load_limits(AssetLoadLimits {
+            max_concurrent_loads: 1,
+            ..Default::default()
+        });
+        let _block: Handle<TextAsset> = asset_server.load("block.order");
+        let a: Handle<TextAsset> = asset_server.load("a.order");
+
+        // the load of `a` is started, but its task doesn't run before its handle is dropped
+        let load = asset_server.server.load_queue.lock().start_next().unwrap();
+        let a_id = a.id();
+        drop(a);
+        asset_server.mark_unused_assets();
+        asset_server.free_unused_assets();
+        futures_lite::future::block_on(asset_server.run_queued_load(load, 0));
+        assert_eq!(LoadState::NotLoaded, asset_server.get_load_state(a_id));
+
+        released.store(true, Ordering::SeqCst);
+        wait_until(|| !order.lock().is_empty());
+        assert_eq!(vec![PathBuf::from("block.order")], *order.lock());
+    }
 }
@@ -1,13 +1,14 @@
 use crate::{
-    update_asset_storage_system, Asset, AssetLoader, AssetServer, AssetSet, Handle, HandleId,
-    RefChange, ReflectAsset, ReflectHandle,
+    save_assets_system, update_asset_storage_system, Asset, AssetIo, AssetLoader, AssetProcessor,
+    AssetSaver, AssetServer, AssetSet, Handle, HandleId, MemoryAssetIo, RefChange, ReflectAsset,
+    ReflectHandle, EMBEDDED_ASSET_SOURCE,
 };
 use bevy_app::{App, AppTypeRegistry};
 use bevy_ecs::prelude::*;
 use bevy_reflect::{FromReflect, GetTypeRegistration, Reflect};
 use bevy_utils::HashMap;
 use crossbeam_channel::Sender;
-use std::fmt::Debug;
+use std::{fmt::Debug, path::PathBuf};
 
 /// Events that involve assets of type `T`.
 ///
@@ -20,6 +21,14 @@ pub enum AssetEvent<T: Asset> {
     Modified { handle: Handle<T> },
     #[allow(missing_docs)]
     Removed { handle: Handle<T> },
+    /// Sent once an asset loaded by the [`AssetServer`](crate::AssetServer) and all of its
+    /// recursive dependencies are loaded.
+    ///
+    /// See [`AssetServer::get_recursive_dependency_load_state`](crate::AssetServer::get_recursive_dependency_load_state).
+    LoadedWithDependencies {
+        /// The handle to the loaded asset.
+        handle: Handle<T>,
+    },
 }
 
 impl<T: Asset> Debug for AssetEvent<T> {
@@ -46,6 +55,13 @@ impl<T: Asset> Debug for AssetEvent<T> {
                 ))
                 .field("handle", &handle.id())
                 .finish(),
+            AssetEvent::LoadedWithDependencies { handle } => f
+                .debug_struct(&format!(
+                    "AssetEvent<{}>::LoadedWithDependencies",
+                    std::any::type_name::<T>()
+                ))
+                .field("handle", &handle.id())
+                .finish(),
         }
     }
 }
@@ -66,7 +82,7 @@ impl<T: Asset> Debug for AssetEvent<T> {
 #[derive(Debug, Resource)]
 pub struct Assets<T: Asset> {
     assets: HashMap<HandleId, T>,
-    events: Events<AssetEvent<T>>,
+    pub(crate) events: Events<AssetEvent<T>>,
     pub(crate) ref_change_sender: Sender<RefChange>,
 }
 
@@ -315,6 +331,45 @@ pub trait AddAsset {
     fn add_asset_loader<T>(&mut self, loader: T) -> &mut Self
     where
         T: AssetLoader;
+
+    /// Adds an asset processor `T` using default values.
+    ///
+    /// The default values may come from the `World` or from `T::default()`.
+    fn init_asset_processor<T>(&mut self) -> &mut Self
+    where
+        T: AssetProcessor + FromWorld;
+
+    /// Adds the provided asset processor to the application.
+    fn add_asset_processor<T>(&mut self, processor: T) -> &mut Self
+    where
+        T: AssetProcessor;
+
+    /// Adds an asset saver `T` using default values.
+    ///
+    /// The default values may come from the `World` or from `T::default()`.
+    fn init_asset_saver<T>(&mut self) -> &mut Self
+    where
+        T: AssetSaver + FromWorld;
+
+    /// Adds the provided asset saver to the application, replacing the saver of the same asset
+    /// type if it exists.
+    ///
+    /// See [`AssetServer::save`].
+    fn add_asset_saver<T>(&mut self, saver: T) -> &mut Self
+    where
+        T: AssetSaver;
+
+    /// Adds a named asset source to the application.
+    ///
+    /// See [`AssetServer::add_source`].
+    fn add_asset_source<T>(&mut self, name: &str, asset_io: T) -> &mut Self
+    where
+        T: AssetIo;
+
+    /// Adds an asset to the `embedded` asset source, at the provided path.
+    ///
+    /// This is usually called through the [`embedded_asset!`](crate::embedded_asset) macro.
+    fn add_embedded_asset(&mut self, path: impl Into<PathBuf>, bytes: &'static [u8]) -> &mut Self;
 }
 
 impl AddAsset for App {
@@ -402,6 +457,65 @@ impl AddAsset for App {
         self.world.resource_mut::<AssetServer>().add_loader(loader);
         self
     }
+
+    fn init_asset_processor<T>(&mut self) -> &mut Self
+    where
+        T: AssetProcessor + FromWorld,
+    {
+        let result = T::from_world(&mut self.world);
+        self.add_asset_processor(result)
+    }
+
+    fn add_asset_processor<T>(&mut self, processor: T) -> &mut Self
+    where
+        T: AssetProcessor,
+    {
+        self.world
+            .resource_mut::<AssetServer>()
+            .add_processor(processor);
+        self
+    }
+
+    fn init_asset_saver<T>(&mut self) -> &mut Self
+    where
+        T: AssetSaver + FromWorld,
+    {
+        let result = T::from_world(&mut self.world);
+        self.add_asset_saver(result)
+    }
+
+    fn add_asset_saver<T>(&mut self, saver: T) -> &mut Self
+    where
+        T: AssetSaver,
+    {
+        if self.world.resource::<AssetServer>().add_saver(saver) {
+            self.add_system(save_assets_system::<T::Asset>.in_base_set(AssetSet::AssetEvents));
+        }
+        self
+    }
+
+    fn add_asset_source<T>(&mut self, name: &str, asset_io: T) -> &mut Self
+    where
+        T: AssetIo,
+    {
+        self.world
+            .resource::<AssetServer>()
+            .add_source(name, asset_io);
+        self
+    }
+
+    fn add_embedded_asset(&mut self, path: impl Into<PathBuf>, bytes: &'static [u8]) -> &mut Self {
+        let embedded = self
+            .world
+            .resource::<AssetServer>()
+            .get_source(EMBEDDED_ASSET_SOURCE)
+            .expect("the `embedded` asset source should be added by the `AssetPlugin`");
+        embedded
+            .downcast_ref::<MemoryAssetIo>()
+            .expect("the `embedded` asset source should be a `MemoryAssetIo`")
+            .insert(path, bytes);
+        self
+    }
 }
 
 /// Loads an internal asset.
@@ -488,7 +602,7 @@ mod tests {
 
     #[test]
     fn asset_overwriting() {
-        #[derive(bevy_reflect::TypeUuid)]
+        #[derive(bevy_reflect::TypePath, bevy_reflect::TypeUuid)]
         #[uuid = "44115972-f31b-46e5-be5c-2b9aece6a52f"]
         struct MyAsset;
         let mut app = App::new();
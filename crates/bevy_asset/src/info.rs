@@ -1,7 +1,7 @@
-use crate::{path::AssetPath, LabelId};
+use crate::{path::AssetPath, AssetServerError, LabelId};
 use bevy_utils::{HashMap, HashSet, Uuid};
 use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
+use std::{path::PathBuf, sync::Arc};
 
 /// Metadata for an asset source.
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -32,6 +32,8 @@ pub struct SourceInfo {
     pub asset_types: HashMap<LabelId, Uuid>,
     /// The load state of the source.
     pub load_state: LoadState,
+    /// The error that made the source fail to load, if its load state is [`LoadState::Failed`].
+    pub load_error: Option<Arc<AssetServerError>>,
     /// A collection to track which assets were sent to their asset storages.
     pub committed_assets: HashSet<LabelId>,
     /// Current version of the source.
@@ -39,6 +41,19 @@ pub struct SourceInfo {
 }
 
 impl SourceInfo {
+    /// Creates the information of an asset source which hasn't been loaded.
+    pub(crate) fn new(path: PathBuf) -> Self {
+        SourceInfo {
+            meta: None,
+            path,
+            asset_types: Default::default(),
+            load_state: LoadState::NotLoaded,
+            load_error: None,
+            committed_assets: Default::default(),
+            version: 0,
+        }
+    }
+
     /// Returns `true` if all assets tracked by the source were loaded into their asset storages.
     pub fn is_loaded(&self) -> bool {
         self.meta.as_ref().map_or(false, |meta| {
@@ -1,25 +1,28 @@
 #[cfg(feature = "filesystem_watcher")]
-use crate::{filesystem_watcher::FilesystemWatcher, AssetServer};
+use crate::{filesystem_watcher::FilesystemWatcher, AssetPath, AssetServer, META_FILE_EXTENSION};
 use crate::{AssetIo, AssetIoError, Metadata};
 use anyhow::Result;
 #[cfg(feature = "filesystem_watcher")]
 use bevy_ecs::system::Res;
 use bevy_utils::BoxedFuture;
 #[cfg(feature = "filesystem_watcher")]
-use bevy_utils::HashSet;
+use bevy_utils::{HashMap, HashSet};
 #[cfg(feature = "filesystem_watcher")]
 use crossbeam_channel::TryRecvError;
 use fs::File;
 #[cfg(feature = "filesystem_watcher")]
-use parking_lot::RwLock;
-#[cfg(feature = "filesystem_watcher")]
-use std::sync::Arc;
+use parking_lot::{Mutex, RwLock};
 use std::{
     convert::TryFrom,
     env, fs,
     io::Read,
     path::{Path, PathBuf},
 };
+#[cfg(feature = "filesystem_watcher")]
+use std::{
+    hash::{Hash, Hasher},
+    sync::Arc,
+};
 
 /// I/O implementation for the local filesystem.
 ///
@@ -28,6 +31,10 @@ pub struct FileAssetIo {
     root_path: PathBuf,
     #[cfg(feature = "filesystem_watcher")]
     filesystem_watcher: Arc<RwLock<Option<FilesystemWatcher>>>,
+    /// The hash of the contents of the files written with [`AssetIo::save_path`], so that saving
+    /// an asset doesn't reload it.
+    #[cfg(feature = "filesystem_watcher")]
+    saved_files: Mutex<HashMap<PathBuf, u64>>,
 }
 
 impl FileAssetIo {
@@ -39,6 +46,8 @@ impl FileAssetIo {
         let file_asset_io = FileAssetIo {
             #[cfg(feature = "filesystem_watcher")]
             filesystem_watcher: Default::default(),
+            #[cfg(feature = "filesystem_watcher")]
+            saved_files: Default::default(),
             root_path: Self::get_base_path().join(path.as_ref()),
         };
         if watch_for_changes {
@@ -109,6 +118,25 @@ impl AssetIo for FileAssetIo {
         })
     }
 
+    fn save_path<'a>(
+        &'a self,
+        path: &'a Path,
+        bytes: &'a [u8],
+    ) -> BoxedFuture<'a, Result<(), AssetIoError>> {
+        Box::pin(async move {
+            let full_path = self.root_path.join(path);
+            if let Some(parent) = full_path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            #[cfg(feature = "filesystem_watcher")]
+            self.saved_files
+                .lock()
+                .insert(full_path.clone(), hash_bytes(bytes));
+            fs::write(&full_path, bytes)?;
+            Ok(())
+        })
+    }
+
     fn read_directory(
         &self,
         path: &Path,
@@ -169,13 +197,25 @@ impl AssetIo for FileAssetIo {
     all(not(target_arch = "wasm32"), not(target_os = "android"))
 ))]
 pub fn filesystem_watcher_system(asset_server: Res<AssetServer>) {
+    if let Some(asset_io) = asset_server.server.asset_io.downcast_ref::<FileAssetIo>() {
+        reload_changed_assets(&asset_server, asset_io, None);
+    }
+    let named_sources = asset_server.server.named_sources.read().clone();
+    for (name, asset_io) in &named_sources {
+        if let Some(asset_io) = asset_io.downcast_ref::<FileAssetIo>() {
+            reload_changed_assets(&asset_server, asset_io, Some(name));
+        }
+    }
+}
+
+/// Reloads the assets changed in the asset source with the provided name, or in the default
+/// source if `None`.
+#[cfg(all(
+    feature = "filesystem_watcher",
+    all(not(target_arch = "wasm32"), not(target_os = "android"))
+))]
+fn reload_changed_assets(asset_server: &AssetServer, asset_io: &FileAssetIo, source: Option<&str>) {
     let mut changed = HashSet::default();
-    let asset_io =
-        if let Some(asset_io) = asset_server.server.asset_io.downcast_ref::<FileAssetIo>() {
-            asset_io
-        } else {
-            return;
-        };
     let watcher = asset_io.filesystem_watcher.read();
     if let Some(ref watcher) = *watcher {
         loop {
@@ -191,9 +231,28 @@ pub fn filesystem_watcher_system(asset_server: Res<AssetServer>) {
             } = event
             {
                 for path in &paths {
-                    if !changed.contains(path) {
+                    if !changed.contains(path) && !is_saved_file(asset_io, path) {
                         let relative_path = path.strip_prefix(&asset_io.root_path).unwrap();
-                        let _ = asset_server.load_untracked(relative_path.into(), true);
+                        // changing a meta file reloads the asset it belongs to
+                        let relative_path =
+                            if relative_path.extension() == Some(META_FILE_EXTENSION.as_ref()) {
+                                relative_path.with_extension("")
+                            } else {
+                                relative_path.to_owned()
+                            };
+                        let asset_path = AssetPath::from(relative_path.as_path());
+                        match source {
+                            Some(source) => {
+                                // assets overridden by this source are loaded without a source
+                                if asset_server.is_override_source(source) {
+                                    asset_server.reload_asset(asset_path.clone());
+                                }
+                                asset_server.reload_asset(asset_path.with_source(source));
+                            }
+                            None => {
+                                asset_server.reload_asset(asset_path);
+                            }
+                        }
                     }
                 }
                 changed.extend(paths);
@@ -201,3 +260,28 @@ pub fn filesystem_watcher_system(asset_server: Res<AssetServer>) {
         }
     }
 }
+
+/// Returns `true` if the file at the provided path still has the contents written by
+/// [`AssetIo::save_path`].
+#[cfg(all(
+    feature = "filesystem_watcher",
+    all(not(target_arch = "wasm32"), not(target_os = "android"))
+))]
+pub(crate) fn is_saved_file(asset_io: &FileAssetIo, path: &Path) -> bool {
+    let mut saved_files = asset_io.saved_files.lock();
+    let Some(saved_hash) = saved_files.get(path) else {
+        return false;
+    };
+    if fs::read(path).map(|bytes| hash_bytes(&bytes)).ok() == Some(*saved_hash) {
+        return true;
+    }
+    saved_files.remove(path);
+    false
+}
+
+#[cfg(feature = "filesystem_watcher")]
+fn hash_bytes(bytes: &[u8]) -> u64 {
+    let mut hasher = crate::path::get_hasher();
+    bytes.hash(&mut hasher);
+    hasher.finish()
+}
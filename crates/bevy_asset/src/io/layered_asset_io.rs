@@ -0,0 +1,141 @@
+use crate::{AssetIo, AssetIoError, Metadata};
+use anyhow::Result;
+use bevy_utils::{BoxedFuture, HashSet};
+use std::path::{Path, PathBuf};
+
+/// I/O implementation stacking several asset I/Os on top of each other.
+///
+/// Assets are read from the first layer containing them, so the layers added first override the
+/// assets of the following ones, and directories list the assets of every layer. This can be
+/// used to patch a [`PackedAssetIo`](crate::PackedAssetIo) with loose files:
+///
+/// ```no_run
+/// # use bevy_asset::{AssetServer, FileAssetIo, LayeredAssetIo, PackedAssetIo};
+/// let asset_io = LayeredAssetIo::new()
+///     .with_layer(FileAssetIo::new("patch", false))
+///     .with_layer(PackedAssetIo::open("assets.pak").unwrap());
+/// let asset_server = AssetServer::new(asset_io);
+/// ```
+#[derive(Default)]
+pub struct LayeredAssetIo {
+    layers: Vec<Box<dyn AssetIo>>,
+}
+
+impl LayeredAssetIo {
+    /// Creates a new layered asset I/O without any layers.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a layer under the existing layers.
+    pub fn with_layer<T: AssetIo>(mut self, layer: T) -> Self {
+        self.layers.push(Box::new(layer));
+        self
+    }
+
+    /// Returns the layers, from the highest priority to the lowest.
+    pub fn layers(&self) -> impl Iterator<Item = &dyn AssetIo> {
+        self.layers.iter().map(|layer| &**layer)
+    }
+}
+
+impl AssetIo for LayeredAssetIo {
+    fn load_path<'a>(&'a self, path: &'a Path) -> BoxedFuture<'a, Result<Vec<u8>, AssetIoError>> {
+        Box::pin(async move {
+            for layer in &self.layers {
+                match layer.load_path(path).await {
+                    Err(AssetIoError::NotFound(_)) => continue,
+                    result => return result,
+                }
+            }
+            Err(AssetIoError::NotFound(path.to_owned()))
+        })
+    }
+
+    /// Writes the file to the first layer.
+    fn save_path<'a>(
+        &'a self,
+        path: &'a Path,
+        bytes: &'a [u8],
+    ) -> BoxedFuture<'a, Result<(), AssetIoError>> {
+        match self.layers.first() {
+            Some(layer) => layer.save_path(path, bytes),
+            None => Box::pin(async move { Err(AssetIoError::WriteNotSupported(path.to_owned())) }),
+        }
+    }
+
+    fn read_directory(
+        &self,
+        path: &Path,
+    ) -> Result<Box<dyn Iterator<Item = PathBuf>>, AssetIoError> {
+        let mut children = HashSet::default();
+        let mut found = false;
+        for layer in &self.layers {
+            if let Ok(layer_children) = layer.read_directory(path) {
+                children.extend(layer_children);
+                found = true;
+            }
+        }
+        if !found {
+            return Err(AssetIoError::NotFound(path.to_owned()));
+        }
+        Ok(Box::new(children.into_iter()))
+    }
+
+    fn get_metadata(&self, path: &Path) -> Result<Metadata, AssetIoError> {
+        self.layers
+            .iter()
+            .find_map(|layer| layer.get_metadata(path).ok())
+            .ok_or_else(|| AssetIoError::NotFound(path.to_owned()))
+    }
+
+    fn watch_path_for_changes(&self, path: &Path) -> Result<(), AssetIoError> {
+        for layer in &self.layers {
+            layer.watch_path_for_changes(path)?;
+        }
+        Ok(())
+    }
+
+    fn watch_for_changes(&self) -> Result<(), AssetIoError> {
+        for layer in &self.layers {
+            layer.watch_for_changes()?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LayeredAssetIo;
+    use crate::{AssetIo, MemoryAssetIo};
+    use std::path::{Path, PathBuf};
+
+    #[test]
+    fn layered_asset_io() {
+        let patch = MemoryAssetIo::new();
+        patch.insert("dir/a.txt", b"patched".as_slice());
+        patch.insert("dir/new.txt", b"new".as_slice());
+        let base = MemoryAssetIo::new();
+        base.insert("dir/a.txt", b"base".as_slice());
+        base.insert("dir/b.txt", b"base".as_slice());
+        let asset_io = LayeredAssetIo::new().with_layer(patch).with_layer(base);
+
+        let load = |path: &str| futures_lite::future::block_on(asset_io.load_path(Path::new(path)));
+        assert_eq!(b"patched".to_vec(), load("dir/a.txt").unwrap());
+        assert_eq!(b"base".to_vec(), load("dir/b.txt").unwrap());
+        assert!(load("dir/c.txt").is_err());
+
+        let mut children: Vec<_> = asset_io.read_directory(Path::new("dir")).unwrap().collect();
+        children.sort();
+        assert_eq!(
+            vec![
+                PathBuf::from("dir/a.txt"),
+                PathBuf::from("dir/b.txt"),
+                PathBuf::from("dir/new.txt")
+            ],
+            children
+        );
+        assert!(asset_io.is_file(Path::new("dir/new.txt")));
+        assert!(asset_io.read_directory(Path::new("missing")).is_err());
+    }
+}
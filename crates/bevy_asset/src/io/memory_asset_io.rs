@@ -0,0 +1,222 @@
+use crate::{
+    implied_directory_children, implied_metadata, AssetIo, AssetIoError, FileType, Metadata,
+};
+use anyhow::Result;
+use bevy_utils::{BoxedFuture, HashMap};
+use parking_lot::RwLock;
+use std::{
+    borrow::Cow,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+/// The name of the asset source holding the assets embedded in the binary with
+/// [`embedded_asset!`](crate::embedded_asset).
+pub const EMBEDDED_ASSET_SOURCE: &str = "embedded";
+
+/// I/O implementation for assets stored in memory.
+///
+/// Directories are implied by the paths of the stored files. Cloning a memory asset I/O returns
+/// a handle to the same files, so files can still be inserted after it is added to an
+/// [`AssetServer`](crate::AssetServer). This asset I/O is available on every platform, which
+/// makes it useful for assets embedded in the binary and for tests that shouldn't touch the disk.
+#[derive(Clone, Default)]
+pub struct MemoryAssetIo {
+    files: Arc<RwLock<HashMap<PathBuf, Cow<'static, [u8]>>>>,
+}
+
+impl MemoryAssetIo {
+    /// Creates a new memory asset I/O without any files.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Inserts a file at the provided path, replacing the previous one if it exists.
+    pub fn insert(&self, path: impl Into<PathBuf>, bytes: impl Into<Cow<'static, [u8]>>) {
+        self.files.write().insert(path.into(), bytes.into());
+    }
+
+    /// Removes the file at the provided path, returning `true` if it existed.
+    pub fn remove(&self, path: &Path) -> bool {
+        self.files.write().remove(path).is_some()
+    }
+
+    /// Returns `true` if a file exists at the provided path.
+    pub fn contains(&self, path: &Path) -> bool {
+        self.files.read().contains_key(path)
+    }
+}
+
+impl AssetIo for MemoryAssetIo {
+    fn load_path<'a>(&'a self, path: &'a Path) -> BoxedFuture<'a, Result<Vec<u8>, AssetIoError>> {
+        Box::pin(async move {
+            self.files
+                .read()
+                .get(path)
+                .map(|bytes| bytes.to_vec())
+                .ok_or_else(|| AssetIoError::NotFound(path.to_owned()))
+        })
+    }
+
+    fn save_path<'a>(
+        &'a self,
+        path: &'a Path,
+        bytes: &'a [u8],
+    ) -> BoxedFuture<'a, Result<(), AssetIoError>> {
+        self.insert(path, bytes.to_vec());
+        Box::pin(async move { Ok(()) })
+    }
+
+    fn read_directory(
+        &self,
+        path: &Path,
+    ) -> Result<Box<dyn Iterator<Item = PathBuf>>, AssetIoError> {
+        implied_directory_children(self.files.read().keys(), path)
+    }
+
+    fn get_metadata(&self, path: &Path) -> Result<Metadata, AssetIoError> {
+        let files = self.files.read();
+        match files.get(path) {
+            Some(bytes) => Ok(Metadata::new(FileType::File).with_size(bytes.len() as u64)),
+            None => implied_metadata(files.keys(), path),
+        }
+    }
+
+    fn watch_path_for_changes(&self, _path: &Path) -> Result<(), AssetIoError> {
+        Ok(())
+    }
+
+    fn watch_for_changes(&self) -> Result<(), AssetIoError> {
+        Ok(())
+    }
+}
+
+/// Embeds an asset in the binary, making it loadable from the `embedded` asset source.
+///
+/// The file is read at compile time from `$path_str`, relative to the current file like
+/// [`include_bytes!`], and can be loaded with the path `embedded://<crate>/<path>`, where
+/// `<crate>` is the name of the crate invoking the macro.
+///
+/// ```ignore
+/// # use bevy_app::App;
+/// # use bevy_asset::{embedded_asset, AssetPlugin};
+/// # let mut app = App::new();
+/// # app.add_plugin(AssetPlugin::default());
+/// embedded_asset!(app, "shader.wgsl");
+/// // the asset can then be loaded with
+/// // asset_server.load("embedded://my_crate/shader.wgsl")
+/// ```
+#[macro_export]
+macro_rules! embedded_asset {
+    ($app: ident, $path_str: expr) => {{
+        use $crate::AddAsset as _;
+        let crate_name = module_path!().split("::").next().unwrap();
+        $app.add_embedded_asset(
+            std::path::Path::new(crate_name).join($path_str),
+            include_bytes!($path_str),
+        );
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MemoryAssetIo;
+    use crate::{
+        AddAsset, AssetIo, AssetLoader, AssetServer, Assets, Handle, LoadContext, LoadedAsset,
+    };
+    use bevy_app::App;
+    use bevy_reflect::{TypePath, TypeUuid};
+    use bevy_utils::BoxedFuture;
+    use std::path::{Path, PathBuf};
+
+    #[test]
+    fn memory_asset_io() {
+        let asset_io = MemoryAssetIo::new();
+        asset_io.insert("a.txt", b"a".as_slice());
+        asset_io.insert("dir/b.txt", b"b".to_vec());
+        asset_io.insert("dir/sub/c.txt", b"c".as_slice());
+
+        assert_eq!(
+            b"b".to_vec(),
+            futures_lite::future::block_on(asset_io.load_path(Path::new("dir/b.txt"))).unwrap()
+        );
+        assert!(futures_lite::future::block_on(asset_io.load_path(Path::new("dir"))).is_err());
+
+        assert!(asset_io.is_file(Path::new("a.txt")));
+        assert!(asset_io.is_dir(Path::new("")));
+        assert!(asset_io.is_dir(Path::new("dir/sub")));
+        assert!(!asset_io.is_dir(Path::new("di")));
+        assert!(asset_io.get_metadata(Path::new("missing")).is_err());
+        assert_eq!(
+            Some(1),
+            asset_io.get_metadata(Path::new("a.txt")).unwrap().size()
+        );
+
+        let mut children: Vec<_> = asset_io.read_directory(Path::new("dir")).unwrap().collect();
+        children.sort();
+        assert_eq!(
+            vec![PathBuf::from("dir/b.txt"), PathBuf::from("dir/sub")],
+            children
+        );
+
+        assert!(asset_io.remove(Path::new("a.txt")));
+        assert!(!asset_io.contains(Path::new("a.txt")));
+    }
+
+    #[test]
+    fn embedded_asset() {
+        #[derive(TypePath, TypeUuid)]
+        #[uuid = "1973bfd8-af41-48de-b3ef-f3a15550dcfb"]
+        struct SourceFile(Vec<u8>);
+
+        struct SourceFileLoader;
+        impl AssetLoader for SourceFileLoader {
+            fn load<'a>(
+                &'a self,
+                bytes: &'a [u8],
+                load_context: &'a mut LoadContext,
+            ) -> BoxedFuture<'a, anyhow::Result<()>> {
+                load_context.set_default_asset(LoadedAsset::new(SourceFile(bytes.to_vec())));
+                Box::pin(async { Ok(()) })
+            }
+
+            fn extensions(&self) -> &[&str] {
+                &["rs"]
+            }
+        }
+
+        let mut app = App::new();
+        app.add_plugin(bevy_core::TaskPoolPlugin::default())
+            .add_plugin(bevy_core::TypeRegistrationPlugin)
+            .add_plugin(crate::AssetPlugin::default())
+            .add_asset::<SourceFile>()
+            .add_asset_loader(SourceFileLoader);
+        embedded_asset!(app, "memory_asset_io.rs");
+
+        let handle: Handle<SourceFile> = app
+            .world
+            .resource::<AssetServer>()
+            .load("embedded://bevy_asset/memory_asset_io.rs");
+        let start = std::time::Instant::now();
+        while app
+            .world
+            .resource::<Assets<SourceFile>>()
+            .get(&handle)
+            .is_none()
+        {
+            assert!(
+                start.elapsed() < std::time::Duration::from_secs(10),
+                "timed out"
+            );
+            app.update();
+        }
+        assert_eq!(
+            include_bytes!("memory_asset_io.rs").as_slice(),
+            app.world
+                .resource::<Assets<SourceFile>>()
+                .get(&handle)
+                .unwrap()
+                .0
+        );
+    }
+}
@@ -47,12 +47,23 @@ impl TryFrom<std::fs::FileType> for FileType {
 #[derive(Debug, Clone)]
 pub struct Metadata {
     file_type: FileType,
+    size: Option<u64>,
 }
 
 impl Metadata {
     /// Creates new metadata information.
     pub fn new(file_type: FileType) -> Self {
-        Self { file_type }
+        Self {
+            file_type,
+            size: None,
+        }
+    }
+
+    /// Sets the size of the file in bytes.
+    #[must_use]
+    pub fn with_size(mut self, size: u64) -> Self {
+        self.size = Some(size);
+        self
     }
 
     /// Returns the file type.
@@ -72,6 +83,12 @@ impl Metadata {
     pub const fn is_file(&self) -> bool {
         self.file_type.is_file()
     }
+
+    /// Returns the size of the file in bytes, if it is known.
+    #[inline]
+    pub const fn size(&self) -> Option<u64> {
+        self.size
+    }
 }
 
 impl TryFrom<std::fs::Metadata> for Metadata {
@@ -80,6 +97,7 @@ impl TryFrom<std::fs::Metadata> for Metadata {
     fn try_from(metadata: std::fs::Metadata) -> Result<Self, Self::Error> {
         Ok(Self {
             file_type: metadata.file_type().try_into()?,
+            size: metadata.is_file().then_some(metadata.len()),
         })
     }
 }
@@ -5,7 +5,10 @@ mod file_asset_io;
 #[cfg(target_arch = "wasm32")]
 mod wasm_asset_io;
 
+mod layered_asset_io;
+mod memory_asset_io;
 mod metadata;
+mod packed_asset_io;
 
 #[cfg(target_os = "android")]
 pub use android_asset_io::*;
@@ -14,10 +17,13 @@ pub use file_asset_io::*;
 #[cfg(target_arch = "wasm32")]
 pub use wasm_asset_io::*;
 
+pub use layered_asset_io::*;
+pub use memory_asset_io::*;
 pub use metadata::*;
+pub use packed_asset_io::*;
 
 use anyhow::Result;
-use bevy_utils::BoxedFuture;
+use bevy_utils::{BoxedFuture, HashSet};
 use downcast_rs::{impl_downcast, Downcast};
 use std::{
     io,
@@ -39,6 +45,10 @@ pub enum AssetIoError {
     /// Failed to watch path.
     #[error("failed to watch path: {0}")]
     PathWatchError(PathBuf),
+
+    /// The asset I/O does not support writing files.
+    #[error("writing is not supported: {0}")]
+    WriteNotSupported(PathBuf),
 }
 
 /// A storage provider for an [`AssetServer`].
@@ -56,6 +66,18 @@ pub trait AssetIo: Downcast + Send + Sync + 'static {
     /// Returns a future to load the full file data at the provided path.
     fn load_path<'a>(&'a self, path: &'a Path) -> BoxedFuture<'a, Result<Vec<u8>, AssetIoError>>;
 
+    /// Returns a future to write the full file data at the provided path, creating the file or
+    /// replacing its contents.
+    ///
+    /// Asset I/Os are read-only by default, failing with [`AssetIoError::WriteNotSupported`].
+    fn save_path<'a>(
+        &'a self,
+        path: &'a Path,
+        _bytes: &'a [u8],
+    ) -> BoxedFuture<'a, Result<(), AssetIoError>> {
+        Box::pin(async move { Err(AssetIoError::WriteNotSupported(path.to_owned())) })
+    }
+
     /// Returns an iterator of directory entry names at the provided path.
     fn read_directory(
         &self,
@@ -89,3 +111,41 @@ pub trait AssetIo: Downcast + Send + Sync + 'static {
 }
 
 impl_downcast!(AssetIo);
+
+/// Returns the metadata of the entry at `path` in a flat list of file paths, where directories
+/// are implied by the paths of the files they contain.
+pub(crate) fn implied_metadata<'a>(
+    file_paths: impl Iterator<Item = &'a PathBuf>,
+    path: &Path,
+) -> Result<Metadata, AssetIoError> {
+    let mut is_dir = path.as_os_str().is_empty();
+    for file_path in file_paths {
+        if file_path == path {
+            return Ok(Metadata::new(FileType::File));
+        }
+        is_dir |= file_path.starts_with(path);
+    }
+    if is_dir {
+        Ok(Metadata::new(FileType::Directory))
+    } else {
+        Err(AssetIoError::NotFound(path.to_owned()))
+    }
+}
+
+/// Returns the children of the directory at `path` in a flat list of file paths, where
+/// directories are implied by the paths of the files they contain.
+pub(crate) fn implied_directory_children<'a>(
+    file_paths: impl Iterator<Item = &'a PathBuf>,
+    path: &Path,
+) -> Result<Box<dyn Iterator<Item = PathBuf>>, AssetIoError> {
+    let children: HashSet<PathBuf> = file_paths
+        .filter_map(|file_path| {
+            let child = file_path.strip_prefix(path).ok()?.components().next()?;
+            Some(path.join(child))
+        })
+        .collect();
+    if children.is_empty() && !path.as_os_str().is_empty() {
+        return Err(AssetIoError::NotFound(path.to_owned()));
+    }
+    Ok(Box::new(children.into_iter()))
+}
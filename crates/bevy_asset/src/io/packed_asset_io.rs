@@ -0,0 +1,248 @@
+use crate::{
+    implied_directory_children, implied_metadata, AssetIo, AssetIoError, FileType, Metadata,
+};
+use anyhow::Result;
+use bevy_utils::{BoxedFuture, HashMap};
+use std::{
+    fs::{self, File},
+    io::{self, BufReader, BufWriter, Read, Seek, SeekFrom, Write},
+    path::{Component, Path, PathBuf},
+};
+
+const PACKED_ARCHIVE_MAGIC: &[u8; 4] = b"BPAK";
+const PACKED_ARCHIVE_VERSION: u32 = 1;
+
+/// The location of a file in a packed archive.
+#[derive(Debug, Clone, Copy)]
+struct PackedEntry {
+    offset: u64,
+    len: u64,
+}
+
+/// I/O implementation for assets packed in a single archive file.
+///
+/// Packed archives are created from an asset folder with [`PackedAssetIo::pack_folder`]. Only
+/// the index of the archive is kept in memory: each asset is read from the archive file when it
+/// is loaded. To patch the assets of an archive with loose files, layer it under a
+/// [`FileAssetIo`](crate::FileAssetIo) with a [`LayeredAssetIo`](crate::LayeredAssetIo).
+///
+/// An archive starts with the `BPAK` magic bytes and a format version, followed by an index of
+/// the paths, offsets and lengths of its files, and then the contents of the files. All integers
+/// are stored in little endian.
+pub struct PackedAssetIo {
+    archive_path: PathBuf,
+    entries: HashMap<PathBuf, PackedEntry>,
+}
+
+impl PackedAssetIo {
+    /// Opens the packed archive at the provided path, reading its index.
+    pub fn open<P: AsRef<Path>>(archive_path: P) -> io::Result<Self> {
+        let archive_path = archive_path.as_ref().to_owned();
+        let mut reader = BufReader::new(File::open(&archive_path)?);
+
+        let mut magic = [0; 4];
+        reader.read_exact(&mut magic)?;
+        if &magic != PACKED_ARCHIVE_MAGIC {
+            return Err(invalid_data("not a packed asset archive"));
+        }
+        let version = read_u32(&mut reader)?;
+        if version != PACKED_ARCHIVE_VERSION {
+            return Err(invalid_data(format!(
+                "unsupported packed asset archive version {version}"
+            )));
+        }
+
+        let entry_count = read_u32(&mut reader)?;
+        let mut entries = HashMap::default();
+        for _ in 0..entry_count {
+            let mut path = vec![0; read_u32(&mut reader)? as usize];
+            reader.read_exact(&mut path)?;
+            let path = String::from_utf8(path)
+                .map_err(|_| invalid_data("packed asset path is not valid UTF-8"))?;
+            let entry = PackedEntry {
+                offset: read_u64(&mut reader)?,
+                len: read_u64(&mut reader)?,
+            };
+            entries.insert(path.split('/').collect(), entry);
+        }
+
+        Ok(Self {
+            archive_path,
+            entries,
+        })
+    }
+
+    /// Packs the files in the provided folder recursively into an archive at `archive_path`,
+    /// returning the number of packed files.
+    ///
+    /// This is meant to be called from a build script or a separate tool when preparing a
+    /// release, after [processing](crate::AssetServer::process_folder) the assets if needed.
+    pub fn pack_folder<P: AsRef<Path>, Q: AsRef<Path>>(
+        folder: P,
+        archive_path: Q,
+    ) -> io::Result<usize> {
+        let folder = folder.as_ref();
+        let mut files = Vec::new();
+        collect_files(folder, folder, &mut files)?;
+        files.sort();
+
+        let paths = files
+            .iter()
+            .map(|path| archive_entry_path(path))
+            .collect::<io::Result<Vec<_>>>()?;
+        let index_len: usize = paths.iter().map(|path| 4 + path.len() + 16).sum();
+        let mut offset = (PACKED_ARCHIVE_MAGIC.len() + 8 + index_len) as u64;
+
+        let mut writer = BufWriter::new(File::create(archive_path)?);
+        writer.write_all(PACKED_ARCHIVE_MAGIC)?;
+        writer.write_all(&PACKED_ARCHIVE_VERSION.to_le_bytes())?;
+        writer.write_all(&(files.len() as u32).to_le_bytes())?;
+        for (file, path) in files.iter().zip(&paths) {
+            let len = fs::metadata(folder.join(file))?.len();
+            writer.write_all(&(path.len() as u32).to_le_bytes())?;
+            writer.write_all(path.as_bytes())?;
+            writer.write_all(&offset.to_le_bytes())?;
+            writer.write_all(&len.to_le_bytes())?;
+            offset += len;
+        }
+        for file in &files {
+            io::copy(&mut File::open(folder.join(file))?, &mut writer)?;
+        }
+        writer.flush()?;
+
+        Ok(files.len())
+    }
+
+    /// Returns the path of the packed archive.
+    pub fn archive_path(&self) -> &Path {
+        &self.archive_path
+    }
+}
+
+impl AssetIo for PackedAssetIo {
+    fn load_path<'a>(&'a self, path: &'a Path) -> BoxedFuture<'a, Result<Vec<u8>, AssetIoError>> {
+        Box::pin(async move {
+            let entry = self
+                .entries
+                .get(path)
+                .ok_or_else(|| AssetIoError::NotFound(path.to_owned()))?;
+            let mut file = File::open(&self.archive_path)?;
+            file.seek(SeekFrom::Start(entry.offset))?;
+            let mut bytes = vec![0; entry.len as usize];
+            file.read_exact(&mut bytes)?;
+            Ok(bytes)
+        })
+    }
+
+    fn read_directory(
+        &self,
+        path: &Path,
+    ) -> Result<Box<dyn Iterator<Item = PathBuf>>, AssetIoError> {
+        implied_directory_children(self.entries.keys(), path)
+    }
+
+    fn get_metadata(&self, path: &Path) -> Result<Metadata, AssetIoError> {
+        match self.entries.get(path) {
+            Some(entry) => Ok(Metadata::new(FileType::File).with_size(entry.len)),
+            None => implied_metadata(self.entries.keys(), path),
+        }
+    }
+
+    fn watch_path_for_changes(&self, _path: &Path) -> Result<(), AssetIoError> {
+        Ok(())
+    }
+
+    fn watch_for_changes(&self) -> Result<(), AssetIoError> {
+        Ok(())
+    }
+}
+
+fn invalid_data(error: impl Into<Box<dyn std::error::Error + Send + Sync>>) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, error)
+}
+
+fn read_u32(reader: &mut impl Read) -> io::Result<u32> {
+    let mut bytes = [0; 4];
+    reader.read_exact(&mut bytes)?;
+    Ok(u32::from_le_bytes(bytes))
+}
+
+fn read_u64(reader: &mut impl Read) -> io::Result<u64> {
+    let mut bytes = [0; 8];
+    reader.read_exact(&mut bytes)?;
+    Ok(u64::from_le_bytes(bytes))
+}
+
+/// Collects the paths of the files in `path` recursively, relative to `root`.
+fn collect_files(root: &Path, path: &Path, files: &mut Vec<PathBuf>) -> io::Result<()> {
+    for entry in fs::read_dir(path)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            collect_files(root, &path, files)?;
+        } else {
+            files.push(path.strip_prefix(root).unwrap().to_owned());
+        }
+    }
+    Ok(())
+}
+
+/// Returns the path of a file in an archive, which uses `/` as separator on every platform.
+fn archive_entry_path(path: &Path) -> io::Result<String> {
+    let components = path
+        .components()
+        .map(|component| match component {
+            Component::Normal(component) => component
+                .to_str()
+                .ok_or_else(|| invalid_data("asset path is not valid UTF-8")),
+            _ => Err(invalid_data("asset path is not a relative path")),
+        })
+        .collect::<io::Result<Vec<_>>>()?;
+    Ok(components.join("/"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PackedAssetIo;
+    use crate::AssetIo;
+    use std::path::{Path, PathBuf};
+
+    #[test]
+    fn packed_asset_io() {
+        let asset_dir = tempfile::tempdir().unwrap();
+        std::fs::write(asset_dir.path().join("a.txt"), "a").unwrap();
+        std::fs::create_dir_all(asset_dir.path().join("dir/sub")).unwrap();
+        std::fs::write(asset_dir.path().join("dir/b.txt"), "bb").unwrap();
+        std::fs::write(asset_dir.path().join("dir/sub/c.txt"), "ccc").unwrap();
+
+        let archive_dir = tempfile::tempdir().unwrap();
+        let archive_path = archive_dir.path().join("assets.pak");
+        assert_eq!(
+            3,
+            PackedAssetIo::pack_folder(asset_dir.path(), &archive_path).unwrap()
+        );
+        let asset_io = PackedAssetIo::open(&archive_path).unwrap();
+
+        for (path, contents) in [
+            ("a.txt", "a"),
+            ("dir/b.txt", "bb"),
+            ("dir/sub/c.txt", "ccc"),
+        ] {
+            let bytes =
+                futures_lite::future::block_on(asset_io.load_path(Path::new(path))).unwrap();
+            assert_eq!(contents.as_bytes(), bytes);
+        }
+        assert!(futures_lite::future::block_on(asset_io.load_path(Path::new("d.txt"))).is_err());
+
+        assert!(asset_io.is_file(Path::new("dir/b.txt")));
+        assert!(asset_io.is_dir(Path::new("dir/sub")));
+        let mut children: Vec<_> = asset_io.read_directory(Path::new("dir")).unwrap().collect();
+        children.sort();
+        assert_eq!(
+            vec![PathBuf::from("dir/b.txt"), PathBuf::from("dir/sub")],
+            children
+        );
+
+        std::fs::write(&archive_path, "not an archive").unwrap();
+        assert!(PackedAssetIo::open(&archive_path).is_err());
+    }
+}
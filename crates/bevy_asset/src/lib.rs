@@ -23,9 +23,13 @@ mod filesystem_watcher;
 mod handle;
 mod info;
 mod io;
+mod load_queue;
 mod loader;
+mod meta;
 mod path;
+mod processor;
 mod reflect;
+mod saver;
 
 /// The `bevy_asset` prelude.
 pub mod prelude {
@@ -42,9 +46,13 @@ pub use bevy_utils::BoxedFuture;
 pub use handle::*;
 pub use info::*;
 pub use io::*;
+pub use load_queue::*;
 pub use loader::*;
+pub use meta::*;
 pub use path::*;
+pub use processor::*;
 pub use reflect::*;
+pub use saver::*;
 
 use bevy_app::prelude::*;
 use bevy_ecs::prelude::*;
@@ -70,6 +78,14 @@ pub struct AssetPlugin {
     /// Whether to watch for changes in asset files. Requires the `filesystem_watcher` feature,
     /// and cannot be supported on the wasm32 arch nor android os.
     pub watch_for_changes: bool,
+    /// The folder where the output of [`AssetProcessor`]s is cached, relative to the executable,
+    /// such as `"imported_assets"`.
+    ///
+    /// If `None`, processed assets are not cached, and are processed again every time they are
+    /// loaded. Caching is not supported on the wasm32 arch nor android os.
+    pub processed_asset_folder: Option<String>,
+    /// The limits on the asset loads running at the same time, which are unlimited by default.
+    pub load_limits: AssetLoadLimits,
 }
 
 impl Default for AssetPlugin {
@@ -77,6 +93,8 @@ impl Default for AssetPlugin {
         Self {
             asset_folder: "assets".to_string(),
             watch_for_changes: false,
+            processed_asset_folder: None,
+            load_limits: AssetLoadLimits::default(),
         }
     }
 }
@@ -103,8 +121,19 @@ impl Plugin for AssetPlugin {
         if !app.world.contains_resource::<AssetServer>() {
             let source = self.create_platform_default_asset_io();
             let asset_server = AssetServer::with_boxed_io(source);
+            asset_server.set_load_limits(self.load_limits);
+            #[cfg(all(not(target_arch = "wasm32"), not(target_os = "android")))]
+            if let Some(processed_asset_folder) = &self.processed_asset_folder {
+                asset_server.set_processed_asset_folder(
+                    FileAssetIo::get_base_path().join(processed_asset_folder),
+                );
+            }
             app.insert_resource(asset_server);
         }
+        let asset_server = app.world.resource::<AssetServer>();
+        if asset_server.get_source(EMBEDDED_ASSET_SOURCE).is_none() {
+            asset_server.add_source(EMBEDDED_ASSET_SOURCE, MemoryAssetIo::new());
+        }
 
         app.register_type::<HandleId>();
 
@@ -118,7 +147,9 @@ impl Plugin for AssetPlugin {
                 .after(CoreSet::PostUpdate)
                 .before(CoreSet::Last),
         )
-        .add_system(asset_server::free_unused_assets_system.in_base_set(CoreSet::PreUpdate));
+        .add_event::<AssetLoadFailedEvent>()
+        .add_system(asset_server::free_unused_assets_system.in_base_set(CoreSet::PreUpdate))
+        .add_system(asset_server::send_asset_load_failed_events.in_base_set(AssetSet::AssetEvents));
 
         #[cfg(all(
             feature = "filesystem_watcher",
@@ -0,0 +1,216 @@
+use crate::{AssetPath, AssetPathId, SourcePathId};
+use bevy_utils::HashMap;
+use std::cmp::Reverse;
+
+/// The priority of an asset load.
+///
+/// Queued loads with a higher priority are started first, and loads with the same priority are
+/// started in the order they were queued. The dependencies of an asset are loaded with the
+/// priority of the asset.
+///
+/// See [`AssetServer::load_with_priority`](crate::AssetServer::load_with_priority).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct LoadPriority(pub i32);
+
+impl LoadPriority {
+    /// The priority of content which isn't needed soon, such as distant content.
+    pub const LOW: Self = Self(-100);
+    /// The priority of the assets loaded with [`AssetServer::load`](crate::AssetServer::load).
+    pub const NORMAL: Self = Self(0);
+    /// The priority of content which is needed as soon as possible.
+    pub const HIGH: Self = Self(100);
+}
+
+/// Limits on the asset loads running at the same time.
+///
+/// Loads exceeding the limits are queued until running loads finish. At least one load is
+/// always running, even if its asset source is bigger than `max_bytes_in_flight`.
+///
+/// See [`AssetServer::set_load_limits`](crate::AssetServer::set_load_limits).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AssetLoadLimits {
+    /// The maximum number of loads running at the same time.
+    pub max_concurrent_loads: usize,
+    /// The number of bytes of the asset sources being loaded above which no more loads are
+    /// started.
+    pub max_bytes_in_flight: usize,
+}
+
+impl AssetLoadLimits {
+    /// Limits which start every load as soon as it is queued.
+    pub const UNLIMITED: Self = Self {
+        max_concurrent_loads: usize::MAX,
+        max_bytes_in_flight: usize::MAX,
+    };
+}
+
+impl Default for AssetLoadLimits {
+    fn default() -> Self {
+        Self::UNLIMITED
+    }
+}
+
+/// A load waiting to be started by the asset server.
+pub(crate) struct QueuedLoad {
+    pub(crate) asset_path: AssetPath<'static>,
+    pub(crate) priority: LoadPriority,
+    /// The version of the asset source when the load was queued, which changes if the load is
+    /// cancelled or replaced before it runs.
+    pub(crate) version: usize,
+    order: u64,
+}
+
+/// The loads queued by the asset server, and the loads it is running.
+#[derive(Default)]
+pub(crate) struct LoadQueue {
+    pub(crate) limits: AssetLoadLimits,
+    queued: Vec<QueuedLoad>,
+    next_order: u64,
+    loads_in_flight: usize,
+    pub(crate) bytes_in_flight: usize,
+    /// The priorities of the running loads, inherited by their dependencies.
+    priorities: HashMap<SourcePathId, LoadPriority>,
+}
+
+impl LoadQueue {
+    /// Queues a load, merging it with the queued load of the same asset if there is one.
+    pub(crate) fn push(
+        &mut self,
+        asset_path: AssetPath<'static>,
+        priority: LoadPriority,
+        version: usize,
+    ) {
+        let id = asset_path.get_id();
+        if let Some(load) = self
+            .queued
+            .iter_mut()
+            .find(|load| load.asset_path.get_id() == id)
+        {
+            load.priority = load.priority.max(priority);
+            load.version = version;
+            return;
+        }
+        self.queued.push(QueuedLoad {
+            asset_path,
+            priority,
+            version,
+            order: self.next_order,
+        });
+        self.next_order += 1;
+    }
+
+    /// Returns `true` if the limits allow starting another load.
+    pub(crate) fn can_start(&self) -> bool {
+        self.loads_in_flight == 0
+            || (self.loads_in_flight < self.limits.max_concurrent_loads
+                && self.bytes_in_flight < self.limits.max_bytes_in_flight)
+    }
+
+    /// Removes the queued load with the highest priority, marking it as running.
+    pub(crate) fn start_next(&mut self) -> Option<QueuedLoad> {
+        let (index, _) = self
+            .queued
+            .iter()
+            .enumerate()
+            .max_by_key(|(_, load)| (load.priority, Reverse(load.order)))?;
+        let load = self.queued.remove(index);
+        self.loads_in_flight += 1;
+        self.priorities
+            .insert(load.asset_path.get_id().source_path_id(), load.priority);
+        Some(load)
+    }
+
+    /// Marks a load started with [`LoadQueue::start_next`] as finished.
+    pub(crate) fn finish(&mut self, source_path_id: SourcePathId) {
+        self.loads_in_flight -= 1;
+        self.priorities.remove(&source_path_id);
+    }
+
+    /// Returns the priority of the running load of the provided asset source.
+    pub(crate) fn priority(&self, source_path_id: SourcePathId) -> Option<LoadPriority> {
+        self.priorities.get(&source_path_id).copied()
+    }
+
+    /// Changes the priority of the queued load of an asset, returning `false` if it isn't queued.
+    pub(crate) fn set_priority(&mut self, id: AssetPathId, priority: LoadPriority) -> bool {
+        match self
+            .queued
+            .iter_mut()
+            .find(|load| load.asset_path.get_id() == id)
+        {
+            Some(load) => {
+                load.priority = priority;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Removes the queued loads of the assets of the provided asset source.
+    pub(crate) fn remove(&mut self, source_path_id: SourcePathId) {
+        self.queued
+            .retain(|load| load.asset_path.get_id().source_path_id() != source_path_id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AssetLoadLimits, LoadPriority, LoadQueue};
+    use crate::AssetPath;
+
+    #[test]
+    fn load_queue_order() {
+        let mut queue = LoadQueue::default();
+        queue.push("a".into(), LoadPriority::NORMAL, 0);
+        queue.push("b".into(), LoadPriority::LOW, 0);
+        queue.push("c".into(), LoadPriority::NORMAL, 0);
+        queue.push("d".into(), LoadPriority::LOW, 0);
+        queue.push("b".into(), LoadPriority::HIGH, 0);
+        assert!(queue.set_priority(AssetPath::from("d").get_id(), LoadPriority(1)));
+        queue.remove(AssetPath::from("c").get_id().source_path_id());
+
+        let mut order = Vec::new();
+        while let Some(load) = queue.start_next() {
+            order.push(load.asset_path);
+        }
+        assert_eq!(
+            vec![
+                AssetPath::from("b"),
+                AssetPath::from("d"),
+                AssetPath::from("a")
+            ],
+            order
+        );
+        assert_eq!(
+            Some(LoadPriority::HIGH),
+            queue.priority(AssetPath::from("b").get_id().source_path_id())
+        );
+    }
+
+    #[test]
+    fn load_queue_limits() {
+        let mut queue = LoadQueue {
+            limits: AssetLoadLimits {
+                max_concurrent_loads: 2,
+                max_bytes_in_flight: 100,
+            },
+            ..Default::default()
+        };
+        for path in ["a", "b", "c"] {
+            queue.push(path.into(), LoadPriority::NORMAL, 0);
+        }
+
+        // a single load is always allowed
+        queue.bytes_in_flight = 1000;
+        assert!(queue.can_start());
+        queue.start_next().unwrap();
+        assert!(!queue.can_start());
+        queue.bytes_in_flight = 10;
+        assert!(queue.can_start());
+        queue.start_next().unwrap();
+        assert!(!queue.can_start());
+
+        queue.finish(AssetPath::from("a").get_id().source_path_id());
+        assert!(queue.can_start());
+    }
+}
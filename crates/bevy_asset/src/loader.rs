@@ -1,14 +1,17 @@
 use crate::{
-    path::AssetPath, AssetIo, AssetIoError, AssetMeta, AssetServer, Assets, Handle, HandleId,
-    RefChangeChannel,
+    meta::SettingsOverride,
+    path::{AssetPath, SourcePathId},
+    AssetIo, AssetIoError, AssetMeta, AssetServer, AssetServerError, Assets, Handle, HandleId,
+    LoaderMeta, RefChangeChannel,
 };
 use anyhow::Error;
 use anyhow::Result;
 use bevy_ecs::system::{Res, ResMut};
-use bevy_reflect::{TypeUuid, TypeUuidDynamic};
+use bevy_reflect::{TypePath, TypeUuid, TypeUuidDynamic};
 use bevy_utils::{BoxedFuture, HashMap};
 use crossbeam_channel::{Receiver, Sender};
 use downcast_rs::{impl_downcast, Downcast};
+use serde::de::DeserializeOwned;
 use std::path::Path;
 
 /// A loader for an asset source.
@@ -30,13 +33,14 @@ pub trait AssetLoader: Send + Sync + 'static {
 /// An essential piece of data of an application.
 ///
 /// Assets are the building blocks of games. They can be anything, from images and sounds to scenes
-/// and scripts. In Bevy, an asset is any struct that has an unique type id, as shown below:
+/// and scripts. In Bevy, an asset is any struct that has an unique type id and a stable
+/// [type path](TypePath), as shown below:
 ///
 /// ```rust
-/// use bevy_reflect::TypeUuid;
+/// use bevy_reflect::{TypePath, TypeUuid};
 /// use serde::Deserialize;
 ///
-/// #[derive(Debug, Deserialize, TypeUuid)]
+/// #[derive(Debug, Deserialize, TypePath, TypeUuid)]
 /// #[uuid = "39cadc56-aa9c-4543-8640-a018b74b5052"]
 /// pub struct CustomAsset {
 ///     pub value: i32,
@@ -47,13 +51,13 @@ pub trait AssetLoader: Send + Sync + 'static {
 ///
 /// In order to load assets into your game you must either add them manually to an asset storage
 /// with [`Assets::add`] or load them from the filesystem with [`AssetServer::load`].
-pub trait Asset: TypeUuid + AssetDynamic {}
+pub trait Asset: TypeUuid + TypePath + AssetDynamic {}
 
 /// An untyped version of the [`Asset`] trait.
 pub trait AssetDynamic: Downcast + TypeUuidDynamic + Send + Sync + 'static {}
 impl_downcast!(AssetDynamic);
 
-impl<T> Asset for T where T: TypeUuid + AssetDynamic + TypeUuidDynamic {}
+impl<T> Asset for T where T: TypeUuid + TypePath + AssetDynamic + TypeUuidDynamic {}
 
 impl<T> AssetDynamic for T where T: Send + Sync + 'static + TypeUuidDynamic {}
 
@@ -122,9 +126,18 @@ impl<T: Asset> From<LoadedAsset<T>> for BoxedLoadedAsset {
 pub struct LoadContext<'a> {
     pub(crate) ref_change_channel: &'a RefChangeChannel,
     pub(crate) asset_io: &'a dyn AssetIo,
+    pub(crate) asset_server: &'a AssetServer,
     pub(crate) labeled_assets: HashMap<Option<String>, BoxedLoadedAsset>,
     pub(crate) path: &'a Path,
+    pub(crate) processed_path: &'a Path,
+    pub(crate) source: Option<&'a str>,
     pub(crate) version: usize,
+    pub(crate) meta: Option<&'a LoaderMeta>,
+    pub(crate) settings_override: Option<&'a SettingsOverride>,
+    pub(crate) direct_dependencies: Vec<AssetPath<'static>>,
+    /// The sources being loaded with [`LoadContext::load_direct`], from the asset loaded by the
+    /// asset server to this one.
+    pub(crate) load_chain: Vec<SourcePathId>,
 }
 
 impl<'a> LoadContext<'a> {
@@ -132,14 +145,22 @@ impl<'a> LoadContext<'a> {
         path: &'a Path,
         ref_change_channel: &'a RefChangeChannel,
         asset_io: &'a dyn AssetIo,
+        asset_server: &'a AssetServer,
         version: usize,
     ) -> Self {
         Self {
             ref_change_channel,
             asset_io,
+            asset_server,
             labeled_assets: Default::default(),
             version,
             path,
+            processed_path: path,
+            source: None,
+            meta: None,
+            settings_override: None,
+            direct_dependencies: Vec::new(),
+            load_chain: Vec::new(),
         }
     }
 
@@ -148,6 +169,55 @@ impl<'a> LoadContext<'a> {
         self.path
     }
 
+    /// Gets the path of the bytes passed to the asset loader.
+    ///
+    /// When the source was transformed by an [`AssetProcessor`](crate::AssetProcessor), this is
+    /// the path of the processed asset, whose extension tells the format of the bytes. Otherwise
+    /// it is the same as [`LoadContext::path`].
+    pub fn processed_path(&self) -> &Path {
+        self.processed_path
+    }
+
+    /// Gets the name of the asset source the asset is loaded from, or `None` for the default
+    /// source.
+    pub fn source(&self) -> Option<&str> {
+        self.source
+    }
+
+    /// Gets the asset path of the asset with the specified label in this load context,
+    /// including the asset source.
+    ///
+    /// Use it to get handles to the labeled assets of this load context with
+    /// [`LoadContext::get_handle`].
+    pub fn get_asset_path<'b>(&'b self, label: Option<&'b str>) -> AssetPath<'b> {
+        let asset_path = AssetPath::new_ref(self.path, label);
+        match self.source {
+            Some(source) => asset_path.with_source(source),
+            None => asset_path,
+        }
+    }
+
+    /// Gets the [`LoaderMeta`] read from the `.meta` file of the asset source, if it has one.
+    pub fn meta(&self) -> Option<&LoaderMeta> {
+        self.meta
+    }
+
+    /// Gets the settings of the loader.
+    ///
+    /// The settings are read from the `.meta` file of the asset source, defaulting to
+    /// `S::default()`, and then modified by the function passed to
+    /// [`AssetServer::load_with_settings`] if the asset was loaded with it.
+    pub fn settings<S: DeserializeOwned + Default + 'static>(&self) -> Result<S, Error> {
+        let mut settings = match self.meta {
+            Some(meta) => meta.settings()?,
+            None => S::default(),
+        };
+        if let Some(settings_override) = self.settings_override {
+            settings_override(&mut settings);
+        }
+        Ok(settings)
+    }
+
     /// Returns `true` if the load context contains an asset with the specified label.
     pub fn has_labeled_asset(&self, label: &str) -> bool {
         self.labeled_assets.contains_key(&Some(label.to_string()))
@@ -163,7 +233,7 @@ impl<'a> LoadContext<'a> {
         assert!(!label.is_empty());
         self.labeled_assets
             .insert(Some(label.to_string()), asset.into());
-        self.get_handle(AssetPath::new_ref(self.path(), Some(label)))
+        self.get_handle(self.get_asset_path(Some(label)))
     }
 
     /// Gets a handle to an asset of type `T` from its id.
@@ -177,6 +247,39 @@ impl<'a> LoadContext<'a> {
         self.asset_io.load_path(path.as_ref()).await
     }
 
+    /// Loads the asset at the provided path immediately and returns its value, so that it can be
+    /// used to load the assets of this load context.
+    ///
+    /// The nested asset is loaded by its own asset loader, but isn't added to its asset storage:
+    /// load it with [`AssetServer::load`] or add it as a dependency to get a handle to it. The
+    /// other assets defined by its asset source are discarded. Reloading the nested asset reloads
+    /// the assets of this load context.
+    ///
+    /// Loading an asset source which is already being loaded, such as the source of this load
+    /// context, fails with [`AssetServerError::CyclicLoadDirect`].
+    pub async fn load_direct<'b, T: Asset>(
+        &mut self,
+        path: impl Into<AssetPath<'b>>,
+    ) -> Result<T, AssetServerError> {
+        let asset_path = path.into().to_owned();
+        let source_path_id = asset_path.get_id().source_path_id();
+        if self.load_chain.contains(&source_path_id) {
+            return Err(AssetServerError::CyclicLoadDirect(asset_path));
+        }
+        let mut asset_path_without_label = AssetPath::new(asset_path.path().to_owned(), None);
+        if let Some(source) = asset_path.source() {
+            asset_path_without_label = asset_path_without_label.with_source(source.to_string());
+        }
+        if !self.direct_dependencies.contains(&asset_path_without_label) {
+            self.direct_dependencies.push(asset_path_without_label);
+        }
+        let mut load_chain = self.load_chain.clone();
+        load_chain.push(source_path_id);
+        self.asset_server
+            .load_direct_async(&asset_path, load_chain, &mut self.direct_dependencies)
+            .await
+    }
+
     /// Generates metadata for the assets managed by this load context.
     pub fn get_asset_metas(&self) -> Vec<AssetMeta> {
         let mut asset_metas = Vec::new();
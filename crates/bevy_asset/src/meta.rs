@@ -0,0 +1,152 @@
+use ron::{error::SpannedError, extensions::Extensions, Options};
+use serde::{
+    de::{DeserializeOwned, IgnoredAny},
+    Deserialize,
+};
+use std::{
+    any::Any,
+    ffi::OsString,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+/// The extension of the `.meta` files holding the [`LoaderMeta`] of asset sources.
+pub const META_FILE_EXTENSION: &str = "meta";
+
+/// Loader configuration read from the `.meta` file next to an asset source.
+///
+/// The `.meta` file of `textures/player.png` is `textures/player.png.meta`. It is a
+/// [RON](https://github.com/ron-rs/ron) file which can declare the extension of the
+/// [`AssetLoader`] to use instead of the one of the source, as well as the settings of that
+/// loader, which the loader reads with [`LoadContext::settings`]. Both are optional:
+///
+/// ```ron
+/// (
+///     loader: "png",
+///     settings: (
+///         is_srgb: false,
+///         sampler: Nearest,
+///     ),
+/// )
+/// ```
+///
+/// [`AssetLoader`]: crate::AssetLoader
+/// [`LoadContext::settings`]: crate::LoadContext::settings
+#[derive(Debug, Clone)]
+pub struct LoaderMeta {
+    loader: Option<String>,
+    bytes: Vec<u8>,
+}
+
+#[derive(Deserialize)]
+struct MetaFile<S> {
+    #[serde(default)]
+    loader: Option<String>,
+    #[serde(default)]
+    settings: S,
+}
+
+fn ron_options() -> Options {
+    Options::default().with_default_extension(Extensions::IMPLICIT_SOME)
+}
+
+impl LoaderMeta {
+    /// Parses the contents of a `.meta` file.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, SpannedError> {
+        let meta = ron_options().from_bytes::<MetaFile<Option<IgnoredAny>>>(bytes)?;
+        Ok(Self {
+            loader: meta.loader,
+            bytes: bytes.to_vec(),
+        })
+    }
+
+    /// Returns the path of the `.meta` file of the asset source at `path`.
+    pub fn path_for(path: &Path) -> PathBuf {
+        let mut meta_path = OsString::from(path);
+        meta_path.push(".");
+        meta_path.push(META_FILE_EXTENSION);
+        PathBuf::from(meta_path)
+    }
+
+    /// Returns the extension of the loader to use for the asset source, if it was specified.
+    pub fn loader(&self) -> Option<&str> {
+        self.loader.as_deref()
+    }
+
+    /// Deserializes the loader settings.
+    ///
+    /// If the settings are not specified, returns `S::default()`.
+    pub fn settings<S: DeserializeOwned + Default>(&self) -> Result<S, SpannedError> {
+        ron_options()
+            .from_bytes::<MetaFile<S>>(&self.bytes)
+            .map(|meta| meta.settings)
+    }
+}
+
+/// A function modifying the loader settings of an asset, passed to
+/// [`AssetServer::load_with_settings`](crate::AssetServer::load_with_settings).
+pub(crate) type SettingsOverride = Arc<dyn Fn(&mut dyn Any) + Send + Sync>;
+
+#[cfg(test)]
+mod tests {
+    use super::LoaderMeta;
+    use serde::Deserialize;
+    use std::path::Path;
+
+    #[derive(Deserialize, Debug, PartialEq)]
+    #[serde(default)]
+    struct Settings {
+        filter: Filter,
+        scale: f32,
+    }
+
+    impl Default for Settings {
+        fn default() -> Self {
+            Self {
+                filter: Filter::Linear,
+                scale: 1.0,
+            }
+        }
+    }
+
+    #[derive(Deserialize, Debug, PartialEq)]
+    enum Filter {
+        Linear,
+        Nearest,
+    }
+
+    #[test]
+    fn parse_meta() {
+        let meta = LoaderMeta::from_bytes(
+            br#"(
+                loader: "png",
+                settings: (filter: Nearest),
+            )"#,
+        )
+        .unwrap();
+        assert_eq!(Some("png"), meta.loader());
+        assert_eq!(
+            Settings {
+                filter: Filter::Nearest,
+                scale: 1.0,
+            },
+            meta.settings().unwrap()
+        );
+
+        let meta = LoaderMeta::from_bytes(b"()").unwrap();
+        assert_eq!(None, meta.loader());
+        assert_eq!(Settings::default(), meta.settings().unwrap());
+
+        assert!(LoaderMeta::from_bytes(b"(loader: 42)").is_err());
+        let meta = LoaderMeta::from_bytes(b"(settings: (filter: Cubic))").unwrap();
+        assert!(meta.settings::<Settings>().is_err());
+    }
+
+    #[test]
+    fn meta_path() {
+        assert_eq!(
+            Path::new("textures/player.png.meta"),
+            LoaderMeta::path_for(Path::new("textures/player.png"))
+        );
+    }
+}
@@ -8,8 +8,15 @@ use std::{
 };
 
 /// Represents a path to an asset in the file system.
+///
+/// An asset path is written as `source://path/to/asset.ext#label`, where both the name of the
+/// asset source and the sub-asset label are optional. Paths without a source are read from the
+/// default source of the [`AssetServer`](crate::AssetServer), unless they are overridden by one
+/// of its [override sources](crate::AssetServer::set_override_sources).
 #[derive(Debug, Eq, PartialEq, Hash, Clone, Serialize, Deserialize)]
 pub struct AssetPath<'a> {
+    #[serde(default)]
+    source: Option<Cow<'a, str>>,
     path: Cow<'a, Path>,
     label: Option<Cow<'a, str>>,
 }
@@ -19,6 +26,7 @@ impl<'a> AssetPath<'a> {
     #[inline]
     pub fn new_ref(path: &'a Path, label: Option<&'a str>) -> AssetPath<'a> {
         AssetPath {
+            source: None,
             path: Cow::Borrowed(path),
             label: label.map(Cow::Borrowed),
         }
@@ -28,6 +36,7 @@ impl<'a> AssetPath<'a> {
     #[inline]
     pub fn new(path: PathBuf, label: Option<String>) -> AssetPath<'a> {
         AssetPath {
+            source: None,
             path: Cow::Owned(path),
             label: label.map(Cow::Owned),
         }
@@ -39,6 +48,21 @@ impl<'a> AssetPath<'a> {
         AssetPathId::from(self)
     }
 
+    /// Returns this asset path with the provided asset source.
+    #[inline]
+    pub fn with_source(self, source: impl Into<Cow<'a, str>>) -> AssetPath<'a> {
+        AssetPath {
+            source: Some(source.into()),
+            ..self
+        }
+    }
+
+    /// Gets the name of the asset source, if the path has one.
+    #[inline]
+    pub fn source(&self) -> Option<&str> {
+        self.source.as_deref()
+    }
+
     /// Gets the sub-asset label.
     #[inline]
     pub fn label(&self) -> Option<&str> {
@@ -55,6 +79,10 @@ impl<'a> AssetPath<'a> {
     #[inline]
     pub fn to_owned(&self) -> AssetPath<'static> {
         AssetPath {
+            source: self
+                .source
+                .as_ref()
+                .map(|source| Cow::Owned(source.to_string())),
             path: Cow::Owned(self.path.to_path_buf()),
             label: self
                 .label
@@ -93,6 +121,18 @@ impl<'a> From<&'a Path> for SourcePathId {
     }
 }
 
+impl SourcePathId {
+    fn new(source: Option<&str>, path: &Path) -> Self {
+        let Some(source) = source else {
+            return SourcePathId::from(path);
+        };
+        let mut hasher = get_hasher();
+        source.hash(&mut hasher);
+        path.hash(&mut hasher);
+        SourcePathId(hasher.finish())
+    }
+}
+
 impl From<AssetPathId> for SourcePathId {
     fn from(id: AssetPathId) -> Self {
         id.source_path_id()
@@ -137,7 +177,7 @@ where
     fn from(value: T) -> Self {
         let asset_path: AssetPath = value.into();
         AssetPathId(
-            SourcePathId::from(asset_path.path()),
+            SourcePathId::new(asset_path.source(), asset_path.path()),
             LabelId::from(asset_path.label()),
         )
     }
@@ -146,19 +186,29 @@ where
 impl<'a, 'b> From<&'a AssetPath<'b>> for AssetPathId {
     fn from(asset_path: &'a AssetPath<'b>) -> Self {
         AssetPathId(
-            SourcePathId::from(asset_path.path()),
+            SourcePathId::new(asset_path.source(), asset_path.path()),
             LabelId::from(asset_path.label()),
         )
     }
 }
 
+/// Splits an asset path into its source, path and label.
+fn parse_asset_path(asset_path: &str) -> (Option<&str>, &str, Option<&str>) {
+    let (source, asset_path) = match asset_path.split_once("://") {
+        Some((source, asset_path)) => (Some(source), asset_path),
+        None => (None, asset_path),
+    };
+    let mut parts = asset_path.splitn(2, '#');
+    let path = parts.next().expect("Path must be set.");
+    (source, path, parts.next())
+}
+
 impl<'a> From<&'a str> for AssetPath<'a> {
     fn from(asset_path: &'a str) -> Self {
-        let mut parts = asset_path.splitn(2, '#');
-        let path = Path::new(parts.next().expect("Path must be set."));
-        let label = parts.next();
+        let (source, path, label) = parse_asset_path(asset_path);
         AssetPath {
-            path: Cow::Borrowed(path),
+            source: source.map(Cow::Borrowed),
+            path: Cow::Borrowed(Path::new(path)),
             label: label.map(Cow::Borrowed),
         }
     }
@@ -173,6 +223,7 @@ impl<'a> From<&'a String> for AssetPath<'a> {
 impl<'a> From<&'a Path> for AssetPath<'a> {
     fn from(path: &'a Path) -> Self {
         AssetPath {
+            source: None,
             path: Cow::Borrowed(path),
             label: None,
         }
@@ -182,6 +233,7 @@ impl<'a> From<&'a Path> for AssetPath<'a> {
 impl<'a> From<PathBuf> for AssetPath<'a> {
     fn from(path: PathBuf) -> Self {
         AssetPath {
+            source: None,
             path: Cow::Owned(path),
             label: None,
         }
@@ -190,12 +242,11 @@ impl<'a> From<PathBuf> for AssetPath<'a> {
 
 impl<'a> From<String> for AssetPath<'a> {
     fn from(asset_path: String) -> Self {
-        let mut parts = asset_path.splitn(2, '#');
-        let path = PathBuf::from(parts.next().expect("Path must be set."));
-        let label = parts.next().map(String::from);
+        let (source, path, label) = parse_asset_path(&asset_path);
         AssetPath {
-            path: Cow::Owned(path),
-            label: label.map(Cow::Owned),
+            source: source.map(|source| Cow::Owned(source.to_string())),
+            path: Cow::Owned(PathBuf::from(path)),
+            label: label.map(|label| Cow::Owned(label.to_string())),
         }
     }
 }
@@ -0,0 +1,192 @@
+use crate::{path::get_hasher, AssetIo, AssetIoError};
+use anyhow::Error;
+use bevy_log::warn;
+use bevy_utils::BoxedFuture;
+use std::{
+    ffi::OsString,
+    fs,
+    hash::Hasher,
+    io,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+/// A processor transforming asset sources into a form that is faster to load.
+///
+/// Processors run on the raw bytes of an asset source before they are handed to an
+/// [`AssetLoader`](crate::AssetLoader), and can be used to compress textures, optimize meshes,
+/// or perform any other expensive transformation once instead of on every load.
+///
+/// The output of a processor is cached in the processed asset folder of the [`AssetServer`],
+/// keyed by a hash of the source. An asset is only processed again when its source, its processor
+/// or the [version](AssetProcessor::version) of its processor changes. Loading an asset with a
+/// processor transparently loads its processed form.
+///
+/// [`AssetServer`]: crate::AssetServer
+pub trait AssetProcessor: Send + Sync + 'static {
+    /// Processes the bytes of an asset source, returning the bytes of the processed asset.
+    fn process<'a>(
+        &'a self,
+        bytes: &'a [u8],
+        process_context: &'a mut ProcessContext,
+    ) -> BoxedFuture<'a, Result<Vec<u8>, Error>>;
+
+    /// Returns a list of extensions supported by this asset processor, without the preceding dot.
+    fn extensions(&self) -> &[&str];
+
+    /// Returns the extension appended to the path of processed assets, without the preceding dot.
+    ///
+    /// The asset loader of a processed asset is picked from its extended path, so a processor
+    /// turning `image.png` into a compressed texture can return `"ktx2"` to have `image.png.ktx2`
+    /// loaded by the loader for `ktx2` files. If `None`, the loader of the source is used.
+    fn processed_extension(&self) -> Option<&str> {
+        None
+    }
+
+    /// Returns the version of the processor.
+    ///
+    /// Changing the version invalidates the processed assets cached for this processor.
+    fn version(&self) -> u32 {
+        0
+    }
+}
+
+/// An asynchronous context where an asset source is processed.
+///
+/// The process context is created by the [`AssetServer`](crate::AssetServer) and passed to the
+/// [`AssetProcessor`] matching the extension of the asset's path.
+pub struct ProcessContext<'a> {
+    pub(crate) path: &'a Path,
+    pub(crate) asset_io: &'a dyn AssetIo,
+}
+
+impl<'a> ProcessContext<'a> {
+    /// Gets the source path for this process context.
+    pub fn path(&self) -> &Path {
+        self.path
+    }
+
+    /// Reads the contents of the file at the specified path through the [`AssetIo`] associated
+    /// with this context.
+    pub async fn read_asset_bytes<P: AsRef<Path>>(&self, path: P) -> Result<Vec<u8>, AssetIoError> {
+        self.asset_io.load_path(path.as_ref()).await
+    }
+
+    /// Gets the asset I/O associated with this process context.
+    pub fn asset_io(&self) -> &dyn AssetIo {
+        self.asset_io
+    }
+}
+
+/// An [`AssetProcessor`] added to the [`AssetServer`](crate::AssetServer).
+#[derive(Clone)]
+pub(crate) struct RegisteredProcessor {
+    pub processor: Arc<dyn AssetProcessor>,
+    /// The type name of the processor, hashed with the sources it processes so that replacing
+    /// the processor of an extension invalidates the cached assets.
+    pub type_name: &'static str,
+}
+
+/// The bytes of an asset source after running them through its [`AssetProcessor`].
+pub(crate) struct ProcessedSource {
+    /// The path the asset loader is picked from.
+    pub path: PathBuf,
+    pub bytes: Vec<u8>,
+    /// Whether the processor was run, rather than the bytes being read from the cache.
+    pub processed: bool,
+}
+
+/// Runs the `registered` processor on the source at `path`, reusing the cached output if the
+/// source is unchanged.
+pub(crate) async fn process_source(
+    registered: &RegisteredProcessor,
+    path: &Path,
+    source_bytes: Vec<u8>,
+    asset_io: &dyn AssetIo,
+    cache: Option<&ProcessedAssetCache>,
+) -> Result<ProcessedSource, Error> {
+    let processor = &*registered.processor;
+    let processed_path = match processor.processed_extension() {
+        Some(extension) => {
+            let mut processed_path = OsString::from(path);
+            processed_path.push(".");
+            processed_path.push(extension);
+            PathBuf::from(processed_path)
+        }
+        None => path.to_owned(),
+    };
+
+    let mut hasher = get_hasher();
+    hasher.write(&source_bytes);
+    hasher.write(registered.type_name.as_bytes());
+    hasher.write_u32(processor.version());
+    let hash = hasher.finish();
+
+    if let Some(bytes) = cache.and_then(|cache| cache.read(&processed_path, hash)) {
+        return Ok(ProcessedSource {
+            path: processed_path,
+            bytes,
+            processed: false,
+        });
+    }
+
+    let mut process_context = ProcessContext { path, asset_io };
+    let bytes = processor
+        .process(&source_bytes, &mut process_context)
+        .await?;
+    if let Some(cache) = cache {
+        if let Err(err) = cache.write(&processed_path, hash, &bytes) {
+            warn!(
+                "failed to cache processed asset {}: {}",
+                processed_path.display(),
+                err
+            );
+        }
+    }
+
+    Ok(ProcessedSource {
+        path: processed_path,
+        bytes,
+        processed: true,
+    })
+}
+
+/// A folder on the local filesystem where processed assets are stored.
+///
+/// Each processed asset is stored at its path relative to the folder, next to a `.hash` file
+/// holding the hash of the source it was processed from.
+pub(crate) struct ProcessedAssetCache {
+    pub root_path: PathBuf,
+}
+
+impl ProcessedAssetCache {
+    fn hash_path(&self, path: &Path) -> PathBuf {
+        let mut hash_path = self.root_path.join(path).into_os_string();
+        hash_path.push(".hash");
+        PathBuf::from(hash_path)
+    }
+
+    /// Reads the processed asset at `path`, if it was processed from a source with the given hash.
+    fn read(&self, path: &Path, hash: u64) -> Option<Vec<u8>> {
+        let cached_hash = fs::read_to_string(self.hash_path(path)).ok()?;
+        if u64::from_str_radix(cached_hash.trim(), 16).ok()? != hash {
+            return None;
+        }
+        fs::read(self.root_path.join(path)).ok()
+    }
+
+    fn write(&self, path: &Path, hash: u64, bytes: &[u8]) -> io::Result<()> {
+        let full_path = self.root_path.join(path);
+        if let Some(parent) = full_path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        // The hash is removed first and written last, so that an interrupted write is never
+        // mistaken for a valid processed asset.
+        let hash_path = self.hash_path(path);
+        if hash_path.exists() {
+            fs::remove_file(&hash_path)?;
+        }
+        fs::write(full_path, bytes)?;
+        fs::write(hash_path, format!("{hash:016x}"))
+    }
+}
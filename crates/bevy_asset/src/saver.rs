@@ -0,0 +1,56 @@
+use crate::{Asset, AssetServer, Assets, Handle};
+use anyhow::Error;
+use bevy_ecs::system::Res;
+use bevy_log::warn;
+use bevy_tasks::IoTaskPool;
+
+/// A saver serializing assets of a given type, so they can be written back to an asset source.
+///
+/// Assets are saved with [`AssetServer::save`]. A saver is usually paired with an
+/// [`AssetLoader`](crate::AssetLoader) supporting its [extension](AssetSaver::extension), so that
+/// saved assets can be loaded back.
+pub trait AssetSaver: Send + Sync + 'static {
+    /// The type of the saved assets.
+    type Asset: Asset;
+
+    /// Serializes an asset, returning the bytes to write to its asset source.
+    fn save(&self, asset: &Self::Asset) -> Result<Vec<u8>, Error>;
+
+    /// Returns the extension of the saved assets, without the preceding dot.
+    ///
+    /// It is appended to the paths passed to [`AssetServer::save`] which don't have an extension.
+    fn extension(&self) -> &str;
+}
+
+/// A system saving the assets of type `T` queued with [`AssetServer::save`].
+pub fn save_assets_system<T: Asset>(asset_server: Res<AssetServer>, assets: Res<Assets<T>>) {
+    let saves = asset_server.take_pending_saves::<T>();
+    if saves.is_empty() {
+        return;
+    }
+    let Some(saver) = asset_server.get_saver::<T>() else {
+        return;
+    };
+
+    for (id, path) in saves {
+        let Some(asset) = assets.get(&Handle::weak(id)) else {
+            warn!("failed to save asset {:?}: the asset does not exist", path);
+            continue;
+        };
+        let bytes = match saver.save(asset) {
+            Ok(bytes) => bytes,
+            Err(err) => {
+                warn!("failed to save asset {:?}: {}", path, err);
+                continue;
+            }
+        };
+        let asset_server = asset_server.clone();
+        IoTaskPool::get()
+            .spawn(async move {
+                if let Err(err) = asset_server.write_asset(&path, &bytes).await {
+                    warn!("failed to save asset {:?}: {}", path, err);
+                }
+            })
+            .detach();
+    }
+}
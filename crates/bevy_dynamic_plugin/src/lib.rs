@@ -1,3 +1,5 @@
 mod loader;
+mod reload;
 
 pub use loader::*;
+pub use reload::ReloadablePlugins;
@@ -1,9 +1,16 @@
 use libloading::{Library, Symbol};
-use std::ffi::OsStr;
+use std::{
+    ffi::OsStr,
+    path::{Path, PathBuf},
+};
 use thiserror::Error;
 
 use bevy_app::{App, CreatePlugin, Plugin};
 
+use crate::reload::{
+    add_reloadable_plugin, load_library_copy, next_generation, replace_reloadable_plugin,
+};
+
 /// Errors that can occur when loading a dynamic plugin
 #[derive(Debug, Error)]
 pub enum DynamicPluginLoadError {
@@ -11,6 +18,14 @@ pub enum DynamicPluginLoadError {
     Library(libloading::Error),
     #[error("dynamic library does not contain a valid Bevy dynamic plugin")]
     Plugin(libloading::Error),
+    #[error("cannot copy library for dynamic plugin: {0}")]
+    Copy(std::io::Error),
+    #[error("no library file at {0}")]
+    NotFound(PathBuf),
+    #[error("library path {0} does not name a file")]
+    InvalidPath(PathBuf),
+    #[error("no reloadable dynamic plugin was loaded from {0}")]
+    NotLoaded(PathBuf),
 }
 
 /// Dynamically links a plugin at the given path. The plugin must export a function with the
@@ -37,6 +52,49 @@ pub trait DynamicPluginExt {
     ///
     /// Same as [`dynamically_load_plugin`].
     unsafe fn load_plugin<P: AsRef<OsStr>>(&mut self, path: P) -> &mut Self;
+
+    /// Dynamically links and builds a plugin like [`load_plugin`](Self::load_plugin), recording
+    /// the systems, schedules and reflected types it adds so that it can later be
+    /// [reloaded](Self::reload_plugin).
+    ///
+    /// The library is loaded from a copy, so the file at `path` can be rebuilt while the app runs.
+    /// Loaded plugins are listed in the [`ReloadablePlugins`](crate::ReloadablePlugins) resource.
+    ///
+    /// # Safety
+    ///
+    /// Same as [`dynamically_load_plugin`].
+    unsafe fn load_reloadable_plugin<P: AsRef<Path>>(
+        &mut self,
+        path: P,
+    ) -> Result<&mut Self, DynamicPluginLoadError>;
+
+    /// Replaces a plugin loaded with [`load_reloadable_plugin`](Self::load_reloadable_plugin) by
+    /// the current version of the library at `path`.
+    ///
+    /// This removes the systems the old plugin added from their [`Schedule`]s, removes the
+    /// schedules it created, and unregisters the types it registered. The resources and
+    /// components of those types are saved through reflection and removed from the world; after
+    /// the new plugin is built, the saved values are applied to the types it registered with the
+    /// same names.
+    ///
+    /// Startup systems of the new plugin won't run if the app has already started. The old
+    /// library stays loaded, since values created by its code may still be alive.
+    ///
+    /// This must not be called while a schedule is running, for example from the app's runner
+    /// between two calls to [`App::update`].
+    ///
+    /// # Safety
+    ///
+    /// Same as [`dynamically_load_plugin`]. In addition, the resource and component types of the
+    /// plugin must keep the same memory layout across reloads, because the [`World`] keeps
+    /// the layout it first saw for every type. Types whose fields change must be renamed.
+    ///
+    /// [`Schedule`]: bevy_ecs::schedule::Schedule
+    /// [`World`]: bevy_ecs::world::World
+    unsafe fn reload_plugin<P: AsRef<Path>>(
+        &mut self,
+        path: P,
+    ) -> Result<&mut Self, DynamicPluginLoadError>;
 }
 
 impl DynamicPluginExt for App {
@@ -46,4 +104,24 @@ impl DynamicPluginExt for App {
         plugin.build(self);
         self
     }
+
+    unsafe fn load_reloadable_plugin<P: AsRef<Path>>(
+        &mut self,
+        path: P,
+    ) -> Result<&mut Self, DynamicPluginLoadError> {
+        let path = path.as_ref();
+        let plugin = load_library_copy(path, 0)?;
+        add_reloadable_plugin(self, path.to_path_buf(), &*plugin);
+        Ok(self)
+    }
+
+    unsafe fn reload_plugin<P: AsRef<Path>>(
+        &mut self,
+        path: P,
+    ) -> Result<&mut Self, DynamicPluginLoadError> {
+        let path = path.as_ref();
+        let plugin = load_library_copy(path, next_generation(self, path)?)?;
+        replace_reloadable_plugin(self, path, &*plugin)?;
+        Ok(self)
+    }
 }
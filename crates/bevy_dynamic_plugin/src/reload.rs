@@ -0,0 +1,376 @@
+use std::{
+    any::TypeId,
+    path::{Path, PathBuf},
+};
+
+use bevy_app::{App, AppTypeRegistry, Plugin};
+use bevy_ecs::{
+    prelude::{Entity, Resource},
+    reflect::{ReflectComponent, ReflectResource},
+    schedule::{BoxedScheduleLabel, NodeId, Schedules},
+    world::{Mut, World},
+};
+use bevy_reflect::Reflect;
+use bevy_utils::{tracing::warn, HashMap, HashSet};
+
+use crate::{dynamically_load_plugin, DynamicPluginLoadError};
+
+/// Keeps track of the plugins loaded with
+/// [`load_reloadable_plugin`](crate::DynamicPluginExt::load_reloadable_plugin), by the path of
+/// their library.
+#[derive(Resource, Default)]
+pub struct ReloadablePlugins {
+    plugins: HashMap<PathBuf, ReloadablePlugin>,
+}
+
+impl ReloadablePlugins {
+    /// Returns `true` if a reloadable plugin was loaded from `path`.
+    pub fn contains(&self, path: impl AsRef<Path>) -> bool {
+        self.plugins.contains_key(path.as_ref())
+    }
+
+    /// Returns the number of times the plugin loaded from `path` has been reloaded.
+    pub fn generation(&self, path: impl AsRef<Path>) -> Option<u32> {
+        self.plugins
+            .get(path.as_ref())
+            .map(|plugin| plugin.generation)
+    }
+
+    /// Returns the library paths of all reloadable plugins.
+    pub fn paths(&self) -> impl Iterator<Item = &Path> {
+        self.plugins.keys().map(PathBuf::as_path)
+    }
+}
+
+struct ReloadablePlugin {
+    name: String,
+    generation: u32,
+    footprint: PluginFootprint,
+}
+
+/// Everything a plugin added to the [`App`] while it was built.
+#[derive(Default)]
+struct PluginFootprint {
+    /// Systems added to schedules that existed before the plugin was built.
+    systems: Vec<(BoxedScheduleLabel, NodeId)>,
+    /// Schedules created by the plugin.
+    schedules: Vec<BoxedScheduleLabel>,
+    /// Types registered by the plugin.
+    types: Vec<TypeId>,
+}
+
+/// The reflected resources and components of a plugin, saved while it is reloaded.
+#[derive(Default)]
+struct PluginSnapshot {
+    resources: Vec<Box<dyn Reflect>>,
+    components: Vec<(Entity, Box<dyn Reflect>)>,
+}
+
+/// Copies the library to a unique path, so that it can be loaded again after being rebuilt.
+///
+/// Most platforms won't load a library a second time from the same path, and some lock the files
+/// of loaded libraries.
+fn copy_library(path: &Path, generation: u32) -> Result<PathBuf, DynamicPluginLoadError> {
+    let file_name = path
+        .file_name()
+        .ok_or_else(|| DynamicPluginLoadError::InvalidPath(path.to_path_buf()))?;
+    let mut copy_name = format!("bevy-{}-{}-", std::process::id(), generation);
+    copy_name.push_str(&file_name.to_string_lossy());
+    let copy = std::env::temp_dir().join(copy_name);
+    std::fs::copy(path, &copy).map_err(|error| match error.kind() {
+        std::io::ErrorKind::NotFound => DynamicPluginLoadError::NotFound(path.to_path_buf()),
+        _ => DynamicPluginLoadError::Copy(error),
+    })?;
+    Ok(copy)
+}
+
+/// # Safety
+///
+/// Same as [`dynamically_load_plugin`].
+pub(crate) unsafe fn load_library_copy(
+    path: &Path,
+    generation: u32,
+) -> Result<Box<dyn Plugin>, DynamicPluginLoadError> {
+    let copy = copy_library(path, generation)?;
+    let (lib, plugin) = dynamically_load_plugin(copy)?;
+    // Never unload the library: data and systems created by the plugin can outlive a reload.
+    std::mem::forget(lib);
+    Ok(plugin)
+}
+
+/// Builds `plugin` and records what it added to the app under `path`.
+pub(crate) fn add_reloadable_plugin(app: &mut App, path: PathBuf, plugin: &dyn Plugin) {
+    app.init_resource::<ReloadablePlugins>();
+    let footprint = build_tracked(app, plugin);
+    app.world
+        .resource_mut::<ReloadablePlugins>()
+        .plugins
+        .insert(
+            path,
+            ReloadablePlugin {
+                name: plugin.name().to_string(),
+                generation: 0,
+                footprint,
+            },
+        );
+}
+
+/// Removes what the plugin loaded from `path` added to the app, builds `plugin` in its place,
+/// and restores the reflected state of the old plugin.
+pub(crate) fn replace_reloadable_plugin(
+    app: &mut App,
+    path: &Path,
+    plugin: &dyn Plugin,
+) -> Result<(), DynamicPluginLoadError> {
+    let old = app
+        .world
+        .get_resource_mut::<ReloadablePlugins>()
+        .and_then(|mut plugins| plugins.plugins.remove(path))
+        .ok_or_else(|| DynamicPluginLoadError::NotLoaded(path.to_path_buf()))?;
+
+    let snapshot = unload(&mut app.world, &old.footprint);
+    let footprint = build_tracked(app, plugin);
+    restore(&mut app.world, snapshot);
+
+    bevy_utils::tracing::info!("reloaded plugin {} as {}", old.name, plugin.name());
+    app.world
+        .resource_mut::<ReloadablePlugins>()
+        .plugins
+        .insert(
+            path.to_path_buf(),
+            ReloadablePlugin {
+                name: plugin.name().to_string(),
+                generation: old.generation + 1,
+                footprint,
+            },
+        );
+    Ok(())
+}
+
+pub(crate) fn next_generation(app: &App, path: &Path) -> Result<u32, DynamicPluginLoadError> {
+    app.world
+        .get_resource::<ReloadablePlugins>()
+        .and_then(|plugins| plugins.generation(path))
+        .map(|generation| generation + 1)
+        .ok_or_else(|| DynamicPluginLoadError::NotLoaded(path.to_path_buf()))
+}
+
+fn registered_types(world: &World) -> HashSet<TypeId> {
+    world
+        .resource::<AppTypeRegistry>()
+        .read()
+        .iter()
+        .map(|registration| registration.type_id())
+        .collect()
+}
+
+fn build_tracked(app: &mut App, plugin: &dyn Plugin) -> PluginFootprint {
+    let types_before = registered_types(&app.world);
+    let system_counts: HashMap<BoxedScheduleLabel, usize> = app
+        .world
+        .resource::<Schedules>()
+        .iter()
+        .map(|(label, schedule)| (label.dyn_clone(), schedule.graph().system_count()))
+        .collect();
+
+    plugin.build(app);
+
+    let mut footprint = PluginFootprint::default();
+    for (label, schedule) in app.world.resource::<Schedules>().iter() {
+        match system_counts.get(label) {
+            Some(&count) => footprint.systems.extend(
+                (count..schedule.graph().system_count())
+                    .map(|index| (label.dyn_clone(), NodeId::System(index))),
+            ),
+            None => footprint.schedules.push(label.dyn_clone()),
+        }
+    }
+    footprint.types = registered_types(&app.world)
+        .difference(&types_before)
+        .copied()
+        .collect();
+    footprint
+}
+
+/// Removes everything in `footprint` from the world, returning the reflected state of the
+/// resources and components whose types were registered by the plugin.
+fn unload(world: &mut World, footprint: &PluginFootprint) -> PluginSnapshot {
+    let mut snapshot = PluginSnapshot::default();
+    let registry = world.resource::<AppTypeRegistry>().clone();
+
+    {
+        let registry = registry.read();
+        for registration in footprint
+            .types
+            .iter()
+            .filter_map(|type_id| registry.get(*type_id))
+        {
+            if let Some(reflect_resource) = registration.data::<ReflectResource>() {
+                if let Some(resource) = reflect_resource.reflect(world) {
+                    snapshot.resources.push(resource.clone_value());
+                    reflect_resource.remove(world);
+                }
+            }
+
+            if let Some(reflect_component) = registration.data::<ReflectComponent>() {
+                let components: Vec<_> = world
+                    .iter_entities()
+                    .filter_map(|entity| {
+                        let component = reflect_component.reflect(entity)?;
+                        Some((entity.id(), component.clone_value()))
+                    })
+                    .collect();
+                for (entity, _) in &components {
+                    reflect_component.remove(&mut world.entity_mut(*entity));
+                }
+                snapshot.components.extend(components);
+            }
+        }
+    }
+
+    world.resource_scope(|_, mut schedules: Mut<Schedules>| {
+        for (label, id) in &footprint.systems {
+            if let Some(schedule) = schedules.get_mut(&**label) {
+                schedule.remove_system(*id);
+            }
+        }
+        for label in &footprint.schedules {
+            schedules.remove(&**label);
+        }
+    });
+
+    let mut registry = registry.write();
+    for type_id in &footprint.types {
+        registry.remove(*type_id);
+    }
+
+    snapshot
+}
+
+/// Applies the saved state to the resources and components registered by the new plugin.
+fn restore(world: &mut World, snapshot: PluginSnapshot) {
+    let registry = world.resource::<AppTypeRegistry>().clone();
+    let registry = registry.read();
+
+    for resource in snapshot.resources {
+        match registry
+            .get_with_name(resource.type_name())
+            .and_then(|registration| registration.data::<ReflectResource>())
+        {
+            Some(reflect_resource) => reflect_resource.apply_or_insert(world, &*resource),
+            None => warn!(
+                "resource {} was not restored after reloading its plugin: it is no longer registered with `ReflectResource`",
+                resource.type_name()
+            ),
+        }
+    }
+
+    for (entity, component) in snapshot.components {
+        let Some(reflect_component) = registry
+            .get_with_name(component.type_name())
+            .and_then(|registration| registration.data::<ReflectComponent>())
+        else {
+            warn!(
+                "component {} was not restored after reloading its plugin: it is no longer registered with `ReflectComponent`",
+                component.type_name()
+            );
+            continue;
+        };
+        if let Some(mut entity) = world.get_entity_mut(entity) {
+            reflect_component.apply_or_insert(&mut entity, &*component);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::{Path, PathBuf};
+
+    use bevy_app::{App, Plugin};
+    use bevy_ecs::prelude::*;
+    use bevy_reflect::Reflect;
+
+    use super::{add_reloadable_plugin, replace_reloadable_plugin, ReloadablePlugins};
+
+    #[derive(Resource, Reflect, Default)]
+    #[reflect(Resource)]
+    struct Score(u32);
+
+    #[derive(Component, Reflect, Default)]
+    #[reflect(Component)]
+    struct Health(f32);
+
+    #[derive(Resource, Default)]
+    struct OldSystemRuns(u32);
+
+    struct GameV1;
+    impl Plugin for GameV1 {
+        fn build(&self, app: &mut App) {
+            app.register_type::<Score>()
+                .register_type::<Health>()
+                .init_resource::<Score>()
+                .add_startup_system(|mut commands: Commands| {
+                    commands.spawn(Health(10.0));
+                })
+                .add_system(
+                    |mut score: ResMut<Score>, mut runs: ResMut<OldSystemRuns>| {
+                        score.0 += 1;
+                        runs.0 += 1;
+                    },
+                );
+        }
+    }
+
+    struct GameV2;
+    impl Plugin for GameV2 {
+        fn build(&self, app: &mut App) {
+            app.register_type::<Score>()
+                .register_type::<Health>()
+                .init_resource::<Score>()
+                .add_system(|mut score: ResMut<Score>, mut health: Query<&mut Health>| {
+                    score.0 += 10;
+                    for mut health in &mut health {
+                        health.0 -= 1.0;
+                    }
+                });
+        }
+    }
+
+    #[test]
+    fn reload_replaces_systems_and_preserves_state() {
+        let path = PathBuf::from("libgame.so");
+        let mut app = App::new();
+        app.init_resource::<OldSystemRuns>();
+        add_reloadable_plugin(&mut app, path.clone(), &GameV1);
+
+        app.update();
+        app.update();
+        assert_eq!(app.world.resource::<Score>().0, 2);
+
+        replace_reloadable_plugin(&mut app, &path, &GameV2).unwrap();
+        // the state was restored even though `GameV2` initialized a new `Score`
+        assert_eq!(app.world.resource::<Score>().0, 2);
+
+        app.update();
+        assert_eq!(app.world.resource::<Score>().0, 12);
+        assert_eq!(app.world.resource::<OldSystemRuns>().0, 2);
+
+        let health = app
+            .world
+            .query::<&Health>()
+            .iter(&app.world)
+            .map(|health| health.0)
+            .collect::<Vec<_>>();
+        assert_eq!(health, vec![9.0]);
+        assert_eq!(
+            app.world.resource::<ReloadablePlugins>().generation(&path),
+            Some(1)
+        );
+    }
+
+    #[test]
+    fn reload_of_unknown_plugin_fails() {
+        let mut app = App::new();
+        assert!(replace_reloadable_plugin(&mut app, Path::new("libgame.so"), &GameV2).is_err());
+    }
+}
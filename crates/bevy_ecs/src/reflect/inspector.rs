@@ -0,0 +1,938 @@
+use crate::{
+    entity::Entity,
+    reflect::{ReflectComponent, ReflectResource},
+    world::World,
+};
+use bevy_reflect::{
+    std_traits::ReflectDefault, GetPath, Reflect, ReflectRef, TypeRegistration, TypeRegistry,
+};
+use thiserror::Error;
+
+/// A headless, UI-agnostic entity and resource inspector built on reflection.
+///
+/// The [`Inspector`] turns reflected components and resources into trees of [`InspectorNode`]s
+/// that a frontend can display, and applies edits made by that frontend back to the [`World`].
+/// Every edit is recorded so that it can be undone and redone.
+///
+/// Components are accessed through their [`ReflectComponent`] type data, and resources through
+/// their [`ReflectResource`] type data. Types are identified by their type path, or by their
+/// type name if no type with that path is registered. Fields are addressed with the path
+/// syntax of [`GetPath`], where an empty path refers to the whole value.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::reflect::{Inspector, InspectorTarget};
+/// # use bevy_reflect::{Reflect, TypePath, TypeRegistry};
+/// #[derive(Component, Reflect, Default)]
+/// #[reflect(Component)]
+/// struct Health {
+///     current: f32,
+///     max: f32,
+/// }
+///
+/// let mut registry = TypeRegistry::default();
+/// registry.register::<Health>();
+///
+/// let mut world = World::new();
+/// let entity = world.spawn(Health { current: 50.0, max: 100.0 }).id();
+///
+/// let mut inspector = Inspector::default();
+/// let target = InspectorTarget::Component {
+///     entity,
+///     type_path: Health::type_path().to_string(),
+/// };
+/// inspector
+///     .set_field(&mut world, &registry, &target, "current", &100.0_f32)
+///     .unwrap();
+/// assert_eq!(100.0, world.get::<Health>(entity).unwrap().current);
+///
+/// inspector.undo(&mut world, &registry).unwrap();
+/// assert_eq!(50.0, world.get::<Health>(entity).unwrap().current);
+/// ```
+#[derive(Debug, Default)]
+pub struct Inspector {
+    undo_stack: Vec<InspectorEdit>,
+    redo_stack: Vec<InspectorEdit>,
+}
+
+/// A reflected value that can be inspected and edited by an [`Inspector`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum InspectorTarget {
+    /// A component on an entity.
+    Component {
+        /// The entity the component belongs to.
+        entity: Entity,
+        /// The type path of the component.
+        type_path: String,
+    },
+    /// A resource.
+    Resource {
+        /// The type path of the resource.
+        type_path: String,
+    },
+}
+
+/// A node in the tree of fields produced by an [`Inspector`].
+#[derive(Debug)]
+pub struct InspectorNode {
+    /// The field name, index, or map key of this node within its parent.
+    ///
+    /// This is empty for the root node.
+    pub label: String,
+    /// The path of this node within the inspected value, if it can be edited.
+    ///
+    /// This is `None` for map entries and set members, which can't be addressed mutably.
+    pub path: Option<String>,
+    /// The type name of this node's value.
+    pub type_name: String,
+    /// What kind of value this node represents.
+    pub kind: InspectorNodeKind,
+    /// The fields or elements of this node's value.
+    pub children: Vec<InspectorNode>,
+}
+
+/// The kind of value represented by an [`InspectorNode`].
+#[derive(Debug)]
+pub enum InspectorNodeKind {
+    /// A struct, whose children are its fields.
+    Struct,
+    /// A tuple struct, whose children are its fields.
+    TupleStruct,
+    /// A tuple, whose children are its fields.
+    Tuple,
+    /// A list, whose children are its elements.
+    List,
+    /// An array, whose children are its elements.
+    Array,
+    /// A map, whose children are its values labeled by their keys.
+    Map,
+    /// A set, whose children are its values.
+    Set,
+    /// An enum, whose children are the fields of the current variant.
+    Enum {
+        /// The name of the current variant.
+        variant: String,
+    },
+    /// An opaque value, such as a primitive.
+    Value(Box<dyn Reflect>),
+}
+
+/// The components of an entity, as listed by [`Inspector::inspect_entity`].
+#[derive(Debug)]
+pub struct EntityInspection {
+    /// The inspected entity.
+    pub entity: Entity,
+    /// The components of the entity, sorted by name.
+    pub components: Vec<ComponentInspection>,
+}
+
+/// A component of an entity, as listed by [`Inspector::inspect_entity`].
+#[derive(Debug)]
+pub struct ComponentInspection {
+    /// The name of the component, as given by its [`ComponentInfo`](crate::component::ComponentInfo).
+    pub name: String,
+    /// The type path of the component, if it is registered with [`ReflectComponent`].
+    pub type_path: Option<String>,
+    /// The tree of fields of the component, if it is registered with [`ReflectComponent`].
+    pub root: Option<InspectorNode>,
+}
+
+/// An edit made through an [`Inspector`], which can be undone and redone.
+#[derive(Debug)]
+pub enum InspectorEdit {
+    /// A field of a component or resource was set.
+    SetField {
+        /// The edited component or resource.
+        target: InspectorTarget,
+        /// The path of the edited field.
+        path: String,
+        /// The value of the field before the edit.
+        old: Box<dyn Reflect>,
+        /// The value of the field after the edit.
+        new: Box<dyn Reflect>,
+    },
+    /// A component was added to an entity.
+    AddComponent {
+        /// The entity the component was added to.
+        entity: Entity,
+        /// The type path of the component.
+        type_path: String,
+        /// The added component.
+        value: Box<dyn Reflect>,
+    },
+    /// A component was removed from an entity.
+    RemoveComponent {
+        /// The entity the component was removed from.
+        entity: Entity,
+        /// The type path of the component.
+        type_path: String,
+        /// The removed component.
+        value: Box<dyn Reflect>,
+    },
+}
+
+/// An error returned by an [`Inspector`].
+#[derive(Debug, Error)]
+pub enum InspectorError {
+    /// The entity does not exist.
+    #[error("entity {0:?} does not exist")]
+    NoSuchEntity(Entity),
+    /// No type with the given type path or type name is registered.
+    #[error("`{0}` is not registered")]
+    UnregisteredType(String),
+    /// The type is not registered with the type data needed for the operation.
+    #[error("`{type_path}` is not registered with `{type_data}`")]
+    MissingTypeData {
+        type_path: String,
+        type_data: &'static str,
+    },
+    /// The entity does not have the component.
+    #[error("entity {entity:?} does not have the component `{type_path}`")]
+    MissingComponent { entity: Entity, type_path: String },
+    /// The entity already has the component being added.
+    #[error("entity {entity:?} already has the component `{type_path}`")]
+    DuplicateComponent { entity: Entity, type_path: String },
+    /// The resource does not exist in the world.
+    #[error("the resource `{0}` does not exist")]
+    MissingResource(String),
+    /// The field path could not be parsed or does not exist in the value.
+    #[error("invalid path `{path}`: {error}")]
+    InvalidPath { path: String, error: String },
+    /// The new value of a field does not have the type of the field.
+    #[error("expected a value of type `{expected}` at `{path}`, but found `{found}`")]
+    TypeMismatch {
+        path: String,
+        expected: String,
+        found: String,
+    },
+}
+
+impl Inspector {
+    /// Lists the components of `entity`, along with the tree of fields of every component
+    /// registered with [`ReflectComponent`].
+    pub fn inspect_entity(
+        &self,
+        world: &World,
+        registry: &TypeRegistry,
+        entity: Entity,
+    ) -> Result<EntityInspection, InspectorError> {
+        let entity_ref = world
+            .get_entity(entity)
+            .ok_or(InspectorError::NoSuchEntity(entity))?;
+
+        let mut components = entity_ref
+            .archetype()
+            .components()
+            .filter_map(|component_id| world.components().get_info(component_id))
+            .map(|info| {
+                let reflected = info
+                    .type_id()
+                    .and_then(|type_id| registry.get(type_id))
+                    .and_then(|registration| {
+                        let reflect_component = registration.data::<ReflectComponent>()?;
+                        Some((registration, reflect_component.reflect(entity_ref)?))
+                    });
+                let (type_path, root) = match reflected {
+                    Some((registration, component)) => (
+                        Some(registration.type_path().to_string()),
+                        Some(InspectorNode::new(
+                            String::new(),
+                            Some(String::new()),
+                            component,
+                        )),
+                    ),
+                    None => (None, None),
+                };
+                ComponentInspection {
+                    name: info.name().to_string(),
+                    type_path,
+                    root,
+                }
+            })
+            .collect::<Vec<_>>();
+        components.sort_by(|a, b| a.name.cmp(&b.name));
+
+        Ok(EntityInspection { entity, components })
+    }
+
+    /// Returns the type paths of all resources in `world` that are registered with
+    /// [`ReflectResource`], in sorted order.
+    pub fn resources(&self, world: &World, registry: &TypeRegistry) -> Vec<&'static str> {
+        let mut resources = registry
+            .iter()
+            .filter(|registration| {
+                registration
+                    .data::<ReflectResource>()
+                    .and_then(|reflect_resource| reflect_resource.reflect(world))
+                    .is_some()
+            })
+            .map(TypeRegistration::type_path)
+            .collect::<Vec<_>>();
+        resources.sort_unstable();
+        resources
+    }
+
+    /// Returns the tree of fields of a component or resource.
+    pub fn inspect(
+        &self,
+        world: &World,
+        registry: &TypeRegistry,
+        target: &InspectorTarget,
+    ) -> Result<InspectorNode, InspectorError> {
+        let value = match target {
+            InspectorTarget::Component { entity, type_path } => {
+                let reflect_component = component_data(registry, type_path)?;
+                let entity_ref = world
+                    .get_entity(*entity)
+                    .ok_or(InspectorError::NoSuchEntity(*entity))?;
+                reflect_component.reflect(entity_ref).ok_or_else(|| {
+                    InspectorError::MissingComponent {
+                        entity: *entity,
+                        type_path: type_path.clone(),
+                    }
+                })?
+            }
+            InspectorTarget::Resource { type_path } => resource_data(registry, type_path)?
+                .reflect(world)
+                .ok_or_else(|| InspectorError::MissingResource(type_path.clone()))?,
+        };
+        Ok(InspectorNode::new(
+            String::new(),
+            Some(String::new()),
+            value,
+        ))
+    }
+
+    /// Sets the field at `path` of a component or resource to `value`, and records the edit.
+    ///
+    /// The type of `value` must match the type of the field.
+    pub fn set_field(
+        &mut self,
+        world: &mut World,
+        registry: &TypeRegistry,
+        target: &InspectorTarget,
+        path: &str,
+        value: &dyn Reflect,
+    ) -> Result<(), InspectorError> {
+        let old = write_field(world, registry, target, path, value)?;
+        self.record(InspectorEdit::SetField {
+            target: target.clone(),
+            path: path.to_string(),
+            old,
+            new: value.clone_value(),
+        });
+        Ok(())
+    }
+
+    /// Adds the default value of a component to `entity`, and records the edit.
+    ///
+    /// The component must be registered with both [`ReflectComponent`] and [`ReflectDefault`].
+    pub fn add_component(
+        &mut self,
+        world: &mut World,
+        registry: &TypeRegistry,
+        entity: Entity,
+        type_path: &str,
+    ) -> Result<(), InspectorError> {
+        let registration = registration(registry, type_path)?;
+        let Some(reflect_default) = registration.data::<ReflectDefault>() else {
+            return Err(InspectorError::MissingTypeData {
+                type_path: type_path.to_string(),
+                type_data: "ReflectDefault",
+            });
+        };
+        let value = reflect_default.default();
+        insert_component(world, registry, entity, type_path, &*value)?;
+        self.record(InspectorEdit::AddComponent {
+            entity,
+            type_path: type_path.to_string(),
+            value,
+        });
+        Ok(())
+    }
+
+    /// Removes a component from `entity`, and records the edit.
+    pub fn remove_component(
+        &mut self,
+        world: &mut World,
+        registry: &TypeRegistry,
+        entity: Entity,
+        type_path: &str,
+    ) -> Result<(), InspectorError> {
+        let value = take_component(world, registry, entity, type_path)?;
+        self.record(InspectorEdit::RemoveComponent {
+            entity,
+            type_path: type_path.to_string(),
+            value,
+        });
+        Ok(())
+    }
+
+    /// Reverts the most recent edit, returning `false` if there was nothing to undo.
+    ///
+    /// If the edit can't be reverted, for example because its entity has been despawned,
+    /// it is discarded and the error is returned.
+    pub fn undo(
+        &mut self,
+        world: &mut World,
+        registry: &TypeRegistry,
+    ) -> Result<bool, InspectorError> {
+        let Some(edit) = self.undo_stack.pop() else {
+            return Ok(false);
+        };
+        edit.revert(world, registry)?;
+        self.redo_stack.push(edit);
+        Ok(true)
+    }
+
+    /// Reapplies the most recently undone edit, returning `false` if there was nothing to redo.
+    ///
+    /// If the edit can't be reapplied, it is discarded and the error is returned.
+    pub fn redo(
+        &mut self,
+        world: &mut World,
+        registry: &TypeRegistry,
+    ) -> Result<bool, InspectorError> {
+        let Some(edit) = self.redo_stack.pop() else {
+            return Ok(false);
+        };
+        edit.apply(world, registry)?;
+        self.undo_stack.push(edit);
+        Ok(true)
+    }
+
+    /// Returns the edits that can be undone, from oldest to newest.
+    pub fn history(&self) -> &[InspectorEdit] {
+        &self.undo_stack
+    }
+
+    /// Returns true if there is an edit to undo.
+    pub fn can_undo(&self) -> bool {
+        !self.undo_stack.is_empty()
+    }
+
+    /// Returns true if there is an undone edit to redo.
+    pub fn can_redo(&self) -> bool {
+        !self.redo_stack.is_empty()
+    }
+
+    /// Forgets all recorded edits.
+    pub fn clear_history(&mut self) {
+        self.undo_stack.clear();
+        self.redo_stack.clear();
+    }
+
+    fn record(&mut self, edit: InspectorEdit) {
+        self.undo_stack.push(edit);
+        self.redo_stack.clear();
+    }
+}
+
+impl InspectorNode {
+    fn new(label: String, path: Option<String>, value: &dyn Reflect) -> Self {
+        let child_path = |suffix: String| path.as_ref().map(|path| format!("{path}{suffix}"));
+
+        let (kind, children) = match value.reflect_ref() {
+            ReflectRef::Struct(value) => (
+                InspectorNodeKind::Struct,
+                (0..value.field_len())
+                    .map(|index| {
+                        let name = value.name_at(index).unwrap();
+                        let field = value.field_at(index).unwrap();
+                        Self::new(name.to_string(), child_path(format!(".{name}")), field)
+                    })
+                    .collect(),
+            ),
+            ReflectRef::TupleStruct(value) => (
+                InspectorNodeKind::TupleStruct,
+                value
+                    .iter_fields()
+                    .enumerate()
+                    .map(|(index, field)| {
+                        Self::new(index.to_string(), child_path(format!(".{index}")), field)
+                    })
+                    .collect(),
+            ),
+            ReflectRef::Tuple(value) => (
+                InspectorNodeKind::Tuple,
+                value
+                    .iter_fields()
+                    .enumerate()
+                    .map(|(index, field)| {
+                        Self::new(index.to_string(), child_path(format!(".{index}")), field)
+                    })
+                    .collect(),
+            ),
+            ReflectRef::List(value) => (
+                InspectorNodeKind::List,
+                value
+                    .iter()
+                    .enumerate()
+                    .map(|(index, item)| {
+                        Self::new(index.to_string(), child_path(format!("[{index}]")), item)
+                    })
+                    .collect(),
+            ),
+            ReflectRef::Array(value) => (
+                InspectorNodeKind::Array,
+                value
+                    .iter()
+                    .enumerate()
+                    .map(|(index, item)| {
+                        Self::new(index.to_string(), child_path(format!("[{index}]")), item)
+                    })
+                    .collect(),
+            ),
+            ReflectRef::Map(value) => (
+                InspectorNodeKind::Map,
+                value
+                    .iter()
+                    .map(|(key, item)| Self::new(format!("{key:?}"), None, item))
+                    .collect(),
+            ),
+            ReflectRef::Set(value) => (
+                InspectorNodeKind::Set,
+                value
+                    .iter()
+                    .enumerate()
+                    .map(|(index, item)| Self::new(index.to_string(), None, item))
+                    .collect(),
+            ),
+            ReflectRef::Enum(value) => (
+                InspectorNodeKind::Enum {
+                    variant: value.variant_name().to_string(),
+                },
+                value
+                    .iter_fields()
+                    .enumerate()
+                    .map(|(index, field)| {
+                        let label = field
+                            .name()
+                            .map_or_else(|| index.to_string(), ToString::to_string);
+                        let path = child_path(format!(".{label}"));
+                        Self::new(label, path, field.value())
+                    })
+                    .collect(),
+            ),
+            ReflectRef::Value(value) => (InspectorNodeKind::Value(value.clone_value()), Vec::new()),
+        };
+
+        Self {
+            label,
+            path,
+            type_name: value.type_name().to_string(),
+            kind,
+            children,
+        }
+    }
+}
+
+impl InspectorEdit {
+    fn apply(&self, world: &mut World, registry: &TypeRegistry) -> Result<(), InspectorError> {
+        match self {
+            InspectorEdit::SetField {
+                target, path, new, ..
+            } => write_field(world, registry, target, path, &**new).map(|_| ()),
+            InspectorEdit::AddComponent {
+                entity,
+                type_path,
+                value,
+            } => insert_component(world, registry, *entity, type_path, &**value),
+            InspectorEdit::RemoveComponent {
+                entity, type_path, ..
+            } => take_component(world, registry, *entity, type_path).map(|_| ()),
+        }
+    }
+
+    fn revert(&self, world: &mut World, registry: &TypeRegistry) -> Result<(), InspectorError> {
+        match self {
+            InspectorEdit::SetField {
+                target, path, old, ..
+            } => write_field(world, registry, target, path, &**old).map(|_| ()),
+            InspectorEdit::AddComponent {
+                entity, type_path, ..
+            } => take_component(world, registry, *entity, type_path).map(|_| ()),
+            InspectorEdit::RemoveComponent {
+                entity,
+                type_path,
+                value,
+            } => insert_component(world, registry, *entity, type_path, &**value),
+        }
+    }
+}
+
+fn registration<'a>(
+    registry: &'a TypeRegistry,
+    type_path: &str,
+) -> Result<&'a TypeRegistration, InspectorError> {
+    registry
+        .get_with_type_path(type_path)
+        .or_else(|| registry.get_with_name(type_path))
+        .ok_or_else(|| InspectorError::UnregisteredType(type_path.to_string()))
+}
+
+fn component_data<'a>(
+    registry: &'a TypeRegistry,
+    type_path: &str,
+) -> Result<&'a ReflectComponent, InspectorError> {
+    registration(registry, type_path)?
+        .data::<ReflectComponent>()
+        .ok_or_else(|| InspectorError::MissingTypeData {
+            type_path: type_path.to_string(),
+            type_data: "ReflectComponent",
+        })
+}
+
+fn resource_data<'a>(
+    registry: &'a TypeRegistry,
+    type_path: &str,
+) -> Result<&'a ReflectResource, InspectorError> {
+    registration(registry, type_path)?
+        .data::<ReflectResource>()
+        .ok_or_else(|| InspectorError::MissingTypeData {
+            type_path: type_path.to_string(),
+            type_data: "ReflectResource",
+        })
+}
+
+/// Applies `value` to the field at `path` of `target`, returning the previous value of the field.
+fn write_field(
+    world: &mut World,
+    registry: &TypeRegistry,
+    target: &InspectorTarget,
+    path: &str,
+    value: &dyn Reflect,
+) -> Result<Box<dyn Reflect>, InspectorError> {
+    let write = |root: &mut dyn Reflect| {
+        let field = root
+            .reflect_path_mut(path)
+            .map_err(|error| InspectorError::InvalidPath {
+                path: path.to_string(),
+                error: error.to_string(),
+            })?;
+        if field.type_name() != value.type_name() {
+            return Err(InspectorError::TypeMismatch {
+                path: path.to_string(),
+                expected: field.type_name().to_string(),
+                found: value.type_name().to_string(),
+            });
+        }
+        let old = field.clone_value();
+        field.apply(value);
+        Ok(old)
+    };
+
+    match target {
+        InspectorTarget::Component { entity, type_path } => {
+            let reflect_component = component_data(registry, type_path)?;
+            let mut entity_mut = world
+                .get_entity_mut(*entity)
+                .ok_or(InspectorError::NoSuchEntity(*entity))?;
+            let mut component =
+                reflect_component
+                    .reflect_mut(&mut entity_mut)
+                    .ok_or_else(|| InspectorError::MissingComponent {
+                        entity: *entity,
+                        type_path: type_path.clone(),
+                    })?;
+            write(&mut *component)
+        }
+        InspectorTarget::Resource { type_path } => {
+            let mut resource = resource_data(registry, type_path)?
+                .reflect_mut(world)
+                .ok_or_else(|| InspectorError::MissingResource(type_path.clone()))?;
+            write(&mut *resource)
+        }
+    }
+}
+
+fn insert_component(
+    world: &mut World,
+    registry: &TypeRegistry,
+    entity: Entity,
+    type_path: &str,
+    value: &dyn Reflect,
+) -> Result<(), InspectorError> {
+    let reflect_component = component_data(registry, type_path)?;
+    let entity_ref = world
+        .get_entity(entity)
+        .ok_or(InspectorError::NoSuchEntity(entity))?;
+    if reflect_component.contains(entity_ref) {
+        return Err(InspectorError::DuplicateComponent {
+            entity,
+            type_path: type_path.to_string(),
+        });
+    }
+    reflect_component.insert(&mut world.entity_mut(entity), value);
+    Ok(())
+}
+
+/// Removes a component from `entity`, returning a copy of its value.
+fn take_component(
+    world: &mut World,
+    registry: &TypeRegistry,
+    entity: Entity,
+    type_path: &str,
+) -> Result<Box<dyn Reflect>, InspectorError> {
+    let reflect_component = component_data(registry, type_path)?;
+    let entity_ref = world
+        .get_entity(entity)
+        .ok_or(InspectorError::NoSuchEntity(entity))?;
+    let value = reflect_component
+        .reflect(entity_ref)
+        .ok_or_else(|| InspectorError::MissingComponent {
+            entity,
+            type_path: type_path.to_string(),
+        })?
+        .clone_value();
+    reflect_component.remove(&mut world.entity_mut(entity));
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Inspector, InspectorEdit, InspectorError, InspectorNodeKind, InspectorTarget};
+    use crate as bevy_ecs;
+    use crate::{component::Component, reflect::ReflectComponent, reflect::ReflectResource};
+    use crate::{system::Resource, world::World};
+    use bevy_reflect::{std_traits::ReflectDefault, Reflect, TypePath, TypeRegistry};
+
+    #[derive(Component, Reflect, Default, Debug, PartialEq)]
+    #[reflect(Component, Default)]
+    struct Health {
+        current: f32,
+        max: f32,
+    }
+
+    #[derive(Component, Reflect, Default, Debug, PartialEq)]
+    #[reflect(Component, Default)]
+    struct Inventory(Vec<String>);
+
+    #[derive(Component)]
+    struct Unreflected;
+
+    #[derive(Resource, Reflect, Default)]
+    #[reflect(Resource)]
+    struct Score(u32);
+
+    fn setup() -> (World, TypeRegistry) {
+        let mut registry = TypeRegistry::default();
+        registry.register::<Health>();
+        registry.register::<Inventory>();
+        registry.register::<Score>();
+        (World::new(), registry)
+    }
+
+    fn health_target(entity: bevy_ecs::entity::Entity) -> InspectorTarget {
+        InspectorTarget::Component {
+            entity,
+            type_path: Health::type_path().to_string(),
+        }
+    }
+
+    #[test]
+    fn inspect_entity() {
+        let (mut world, registry) = setup();
+        let entity = world
+            .spawn((
+                Health {
+                    current: 5.0,
+                    max: 10.0,
+                },
+                Inventory(vec!["sword".to_string()]),
+                Unreflected,
+            ))
+            .id();
+
+        let inspection = Inspector::default()
+            .inspect_entity(&world, &registry, entity)
+            .unwrap();
+        assert_eq!(3, inspection.components.len());
+
+        let health = inspection
+            .components
+            .iter()
+            .find(|component| component.type_path.as_deref() == Some(Health::type_path()))
+            .unwrap();
+        let root = health.root.as_ref().unwrap();
+        assert!(matches!(root.kind, InspectorNodeKind::Struct));
+        assert_eq!("max", root.children[1].label);
+        assert_eq!(Some(".max"), root.children[1].path.as_deref());
+        assert_eq!("f32", root.children[1].type_name);
+        let InspectorNodeKind::Value(max) = &root.children[1].kind else {
+            panic!("expected a value node");
+        };
+        assert_eq!(Some(&10.0), max.downcast_ref::<f32>());
+
+        let inventory = inspection
+            .components
+            .iter()
+            .find(|component| component.type_path.as_deref() == Some(Inventory::type_path()))
+            .unwrap();
+        let items = &inventory.root.as_ref().unwrap().children[0];
+        assert!(matches!(items.kind, InspectorNodeKind::List));
+        assert_eq!(Some(".0[0]"), items.children[0].path.as_deref());
+
+        let unreflected = inspection
+            .components
+            .iter()
+            .find(|component| component.type_path.is_none())
+            .unwrap();
+        assert!(unreflected.name.ends_with("Unreflected"));
+        assert!(unreflected.root.is_none());
+    }
+
+    #[test]
+    fn set_field_undo_redo() {
+        let (mut world, registry) = setup();
+        let entity = world.spawn(Health::default()).id();
+        let mut inspector = Inspector::default();
+
+        inspector
+            .set_field(
+                &mut world,
+                &registry,
+                &health_target(entity),
+                "max",
+                &20.0_f32,
+            )
+            .unwrap();
+        inspector
+            .set_field(
+                &mut world,
+                &registry,
+                &health_target(entity),
+                "current",
+                &15.0_f32,
+            )
+            .unwrap();
+        assert_eq!(2, inspector.history().len());
+        assert_eq!(
+            &Health {
+                current: 15.0,
+                max: 20.0
+            },
+            world.get::<Health>(entity).unwrap()
+        );
+
+        assert!(inspector.undo(&mut world, &registry).unwrap());
+        assert_eq!(0.0, world.get::<Health>(entity).unwrap().current);
+        assert!(inspector.undo(&mut world, &registry).unwrap());
+        assert_eq!(&Health::default(), world.get::<Health>(entity).unwrap());
+        assert!(!inspector.undo(&mut world, &registry).unwrap());
+
+        assert!(inspector.redo(&mut world, &registry).unwrap());
+        assert_eq!(20.0, world.get::<Health>(entity).unwrap().max);
+        assert!(inspector.can_redo());
+
+        // A new edit discards the undone edits.
+        inspector
+            .set_field(
+                &mut world,
+                &registry,
+                &health_target(entity),
+                "max",
+                &30.0_f32,
+            )
+            .unwrap();
+        assert!(!inspector.can_redo());
+    }
+
+    #[test]
+    fn set_field_errors() {
+        let (mut world, registry) = setup();
+        let entity = world.spawn(Health::default()).id();
+        let mut inspector = Inspector::default();
+
+        let result =
+            inspector.set_field(&mut world, &registry, &health_target(entity), "max", &1_u32);
+        assert!(matches!(result, Err(InspectorError::TypeMismatch { .. })));
+
+        let result = inspector.set_field(
+            &mut world,
+            &registry,
+            &health_target(entity),
+            "mana",
+            &1.0_f32,
+        );
+        assert!(matches!(result, Err(InspectorError::InvalidPath { .. })));
+
+        let target = InspectorTarget::Component {
+            entity,
+            type_path: Inventory::type_path().to_string(),
+        };
+        let result =
+            inspector.set_field(&mut world, &registry, &target, "0", &Vec::<String>::new());
+        assert!(matches!(
+            result,
+            Err(InspectorError::MissingComponent { .. })
+        ));
+
+        assert!(!inspector.can_undo());
+    }
+
+    #[test]
+    fn add_and_remove_components() {
+        let (mut world, registry) = setup();
+        let entity = world.spawn_empty().id();
+        let mut inspector = Inspector::default();
+
+        inspector
+            .add_component(&mut world, &registry, entity, Inventory::type_path())
+            .unwrap();
+        assert_eq!(
+            &Inventory::default(),
+            world.get::<Inventory>(entity).unwrap()
+        );
+        assert!(matches!(
+            inspector.add_component(&mut world, &registry, entity, Inventory::type_path()),
+            Err(InspectorError::DuplicateComponent { .. })
+        ));
+
+        world
+            .get_mut::<Inventory>(entity)
+            .unwrap()
+            .0
+            .push("shield".to_string());
+        inspector
+            .remove_component(&mut world, &registry, entity, Inventory::type_path())
+            .unwrap();
+        assert!(world.get::<Inventory>(entity).is_none());
+        assert!(matches!(
+            inspector.history().last(),
+            Some(InspectorEdit::RemoveComponent { .. })
+        ));
+
+        inspector.undo(&mut world, &registry).unwrap();
+        assert_eq!(
+            &Inventory(vec!["shield".to_string()]),
+            world.get::<Inventory>(entity).unwrap()
+        );
+        inspector.undo(&mut world, &registry).unwrap();
+        assert!(world.get::<Inventory>(entity).is_none());
+    }
+
+    #[test]
+    fn inspect_and_edit_resource() {
+        let (mut world, registry) = setup();
+        world.init_resource::<Score>();
+        let mut inspector = Inspector::default();
+
+        assert_eq!(
+            vec![Score::type_path()],
+            inspector.resources(&world, &registry)
+        );
+
+        let target = InspectorTarget::Resource {
+            type_path: Score::type_path().to_string(),
+        };
+        inspector
+            .set_field(&mut world, &registry, &target, ".0", &7_u32)
+            .unwrap();
+        assert_eq!(7, world.resource::<Score>().0);
+
+        let root = inspector.inspect(&world, &registry, &target).unwrap();
+        assert!(matches!(root.kind, InspectorNodeKind::TupleStruct));
+        assert_eq!(Some(".0"), root.children[0].path.as_deref());
+    }
+}
@@ -1,9 +1,14 @@
 //! Types that enable reflection support.
 
+mod inspector;
+
+pub use inspector::*;
+
 use crate::{
     change_detection::Mut,
     component::Component,
     entity::{Entity, EntityMap, MapEntities, MapEntitiesError},
+    event::{Event, Events},
     system::Resource,
     world::{
         unsafe_world_cell::{UnsafeEntityCell, UnsafeWorldCell},
@@ -11,8 +16,8 @@ use crate::{
     },
 };
 use bevy_reflect::{
-    impl_from_reflect_value, impl_reflect_value, FromType, Reflect, ReflectDeserialize,
-    ReflectSerialize,
+    impl_from_reflect_value, impl_reflect_value, FromReflect, FromType, Reflect,
+    ReflectDeserialize, ReflectSerialize,
 };
 
 /// A struct used to operate on reflected [`Component`] of a type.
@@ -403,7 +408,50 @@ impl<C: Resource + Reflect + FromWorld> FromType<C> for ReflectResource {
     }
 }
 
-impl_reflect_value!(Entity(Hash, PartialEq, Serialize, Deserialize));
+/// A struct used to send reflected [`Event`]s of a type.
+///
+/// A [`ReflectEvent`] for type `T` can be obtained via
+/// [`bevy_reflect::TypeRegistration::data`].
+/// It is usually created by deriving [`Reflect`] and [`FromReflect`] and adding the
+/// `#[reflect(Event)]` attribute.
+#[derive(Clone)]
+pub struct ReflectEvent {
+    send: fn(&mut World, &dyn Reflect),
+}
+
+impl ReflectEvent {
+    /// Sends a reflected [`Event`] like [`send_event()`](World::send_event).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `event` can't be converted to the event type with [`FromReflect`], or if
+    /// there is no [`Events`] resource for the event type.
+    pub fn send(&self, world: &mut World, event: &dyn Reflect) {
+        (self.send)(world, event);
+    }
+}
+
+impl<E: Event + FromReflect> FromType<E> for ReflectEvent {
+    fn from_type() -> Self {
+        ReflectEvent {
+            send: |world, reflected_event| {
+                let Some(event) = E::from_reflect(reflected_event) else {
+                    panic!(
+                        "`{}` can't be converted to the event type `{}`",
+                        reflected_event.type_name(),
+                        std::any::type_name::<E>()
+                    );
+                };
+                world.resource_mut::<Events<E>>().send(event);
+            },
+        }
+    }
+}
+
+impl_reflect_value!(
+    #[type_path = "bevy_ecs::entity"]
+    Entity(Hash, PartialEq, Serialize, Deserialize)
+);
 impl_from_reflect_value!(Entity);
 
 #[derive(Clone)]
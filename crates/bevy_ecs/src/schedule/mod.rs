@@ -197,6 +197,83 @@ mod tests {
         }
     }
 
+    mod system_removal {
+        use super::*;
+
+        #[test]
+        fn removed_system_does_not_run() {
+            let mut world = World::default();
+            let mut schedule = Schedule::default();
+
+            world.init_resource::<SystemOrder>();
+
+            schedule.add_systems(
+                (
+                    make_function_system(0),
+                    make_function_system(1).run_if(|| true),
+                    make_exclusive_system(2),
+                )
+                    .chain(),
+            );
+            schedule.run(&mut world);
+            assert_eq!(world.resource::<SystemOrder>().0, vec![0, 1, 2]);
+
+            assert!(schedule.remove_system(NodeId::System(1)).is_some());
+            assert!(schedule.remove_system(NodeId::System(2)).is_some());
+            assert_eq!(schedule.graph().system_count(), 3);
+
+            schedule.run(&mut world);
+            assert_eq!(world.resource::<SystemOrder>().0, vec![0, 1, 2, 0]);
+        }
+
+        #[test]
+        fn removed_system_keeps_ordering() {
+            let mut world = World::default();
+            let mut schedule = Schedule::default();
+
+            world.init_resource::<SystemOrder>();
+
+            schedule.add_systems(
+                (
+                    make_function_system(0),
+                    make_function_system(1),
+                    make_function_system(2),
+                )
+                    .chain(),
+            );
+            schedule.remove_system(NodeId::System(1));
+            schedule.add_system(make_function_system(3));
+
+            schedule.run(&mut world);
+            let order = &world.resource::<SystemOrder>().0;
+            assert_eq!(order.len(), 3);
+            assert!(order.iter().position(|&i| i == 0) < order.iter().position(|&i| i == 2));
+            assert!(!order.contains(&1));
+        }
+
+        #[test]
+        fn removing_unknown_system_returns_none() {
+            let mut world = World::default();
+            let mut schedule = Schedule::default();
+
+            world.init_resource::<SystemOrder>();
+
+            schedule.add_systems((make_function_system(0), make_function_system(1)).chain());
+            schedule.run(&mut world);
+            assert_eq!(world.resource::<SystemOrder>().0, vec![0, 1]);
+
+            assert!(schedule.remove_system(NodeId::System(2)).is_none());
+            assert!(schedule.remove_system(NodeId::Set(0)).is_none());
+            assert!(schedule.remove_system(NodeId::System(1)).is_some());
+            // removing a system twice doesn't return its placeholder
+            assert!(schedule.remove_system(NodeId::System(1)).is_none());
+
+            // the other systems still run
+            schedule.run(&mut world);
+            assert_eq!(world.resource::<SystemOrder>().0, vec![0, 1, 0]);
+        }
+    }
+
     mod conditions {
         use crate::change_detection::DetectChanges;
 
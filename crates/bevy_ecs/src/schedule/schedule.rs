@@ -19,7 +19,7 @@ use crate::{
     self as bevy_ecs,
     component::{ComponentId, Components},
     schedule::*,
-    system::{BoxedSystem, Resource, System},
+    system::{BoxedSystem, IntoSystem, Resource, System},
     world::World,
 };
 
@@ -235,6 +235,20 @@ impl Schedule {
         Ok(())
     }
 
+    /// Removes the system with the given [`NodeId`] from the schedule, returning it.
+    ///
+    /// The system is replaced by a placeholder that does nothing, so that the ordering
+    /// constraints of the other systems are preserved.
+    /// Returns `None` if `id` does not identify a system of this schedule.
+    pub fn remove_system(&mut self, id: NodeId) -> Option<BoxedSystem> {
+        // the executable schedule is only rebuilt if a system was removed
+        if !self.graph.is_removable_system(id) {
+            return None;
+        }
+        self.graph.reclaim_systems(&mut self.executable);
+        self.graph.remove_system(id)
+    }
+
     /// Returns the [`ScheduleGraph`].
     pub fn graph(&self) -> &ScheduleGraph {
         &self.graph
@@ -388,6 +402,7 @@ pub struct ScheduleGraph {
     ambiguous_with: UnGraphMap<NodeId, ()>,
     ambiguous_with_flattened: UnGraphMap<NodeId, ()>,
     ambiguous_with_all: HashSet<NodeId>,
+    removed_systems: HashSet<NodeId>,
     conflicting_systems: Vec<(NodeId, NodeId, Vec<ComponentId>)>,
     changed: bool,
     settings: ScheduleBuildSettings,
@@ -410,6 +425,7 @@ impl ScheduleGraph {
             ambiguous_with: UnGraphMap::new(),
             ambiguous_with_flattened: UnGraphMap::new(),
             ambiguous_with_all: HashSet::new(),
+            removed_systems: HashSet::new(),
             conflicting_systems: Vec::new(),
             changed: false,
             settings: default(),
@@ -455,6 +471,14 @@ impl ScheduleGraph {
             .unwrap()
     }
 
+    /// Returns the number of systems that were added to this schedule.
+    ///
+    /// Systems are identified by their insertion order, so the systems of this schedule are
+    /// `NodeId::System(0)` to `NodeId::System(system_count() - 1)`.
+    pub fn system_count(&self) -> usize {
+        self.systems.len()
+    }
+
     /// Returns an iterator over all systems in this schedule.
     ///
     /// Note that the [`BaseSetMembership`] will only be initialized after [`ScheduleGraph::build_schedule`] is called.
@@ -1260,16 +1284,8 @@ impl ScheduleGraph {
         })
     }
 
-    fn update_schedule(
-        &mut self,
-        schedule: &mut SystemSchedule,
-        components: &Components,
-    ) -> Result<(), ScheduleBuildError> {
-        if !self.uninit.is_empty() {
-            return Err(ScheduleBuildError::Uninitialized);
-        }
-
-        // move systems out of old schedule
+    /// Moves all systems and conditions out of `schedule` and back into the graph.
+    fn reclaim_systems(&mut self, schedule: &mut SystemSchedule) {
         for ((id, system), conditions) in schedule
             .system_ids
             .drain(..)
@@ -1287,6 +1303,43 @@ impl ScheduleGraph {
         {
             self.system_set_conditions[id.index()] = Some(conditions);
         }
+    }
+
+    /// Returns `true` if `id` identifies a system of this graph which hasn't been removed.
+    fn is_removable_system(&self, id: NodeId) -> bool {
+        id.is_system() && id.index() < self.systems.len() && !self.removed_systems.contains(&id)
+    }
+
+    /// Replaces a system and its conditions by a placeholder that does nothing.
+    ///
+    /// The system must have been [reclaimed](Self::reclaim_systems) from the executable schedule.
+    fn remove_system(&mut self, id: NodeId) -> Option<BoxedSystem> {
+        if !self.is_removable_system(id) {
+            return None;
+        }
+        let system = self.systems[id.index()]
+            .inner
+            .replace(Box::new(IntoSystem::into_system(removed_system)))?;
+        self.system_conditions[id.index()] = Some(Vec::new());
+        self.removed_systems.insert(id);
+
+        // the placeholder has to be initialized like a newly-added system
+        self.uninit.push((id, 0));
+        self.changed = true;
+        Some(system)
+    }
+
+    fn update_schedule(
+        &mut self,
+        schedule: &mut SystemSchedule,
+        components: &Components,
+    ) -> Result<(), ScheduleBuildError> {
+        if !self.uninit.is_empty() {
+            return Err(ScheduleBuildError::Uninitialized);
+        }
+
+        // move systems out of old schedule
+        self.reclaim_systems(schedule);
 
         *schedule = self.build_schedule(components)?;
 
@@ -1318,6 +1371,9 @@ impl ScheduleGraph {
     }
 }
 
+/// The placeholder left in place of systems removed with [`Schedule::remove_system`].
+fn removed_system() {}
+
 // methods for reporting errors
 impl ScheduleGraph {
     fn get_node_name(&self, id: &NodeId) -> String {
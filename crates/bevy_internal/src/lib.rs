@@ -172,3 +172,9 @@ pub mod dynamic_plugin {
     //! Dynamic linking of plugins
     pub use bevy_dynamic_plugin::*;
 }
+
+#[cfg(feature = "bevy_remote")]
+pub mod remote {
+    //! Remote inspection and editing of a running app
+    pub use bevy_remote::*;
+}
@@ -0,0 +1,74 @@
+//! Contains code related to custom attributes for reflected types.
+//!
+//! A custom attribute is any expression prefixed with `@` inside a `#[reflect(...)]`
+//! attribute, such as `#[reflect(@0.0..1.0)]`. These are stored in the type's
+//! `TypeInfo` and can be retrieved by type at runtime.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::parse::ParseStream;
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned;
+use syn::{Attribute, Expr, Meta, MetaList, NestedMeta, Path, Token};
+
+/// A collection of custom attribute expressions.
+#[derive(Default, Clone)]
+pub(crate) struct CustomAttributes {
+    attributes: Vec<Expr>,
+}
+
+impl CustomAttributes {
+    /// Generates a call to `with_custom_attributes` on an info type, if there are any attributes.
+    pub fn to_tokens(&self, bevy_reflect_path: &Path) -> TokenStream {
+        if self.attributes.is_empty() {
+            return TokenStream::new();
+        }
+
+        let attributes = &self.attributes;
+        quote! {
+            .with_custom_attributes(
+                #bevy_reflect_path::CustomAttributes::default()
+                    #(.with_attribute(#attributes))*
+            )
+        }
+    }
+}
+
+/// Parses a `#[reflect(...)]` attribute, collecting any `@` expressions into `custom_attributes`.
+///
+/// The remaining items are returned as a [`Meta`] so that they can be handled
+/// the same way as any other attribute.
+pub(crate) fn parse_reflect_attribute(
+    attr: &Attribute,
+    custom_attributes: &mut CustomAttributes,
+) -> Result<Meta, syn::Error> {
+    if attr.tokens.is_empty() {
+        return attr.parse_meta();
+    }
+
+    let nested = attr.parse_args_with(|input: ParseStream| {
+        let mut nested = Punctuated::<NestedMeta, Token![,]>::new();
+        while !input.is_empty() {
+            if input.peek(Token![@]) {
+                input.parse::<Token![@]>()?;
+                custom_attributes.attributes.push(input.parse()?);
+            } else {
+                nested.push(input.parse()?);
+            }
+
+            if input.is_empty() {
+                break;
+            }
+            input.parse::<Token![,]>()?;
+        }
+        Ok(nested)
+    })?;
+
+    Ok(Meta::List(MetaList {
+        path: attr.path.clone(),
+        paren_token: syn::token::Paren {
+            span: attr.tokens.span(),
+        },
+        nested,
+    }))
+}
@@ -1,6 +1,8 @@
 use crate::container_attributes::ReflectTraits;
+use crate::custom_attributes::{parse_reflect_attribute, CustomAttributes};
 use crate::field_attributes::{parse_field_attrs, ReflectFieldAttr};
 use crate::fq_std::{FQAny, FQDefault, FQSend, FQSync};
+use crate::type_path::TypePathOptions;
 use crate::utility::{members_to_serialization_denylist, WhereClauseOptions};
 use bit_set::BitSet;
 use quote::quote;
@@ -38,8 +40,12 @@ pub(crate) struct ReflectMeta<'a> {
     type_name: &'a Ident,
     /// The generics defined on this type.
     generics: &'a Generics,
+    /// How the `TypePath` of this type is determined.
+    type_path_options: TypePathOptions,
     /// A cached instance of the path to the `bevy_reflect` crate.
     bevy_reflect_path: Path,
+    /// Custom attributes created via `#[reflect(@...)]`.
+    custom_attributes: CustomAttributes,
     /// The documentation for this type, if any
     #[cfg(feature = "documentation")]
     docs: crate::documentation::Documentation,
@@ -130,13 +136,23 @@ enum ReflectMode {
 impl<'a> ReflectDerive<'a> {
     pub fn from_input(input: &'a DeriveInput) -> Result<Self, syn::Error> {
         let mut traits = ReflectTraits::default();
+        let mut type_path_options = TypePathOptions::default();
+        let mut custom_attributes = CustomAttributes::default();
         // Should indicate whether `#[reflect_value]` was used
         let mut reflect_mode = None;
 
         #[cfg(feature = "documentation")]
         let mut doc = crate::documentation::Documentation::default();
 
-        for attribute in input.attrs.iter().filter_map(|attr| attr.parse_meta().ok()) {
+        for attribute in &input.attrs {
+            let attribute = if attribute.path.is_ident(REFLECT_ATTRIBUTE_NAME) {
+                parse_reflect_attribute(attribute, &mut custom_attributes)?
+            } else if let Ok(meta) = attribute.parse_meta() {
+                meta
+            } else {
+                continue;
+            };
+
             match attribute {
                 Meta::List(meta_list) if meta_list.path.is_ident(REFLECT_ATTRIBUTE_NAME) => {
                     if !matches!(reflect_mode, None | Some(ReflectMode::Normal)) {
@@ -178,11 +194,14 @@ impl<'a> ReflectDerive<'a> {
                         doc.push(lit.value());
                     }
                 }
+                Meta::NameValue(_) => type_path_options.parse_meta(&attribute)?,
                 _ => continue,
             }
         }
 
-        let meta = ReflectMeta::new(&input.ident, &input.generics, traits);
+        let meta = ReflectMeta::new(&input.ident, &input.generics, traits)
+            .with_type_path_options(type_path_options)
+            .with_custom_attributes(custom_attributes);
 
         #[cfg(feature = "documentation")]
         let meta = meta.with_docs(doc);
@@ -284,12 +303,30 @@ impl<'a> ReflectMeta<'a> {
             traits,
             type_name,
             generics,
+            type_path_options: TypePathOptions::default(),
             bevy_reflect_path: utility::get_bevy_reflect_path(),
+            custom_attributes: CustomAttributes::default(),
             #[cfg(feature = "documentation")]
             docs: Default::default(),
         }
     }
 
+    /// Sets how the `TypePath` of this type is determined.
+    pub fn with_type_path_options(self, type_path_options: TypePathOptions) -> Self {
+        Self {
+            type_path_options,
+            ..self
+        }
+    }
+
+    /// Sets the custom attributes for this type.
+    pub fn with_custom_attributes(self, custom_attributes: CustomAttributes) -> Self {
+        Self {
+            custom_attributes,
+            ..self
+        }
+    }
+
     /// Sets the documentation for this type.
     #[cfg(feature = "documentation")]
     pub fn with_docs(self, docs: crate::documentation::Documentation) -> Self {
@@ -316,6 +353,21 @@ impl<'a> ReflectMeta<'a> {
         &self.bevy_reflect_path
     }
 
+    /// The custom attributes defined on this type.
+    pub fn custom_attributes(&self) -> &CustomAttributes {
+        &self.custom_attributes
+    }
+
+    /// Returns the `TypePath` impl as a `TokenStream`.
+    pub fn get_type_path_impl(&self) -> proc_macro2::TokenStream {
+        crate::type_path::impl_type_path(
+            self.type_name,
+            self.generics,
+            &self.type_path_options,
+            &self.bevy_reflect_path,
+        )
+    }
+
     /// Returns the `GetTypeRegistration` impl as a `TokenStream`.
     pub fn get_type_registration(
         &self,
@@ -60,19 +60,23 @@ pub(crate) fn impl_enum(reflect_enum: &ReflectEnum) -> TokenStream {
         });
 
     let string_name = enum_name.to_string();
+    let custom_attributes = reflect_enum
+        .meta()
+        .custom_attributes()
+        .to_tokens(bevy_reflect_path);
 
     #[cfg(feature = "documentation")]
     let info_generator = {
         let doc = reflect_enum.meta().doc();
         quote! {
-            #bevy_reflect_path::EnumInfo::new::<Self>(#string_name, &variants).with_docs(#doc)
+            #bevy_reflect_path::EnumInfo::new::<Self>(#string_name, &variants) #custom_attributes .with_docs(#doc)
         }
     };
 
     #[cfg(not(feature = "documentation"))]
     let info_generator = {
         quote! {
-            #bevy_reflect_path::EnumInfo::new::<Self>(#string_name, &variants)
+            #bevy_reflect_path::EnumInfo::new::<Self>(#string_name, &variants) #custom_attributes
         }
     };
 
@@ -96,9 +100,13 @@ pub(crate) fn impl_enum(reflect_enum: &ReflectEnum) -> TokenStream {
 
     let where_reflect_clause = extend_where_clause(where_clause, &where_clause_options);
 
+    let type_path_impl = reflect_enum.meta().get_type_path_impl();
+
     TokenStream::from(quote! {
         #get_type_registration_impl
 
+        #type_path_impl
+
         #typed_impl
 
         impl #impl_generics #bevy_reflect_path::Enum for #enum_name #ty_generics #where_reflect_clause {
@@ -357,29 +365,33 @@ fn generate_impls(reflect_enum: &ReflectEnum, ref_index: &Ident, ref_name: &Iden
             constructor_argument
         }
 
-        let mut push_variant =
-            |_variant: &EnumVariant, arguments: proc_macro2::TokenStream, field_len: usize| {
-                #[cfg(feature = "documentation")]
-                let with_docs = {
-                    let doc = quote::ToTokens::to_token_stream(&_variant.doc);
-                    Some(quote!(.with_docs(#doc)))
-                };
-                #[cfg(not(feature = "documentation"))]
-                let with_docs: Option<proc_macro2::TokenStream> = None;
-
-                variant_info.push(quote! {
-                    #bevy_reflect_path::VariantInfo::#variant_type_ident(
-                        #bevy_reflect_path::#variant_info_ident::new(#arguments)
-                        #with_docs
-                    )
-                });
-                enum_field_len.push(quote! {
-                    #unit{..} => #field_len
-                });
-                enum_variant_type.push(quote! {
-                    #unit{..} => #bevy_reflect_path::VariantType::#variant_type_ident
-                });
+        let mut push_variant = |variant: &EnumVariant,
+                                arguments: proc_macro2::TokenStream,
+                                field_len: usize| {
+            let custom_attributes = variant.attrs.custom_attributes.to_tokens(bevy_reflect_path);
+
+            #[cfg(feature = "documentation")]
+            let with_docs = {
+                let doc = quote::ToTokens::to_token_stream(&variant.doc);
+                Some(quote!(.with_docs(#doc)))
             };
+            #[cfg(not(feature = "documentation"))]
+            let with_docs: Option<proc_macro2::TokenStream> = None;
+
+            variant_info.push(quote! {
+                #bevy_reflect_path::VariantInfo::#variant_type_ident(
+                    #bevy_reflect_path::#variant_info_ident::new(#arguments)
+                    #custom_attributes
+                    #with_docs
+                )
+            });
+            enum_field_len.push(quote! {
+                #unit{..} => #field_len
+            });
+            enum_variant_type.push(quote! {
+                #unit{..} => #bevy_reflect_path::VariantType::#variant_type_ident
+            });
+        };
 
         match &variant.fields {
             EnumVariantFields::Unit => {
@@ -401,8 +413,11 @@ fn generate_impls(reflect_enum: &ReflectEnum, ref_index: &Ident, ref_name: &Iden
                     let with_docs: Option<proc_macro2::TokenStream> = None;
 
                     let field_ty = &field.data.ty;
+                    let custom_attributes =
+                        field.attrs.custom_attributes.to_tokens(bevy_reflect_path);
                     quote! {
                         #bevy_reflect_path::UnnamedField::new::<#field_ty>(#reflect_idx)
+                        #custom_attributes
                         #with_docs
                     }
                 });
@@ -436,8 +451,11 @@ fn generate_impls(reflect_enum: &ReflectEnum, ref_index: &Ident, ref_name: &Iden
                     let with_docs: Option<proc_macro2::TokenStream> = None;
 
                     let field_ty = &field.data.ty;
+                    let custom_attributes =
+                        field.attrs.custom_attributes.to_tokens(bevy_reflect_path);
                     quote! {
                         #bevy_reflect_path::NamedField::new::<#field_ty>(#field_name)
+                        #custom_attributes
                         #with_docs
                     }
                 });
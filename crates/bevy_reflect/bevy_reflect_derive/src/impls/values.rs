@@ -37,9 +37,13 @@ pub(crate) fn impl_value(meta: &ReflectMeta) -> TokenStream {
     let (impl_generics, ty_generics, where_clause) = meta.generics().split_for_impl();
     let get_type_registration_impl = meta.get_type_registration(&where_clause_options);
 
+    let type_path_impl = meta.get_type_path_impl();
+
     TokenStream::from(quote! {
         #get_type_registration_impl
 
+        #type_path_impl
+
         #typed_impl
 
         impl #impl_generics #bevy_reflect_path::Reflect for #type_name #ty_generics #where_clause  {
@@ -15,6 +15,7 @@
 extern crate proc_macro;
 
 mod container_attributes;
+mod custom_attributes;
 mod derive_data;
 #[cfg(feature = "documentation")]
 mod documentation;
@@ -26,10 +27,12 @@ mod impls;
 mod reflect_value;
 mod registration;
 mod trait_reflection;
+mod type_path;
 mod type_uuid;
 mod utility;
 
 use crate::derive_data::{ReflectDerive, ReflectMeta, ReflectStruct};
+use crate::type_path::TypePathOptions;
 use crate::type_uuid::gen_impl_type_uuid;
 use proc_macro::TokenStream;
 use quote::quote;
@@ -44,7 +47,7 @@ pub(crate) static REFLECT_VALUE_ATTRIBUTE_NAME: &str = "reflect_value";
 /// The main derive macro used by `bevy_reflect` for deriving its `Reflect` trait.
 ///
 /// This macro can be used on all structs and enums (unions are not supported).
-/// It will automatically generate the implementations for `Reflect`, `Typed`, and `GetTypeRegistration`.
+/// It will automatically generate the implementations for `Reflect`, `Typed`, `TypePath`, and `GetTypeRegistration`.
 /// And, depending on the item's structure, will either implement `Struct`, `TupleStruct`, or `Enum`.
 ///
 /// # Container Attributes
@@ -99,6 +102,24 @@ pub(crate) static REFLECT_VALUE_ATTRIBUTE_NAME: &str = "reflect_value";
 /// If planning to serialize this type using the reflection serializers,
 /// then the `Serialize` and `Deserialize` traits will need to be implemented and registered as well.
 ///
+/// ## `#[reflect(@...)]`
+///
+/// Any expression prefixed with `@` is stored as a custom attribute in the type's `TypeInfo`,
+/// where it can be retrieved by type using `get_attribute`.
+/// For example, `#[reflect(@Tooltip("A player"))]` would store a `Tooltip` value.
+/// Custom attributes may also be placed on fields and enum variants.
+///
+/// The expression's type must implement `Reflect`, and only one attribute of each type
+/// is kept.
+///
+/// ## `#[type_path = "..."]` and `#[type_name = "..."]`
+///
+/// These attributes override the module path and the identifier used in the `TypePath`
+/// implementation, which default to the [`module_path`] where the type is defined and the
+/// type's own identifier.
+/// Since type paths are used to identify types in serialized data, this can be used to keep
+/// the path of a type stable when it is moved to another module or renamed.
+///
 /// # Field Attributes
 ///
 /// Along with the container attributes, this macro comes with some attributes that may be applied
@@ -123,7 +144,7 @@ pub(crate) static REFLECT_VALUE_ATTRIBUTE_NAME: &str = "reflect_value";
 /// which will be used by the reflection serializers to determine whether or not the field is serializable.
 ///
 /// [`reflect_trait`]: macro@reflect_trait
-#[proc_macro_derive(Reflect, attributes(reflect, reflect_value))]
+#[proc_macro_derive(Reflect, attributes(reflect, reflect_value, type_path, type_name))]
 pub fn derive_reflect(input: TokenStream) -> TokenStream {
     let ast = parse_macro_input!(input as DeriveInput);
 
@@ -187,6 +208,26 @@ pub fn derive_from_reflect(input: TokenStream) -> TokenStream {
     }
 }
 
+/// Derives the `TypePath` trait on its own, for types that don't implement `Reflect`.
+///
+/// The path of the type can be customized with the `#[type_path = "..."]` and
+/// `#[type_name = "..."]` attributes, in the same way as with [`#[derive(Reflect)]`](Reflect).
+#[proc_macro_derive(TypePath, attributes(type_path, type_name))]
+pub fn derive_type_path(input: TokenStream) -> TokenStream {
+    let ast = parse_macro_input!(input as DeriveInput);
+    let options = match TypePathOptions::from_attributes(&ast.attrs) {
+        Ok(options) => options,
+        Err(err) => return err.into_compile_error().into(),
+    };
+
+    TokenStream::from(type_path::impl_type_path(
+        &ast.ident,
+        &ast.generics,
+        &options,
+        &utility::get_bevy_reflect_path(),
+    ))
+}
+
 // From https://github.com/randomPoison/type-uuid
 #[proc_macro_derive(TypeUuid, attributes(uuid))]
 pub fn derive_type_uuid(input: TokenStream) -> TokenStream {
@@ -204,6 +245,10 @@ pub fn derive_type_uuid(input: TokenStream) -> TokenStream {
 /// The creation and registration of this generated struct as type data can be automatically handled
 /// by [`#[derive(Reflect)]`](Reflect).
 ///
+/// The generated struct also implements `TraitCast`, which lets the `TypeRegistry` cast reflected
+/// values with `cast_ref`, `cast_mut` and `cast_boxed`, and find all the registered implementors
+/// of the trait with `iter_with_data`.
+///
 /// # Example
 ///
 /// ```ignore
@@ -268,15 +313,27 @@ pub fn reflect_trait(args: TokenStream, input: TokenStream) -> TokenStream {
 /// impl_reflect_value!(foo<T1, T2: Baz> where T1: Bar (Default, Serialize, Deserialize));
 /// ```
 ///
+/// Since the macro can't know where the type is defined, the `TypePath` of the type is only its
+/// identifier, unless a module path is given with the `#[type_path = "..."]` attribute:
+///
+/// ```ignore
+/// impl_reflect_value!(#[type_path = "std::path"] PathBuf(Debug, Default));
+/// ```
+///
 /// [deriving `Reflect`]: Reflect
 #[proc_macro]
 pub fn impl_reflect_value(input: TokenStream) -> TokenStream {
     let def = parse_macro_input!(input as ReflectValueDef);
+    let type_path_options = match TypePathOptions::from_attributes(&def.attrs) {
+        Ok(options) => options.foreign(),
+        Err(err) => return err.into_compile_error().into(),
+    };
     let meta = ReflectMeta::new(
         &def.type_name,
         &def.generics,
         def.traits.unwrap_or_default(),
-    );
+    )
+    .with_type_path_options(type_path_options);
 
     #[cfg(feature = "documentation")]
     let meta = meta.with_docs(documentation::Documentation::from_attributes(&def.attrs));
@@ -298,6 +355,9 @@ pub fn impl_reflect_value(input: TokenStream) -> TokenStream {
 /// error message and fail to compile. If the type does not implement `Default`, it may not
 /// be possible to reflect without extending the macro.
 ///
+/// The `#[type_path = "..."]` attribute should be used to give the module path of the type,
+/// as it would otherwise be the path of the module invoking this macro.
+///
 /// # Example
 /// Implementing `Reflect` for `bevy::prelude::Vec3` as a struct type:
 /// ```ignore
@@ -305,6 +365,7 @@ pub fn impl_reflect_value(input: TokenStream) -> TokenStream {
 ///
 /// impl_reflect_struct!(
 ///    #[reflect(PartialEq, Serialize, Deserialize, Default)]
+///    #[type_path = "glam"]
 ///    struct Vec3 {
 ///        x: f32,
 ///        y: f32,
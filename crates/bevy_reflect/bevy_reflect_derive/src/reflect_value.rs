@@ -19,7 +19,6 @@ use syn::{parenthesized, Attribute, Generics};
 /// foo<T1, T2> where T1: Bar (TraitA, TraitB)
 /// ```
 pub(crate) struct ReflectValueDef {
-    #[allow(dead_code)]
     pub attrs: Vec<Attribute>,
     pub type_name: Ident,
     pub generics: Generics,
@@ -25,7 +25,15 @@ pub(crate) fn impl_get_type_registration(
         }
     });
 
-    let where_reflect_clause = extend_where_clause(where_clause, where_clause_options);
+    // The registration stores the type path, so it is always required.
+    let mut where_clause = where_clause
+        .cloned()
+        .unwrap_or_else(|| syn::parse_quote!(where));
+    where_clause
+        .predicates
+        .push(syn::parse_quote!(Self: #bevy_reflect_path::TypePath));
+    where_clause.predicates.push_punct(Default::default());
+    let where_reflect_clause = extend_where_clause(Some(&where_clause), where_clause_options);
 
     quote! {
         #[allow(unused_mut)]
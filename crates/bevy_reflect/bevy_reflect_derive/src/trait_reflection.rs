@@ -25,7 +25,7 @@ impl Parse for TraitInfo {
 /// A trait attribute macro that allows a reflected type to be downcast to a trait object.
 ///
 /// This generates a struct that takes the form `ReflectMyTrait`. An instance of this struct can then be
-/// used to perform the conversion.
+/// used to perform the conversion, either directly or through its `TraitCast` implementation.
 pub(crate) fn reflect_trait(_args: &TokenStream, input: TokenStream) -> TokenStream {
     let trait_info = parse_macro_input!(input as TraitInfo);
     let item_trait = &trait_info.item_trait;
@@ -53,8 +53,8 @@ pub(crate) fn reflect_trait(_args: &TokenStream, input: TokenStream) -> TokenStr
         #[doc = #struct_doc]
         #[derive(#FQClone)]
         #trait_vis struct #reflect_trait_ident {
-            get_func: fn(&dyn #bevy_reflect_path::Reflect) -> #FQOption<&dyn #trait_ident>,
-            get_mut_func: fn(&mut dyn #bevy_reflect_path::Reflect) -> #FQOption<&mut dyn #trait_ident>,
+            get_func: fn(&dyn #bevy_reflect_path::Reflect) -> #FQOption<&(dyn #trait_ident + 'static)>,
+            get_mut_func: fn(&mut dyn #bevy_reflect_path::Reflect) -> #FQOption<&mut (dyn #trait_ident + 'static)>,
             get_boxed_func: fn(#FQBox<dyn #bevy_reflect_path::Reflect>) -> #FQResult<#FQBox<dyn #trait_ident>, #FQBox<dyn #bevy_reflect_path::Reflect>>,
         }
 
@@ -66,7 +66,7 @@ pub(crate) fn reflect_trait(_args: &TokenStream, input: TokenStream) -> TokenStr
 
             #[doc = #get_mut_doc]
             pub fn get_mut<'a>(&self, reflect_value: &'a mut dyn #bevy_reflect_path::Reflect) -> #FQOption<&'a mut dyn #trait_ident> {
-                (self.get_mut_func)(reflect_value)
+                (self.get_mut_func)(reflect_value).map(|value| value as &mut dyn #trait_ident)
             }
 
             #[doc = #get_box_doc]
@@ -75,6 +75,22 @@ pub(crate) fn reflect_trait(_args: &TokenStream, input: TokenStream) -> TokenStr
             }
         }
 
+        impl #bevy_reflect_path::TraitCast for #reflect_trait_ident {
+            type Object = dyn #trait_ident;
+
+            fn cast_ref<'a>(&self, value: &'a dyn #bevy_reflect_path::Reflect) -> #FQOption<&'a Self::Object> {
+                (self.get_func)(value)
+            }
+
+            fn cast_mut<'a>(&self, value: &'a mut dyn #bevy_reflect_path::Reflect) -> #FQOption<&'a mut Self::Object> {
+                (self.get_mut_func)(value)
+            }
+
+            fn cast_boxed(&self, value: #FQBox<dyn #bevy_reflect_path::Reflect>) -> #FQResult<#FQBox<Self::Object>, #FQBox<dyn #bevy_reflect_path::Reflect>> {
+                (self.get_boxed_func)(value)
+            }
+        }
+
         impl<T: #trait_ident + #bevy_reflect_path::Reflect> #bevy_reflect_path::FromType<T> for #reflect_trait_ident {
             fn from_type() -> Self {
                 Self {
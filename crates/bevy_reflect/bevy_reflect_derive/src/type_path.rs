@@ -0,0 +1,196 @@
+//! Contains code related to the `TypePath` trait.
+
+use proc_macro2::Ident;
+use quote::quote;
+use syn::spanned::Spanned;
+use syn::{Attribute, GenericParam, Generics, Lit, LitStr, Meta, Path};
+
+pub(crate) static TYPE_PATH_ATTRIBUTE_NAME: &str = "type_path";
+pub(crate) static TYPE_NAME_ATTRIBUTE_NAME: &str = "type_name";
+
+/// How the path of a type is determined.
+///
+/// ```ignore
+/// #[derive(Reflect)]
+/// //         module_path
+/// //        |------------|
+/// #[type_path = "my_crate::foo"]
+/// #[type_name = "Bar"]
+/// //            |---|
+/// //          type_ident
+/// struct ThingThatImReflecting;
+/// ```
+#[derive(Default)]
+pub(crate) struct TypePathOptions {
+    /// The module path set with `#[type_path = "..."]`.
+    module_path: Option<LitStr>,
+    /// The type identifier set with `#[type_name = "..."]`.
+    type_ident: Option<LitStr>,
+    /// Whether the type is defined in another crate, such as types reflected with
+    /// `impl_reflect_value!`.
+    ///
+    /// Foreign types without a `#[type_path]` are considered primitives, whose path is their
+    /// identifier, since the module they are defined in can't be known.
+    foreign: bool,
+}
+
+impl TypePathOptions {
+    /// Parses the `#[type_path = "..."]` and `#[type_name = "..."]` attributes.
+    pub fn from_attributes(attrs: &[Attribute]) -> Result<Self, syn::Error> {
+        let mut options = Self::default();
+        for attribute in attrs.iter().filter_map(|attr| attr.parse_meta().ok()) {
+            options.parse_meta(&attribute)?;
+        }
+        Ok(options)
+    }
+
+    /// Parses a single container attribute, ignoring it if it isn't a type path attribute.
+    pub fn parse_meta(&mut self, meta: &Meta) -> Result<(), syn::Error> {
+        let Meta::NameValue(pair) = meta else {
+            return Ok(());
+        };
+        let target = if pair.path.is_ident(TYPE_PATH_ATTRIBUTE_NAME) {
+            &mut self.module_path
+        } else if pair.path.is_ident(TYPE_NAME_ATTRIBUTE_NAME) {
+            &mut self.type_ident
+        } else {
+            return Ok(());
+        };
+
+        let Lit::Str(lit) = &pair.lit else {
+            return Err(syn::Error::new(
+                pair.lit.span(),
+                "expected a string literal",
+            ));
+        };
+        if target.is_some() {
+            return Err(syn::Error::new(
+                pair.span(),
+                "duplicate type path attribute",
+            ));
+        }
+        *target = Some(lit.clone());
+        Ok(())
+    }
+
+    /// Marks the type as being defined in another crate.
+    pub fn foreign(self) -> Self {
+        Self {
+            foreign: true,
+            ..self
+        }
+    }
+}
+
+/// Creates the `TypePath` impl for the given type.
+pub(crate) fn impl_type_path(
+    type_name: &Ident,
+    generics: &Generics,
+    options: &TypePathOptions,
+    bevy_reflect_path: &Path,
+) -> proc_macro2::TokenStream {
+    let type_ident = match &options.type_ident {
+        Some(ident) => ident.value(),
+        None => type_name.to_string(),
+    };
+
+    let module_path = match (&options.module_path, options.foreign) {
+        (Some(module_path), _) => Some(quote!(#module_path)),
+        (None, false) => Some(quote!(::core::module_path!())),
+        (None, true) => None,
+    };
+
+    let (long_prefix, crate_name, module_path_fn) = match &module_path {
+        Some(module_path) => (
+            quote!(::core::concat!(#module_path, "::", #type_ident)),
+            quote!(::core::primitive::str::split(#module_path, "::").next()),
+            quote!(::core::option::Option::Some(#module_path)),
+        ),
+        None => (
+            quote!(#type_ident),
+            quote!(::core::option::Option::None),
+            quote!(::core::option::Option::None),
+        ),
+    };
+
+    // Type and const parameters are part of the path, lifetimes are not.
+    let params = generics
+        .params
+        .iter()
+        .filter_map(|param| match param {
+            GenericParam::Type(param) => {
+                let ident = &param.ident;
+                Some((
+                    quote!(<#ident as #bevy_reflect_path::TypePath>::type_path()),
+                    quote!(<#ident as #bevy_reflect_path::TypePath>::short_type_path()),
+                ))
+            }
+            GenericParam::Const(param) => {
+                let ident = &param.ident;
+                let value = quote!(&::std::string::ToString::to_string(&#ident));
+                Some((value.clone(), value))
+            }
+            GenericParam::Lifetime(_) => None,
+        })
+        .collect::<Vec<_>>();
+
+    let (type_path, short_type_path) = if params.is_empty() {
+        (long_prefix, quote!(#type_ident))
+    } else {
+        let (long_params, short_params): (Vec<_>, Vec<_>) = params.into_iter().unzip();
+        let cell = quote!(#bevy_reflect_path::utility::GenericTypePathCell);
+        (
+            quote! {
+                static CELL: #cell = #cell::new();
+                CELL.get_or_insert::<Self, _>(|| {
+                    let params: &[&str] = &[#(#long_params),*];
+                    ::std::format!("{}<{}>", #long_prefix, params.join(", "))
+                })
+            },
+            quote! {
+                static CELL: #cell = #cell::new();
+                CELL.get_or_insert::<Self, _>(|| {
+                    let params: &[&str] = &[#(#short_params),*];
+                    ::std::format!("{}<{}>", #type_ident, params.join(", "))
+                })
+            },
+        )
+    };
+
+    let mut generics = generics.clone();
+    let type_params = generics
+        .type_params()
+        .map(|param| param.ident.clone())
+        .collect::<Vec<_>>();
+    let where_clause = generics.make_where_clause();
+    for ident in type_params {
+        where_clause
+            .predicates
+            .push(syn::parse_quote!(#ident: #bevy_reflect_path::TypePath));
+    }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    quote! {
+        impl #impl_generics #bevy_reflect_path::TypePath for #type_name #ty_generics #where_clause {
+            fn type_path() -> &'static str {
+                #type_path
+            }
+
+            fn short_type_path() -> &'static str {
+                #short_type_path
+            }
+
+            fn type_ident() -> ::core::option::Option<&'static str> {
+                ::core::option::Option::Some(#type_ident)
+            }
+
+            fn crate_name() -> ::core::option::Option<&'static str> {
+                #crate_name
+            }
+
+            fn module_path() -> ::core::option::Option<&'static str> {
+                #module_path_fn
+            }
+        }
+    }
+}
@@ -0,0 +1,262 @@
+use crate::Reflect;
+use bevy_utils::HashMap;
+use std::any::TypeId;
+use std::fmt::{Debug, Formatter};
+
+/// A collection of custom attributes attached to a type, field, or variant.
+///
+/// Custom attributes are arbitrary [reflected] values, keyed by their type, which can be
+/// used to attach metadata such as valid ranges or tooltips to a type's [`TypeInfo`].
+/// They are added with the `#[reflect(@...)]` syntax of the `Reflect` derive, which accepts
+/// any expression:
+///
+/// ```
+/// # use bevy_reflect::{Reflect, Typed, TypeInfo};
+/// #[derive(Reflect)]
+/// struct Tooltip(String);
+///
+/// impl Tooltip {
+///     fn new(text: &str) -> Self {
+///         Self(text.to_string())
+///     }
+/// }
+///
+/// #[derive(Reflect)]
+/// struct Slider {
+///     #[reflect(@Tooltip::new("How loud the sound is"), @0.0..1.0_f32)]
+///     volume: f32,
+/// }
+///
+/// let TypeInfo::Struct(info) = Slider::type_info() else { unreachable!() };
+/// let field = info.field("volume").unwrap();
+///
+/// assert_eq!("How loud the sound is", field.get_attribute::<Tooltip>().unwrap().0);
+/// assert_eq!(&(0.0..1.0), field.get_attribute::<std::ops::Range<f32>>().unwrap());
+/// ```
+///
+/// [reflected]: Reflect
+/// [`TypeInfo`]: crate::TypeInfo
+#[derive(Default)]
+pub struct CustomAttributes {
+    attributes: HashMap<TypeId, Box<dyn Reflect>>,
+}
+
+impl CustomAttributes {
+    /// Adds an attribute, replacing any existing attribute of the same type.
+    pub fn with_attribute<T: Reflect>(mut self, value: T) -> Self {
+        self.insert(value);
+        self
+    }
+
+    /// Inserts an attribute, replacing any existing attribute of the same type.
+    pub fn insert<T: Reflect>(&mut self, value: T) {
+        self.attributes.insert(TypeId::of::<T>(), Box::new(value));
+    }
+
+    /// Returns true if an attribute of type `T` is present.
+    pub fn contains<T: Reflect>(&self) -> bool {
+        self.contains_by_id(TypeId::of::<T>())
+    }
+
+    /// Returns true if an attribute with the given [`TypeId`] is present.
+    pub fn contains_by_id(&self, type_id: TypeId) -> bool {
+        self.attributes.contains_key(&type_id)
+    }
+
+    /// Returns the attribute of type `T`, if present.
+    pub fn get<T: Reflect>(&self) -> Option<&T> {
+        self.get_by_id(TypeId::of::<T>())?.downcast_ref()
+    }
+
+    /// Returns the attribute with the given [`TypeId`], if present.
+    pub fn get_by_id(&self, type_id: TypeId) -> Option<&dyn Reflect> {
+        self.attributes.get(&type_id).map(|value| value.as_ref())
+    }
+
+    /// Returns an iterator over all attributes, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (TypeId, &dyn Reflect)> {
+        self.attributes
+            .iter()
+            .map(|(type_id, value)| (*type_id, value.as_ref()))
+    }
+
+    /// Returns the number of attributes.
+    pub fn len(&self) -> usize {
+        self.attributes.len()
+    }
+
+    /// Returns true if there are no attributes.
+    pub fn is_empty(&self) -> bool {
+        self.attributes.is_empty()
+    }
+}
+
+impl Debug for CustomAttributes {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_set().entries(self.attributes.values()).finish()
+    }
+}
+
+/// Implements the builder and accessor methods for custom attributes on an info type.
+///
+/// The type must have a `custom_attributes: Arc<CustomAttributes>` field.
+macro_rules! impl_custom_attribute_methods {
+    ($name:literal) => {
+        #[doc = concat!("Sets the custom attributes of this ", $name, ".")]
+        pub fn with_custom_attributes(self, custom_attributes: $crate::CustomAttributes) -> Self {
+            Self {
+                custom_attributes: ::std::sync::Arc::new(custom_attributes),
+                ..self
+            }
+        }
+
+        #[doc = concat!("The custom attributes of this ", $name, ".")]
+        pub fn custom_attributes(&self) -> &$crate::CustomAttributes {
+            &self.custom_attributes
+        }
+
+        #[doc = concat!("Returns the custom attribute of type `T` on this ", $name, ", if any.")]
+        pub fn get_attribute<T: $crate::Reflect>(&self) -> Option<&T> {
+            self.custom_attributes.get::<T>()
+        }
+
+        #[doc = concat!("Returns true if this ", $name, " has a custom attribute of type `T`.")]
+        pub fn has_attribute<T: $crate::Reflect>(&self) -> bool {
+            self.custom_attributes.contains::<T>()
+        }
+    };
+}
+
+pub(crate) use impl_custom_attribute_methods;
+
+#[cfg(test)]
+mod tests {
+    use crate as bevy_reflect;
+    use crate::{CustomAttributes, Reflect, TypeInfo, Typed, VariantInfo};
+    use std::ops::Range;
+
+    #[derive(Reflect, Debug, PartialEq)]
+    struct Tooltip(String);
+
+    impl Tooltip {
+        fn new(text: &str) -> Self {
+            Self(text.to_string())
+        }
+    }
+
+    #[derive(Reflect, Debug, PartialEq)]
+    struct SkipInInspector;
+
+    #[test]
+    fn should_get_custom_attributes() {
+        let attributes = CustomAttributes::default()
+            .with_attribute(Tooltip::new("A tooltip"))
+            .with_attribute(0.0..1.0_f32);
+
+        assert_eq!(2, attributes.len());
+        assert_eq!(
+            Some(&Tooltip::new("A tooltip")),
+            attributes.get::<Tooltip>()
+        );
+        assert_eq!(Some(&(0.0..1.0)), attributes.get::<Range<f32>>());
+        assert!(attributes.contains::<Range<f32>>());
+        assert!(!attributes.contains::<SkipInInspector>());
+        assert!(attributes.get::<Range<f64>>().is_none());
+    }
+
+    #[test]
+    fn should_derive_custom_attributes_on_struct() {
+        #[derive(Reflect)]
+        #[reflect(@Tooltip::new("A player"), Debug)]
+        #[derive(Debug)]
+        struct Player {
+            #[reflect(@Tooltip::new("The player's health"), @0.0..100.0_f32)]
+            health: f32,
+            #[reflect(@SkipInInspector)]
+            id: u64,
+            name: String,
+        }
+
+        let TypeInfo::Struct(info) = Player::type_info() else {
+            panic!("expected struct info");
+        };
+        assert_eq!(
+            Some(&Tooltip::new("A player")),
+            info.get_attribute::<Tooltip>()
+        );
+
+        let health = info.field("health").unwrap();
+        assert_eq!(
+            Some(&Tooltip::new("The player's health")),
+            health.get_attribute::<Tooltip>()
+        );
+        assert_eq!(Some(&(0.0..100.0)), health.get_attribute::<Range<f32>>());
+
+        assert!(info.field("id").unwrap().has_attribute::<SkipInInspector>());
+        assert!(info.field("name").unwrap().custom_attributes().is_empty());
+    }
+
+    #[test]
+    fn should_derive_custom_attributes_on_tuple_struct() {
+        #[derive(Reflect)]
+        #[reflect(@SkipInInspector)]
+        struct Volume(#[reflect(@0.0..1.0_f32)] f32);
+
+        let TypeInfo::TupleStruct(info) = Volume::type_info() else {
+            panic!("expected tuple struct info");
+        };
+        assert!(info.has_attribute::<SkipInInspector>());
+        assert_eq!(
+            Some(&(0.0..1.0)),
+            info.field_at(0).unwrap().get_attribute::<Range<f32>>()
+        );
+    }
+
+    #[test]
+    fn should_derive_custom_attributes_on_enum() {
+        #[derive(Reflect)]
+        #[reflect(@Tooltip::new("A shape"))]
+        enum Shape {
+            #[reflect(@Tooltip::new("Nothing"))]
+            Empty,
+            Circle(#[reflect(@0.0..10.0_f32)] f32),
+            Rect {
+                #[reflect(@Tooltip::new("Width"))]
+                width: f32,
+                height: f32,
+            },
+        }
+
+        let TypeInfo::Enum(info) = Shape::type_info() else {
+            panic!("expected enum info");
+        };
+        assert_eq!(
+            Some(&Tooltip::new("A shape")),
+            info.get_attribute::<Tooltip>()
+        );
+
+        let empty = info.variant("Empty").unwrap();
+        assert_eq!(
+            Some(&Tooltip::new("Nothing")),
+            empty.custom_attributes().get::<Tooltip>()
+        );
+
+        let VariantInfo::Tuple(circle) = info.variant("Circle").unwrap() else {
+            panic!("expected tuple variant");
+        };
+        assert!(circle.custom_attributes().is_empty());
+        assert_eq!(
+            Some(&(0.0..10.0)),
+            circle.field_at(0).unwrap().get_attribute::<Range<f32>>()
+        );
+
+        let VariantInfo::Struct(rect) = info.variant("Rect").unwrap() else {
+            panic!("expected struct variant");
+        };
+        assert_eq!(
+            Some(&Tooltip::new("Width")),
+            rect.field("width").unwrap().get_attribute::<Tooltip>()
+        );
+        assert!(rect.field("height").unwrap().custom_attributes().is_empty());
+    }
+}
@@ -0,0 +1,735 @@
+use crate::{
+    Array, Enum, List, Map, ParsedPath, Reflect, ReflectMut, ReflectRef, Set, Struct, Tuple,
+    TupleStruct, VariantType,
+};
+use thiserror::Error;
+
+/// An error returned when a [`ReflectPatch`] can't be applied.
+#[derive(Debug, PartialEq, Eq, Error)]
+pub enum ReflectPatchError {
+    #[error("the path `{path}` could not be resolved: {error}")]
+    InvalidPath { path: String, error: String },
+    #[error("expected a list at `{path}`, but found a different reflect value")]
+    ExpectedList { path: String },
+    #[error("expected a map at `{path}`, but found a different reflect value")]
+    ExpectedMap { path: String },
+    #[error("expected a set at `{path}`, but found a different reflect value")]
+    ExpectedSet { path: String },
+}
+
+/// A single change of a [`ReflectPatch`].
+///
+/// Each operation stores both the previous and the new values it affects,
+/// so that it can be [inverted](PatchOp::inverse).
+/// Paths use the syntax of [`GetPath`](crate::GetPath), and can be parsed as a [`ParsedPath`].
+/// The empty path refers to the patched value itself.
+#[derive(Debug)]
+pub enum PatchOp {
+    /// The value at `path` changed from `old` to `new`.
+    ///
+    /// This is used for changed [value types](crate::ReflectRef::Value), enums whose variant
+    /// changed, and values whose structure changed entirely.
+    Replace {
+        path: String,
+        old: Box<dyn Reflect>,
+        new: Box<dyn Reflect>,
+    },
+    /// `value` was pushed to the end of the [`List`] at `path`.
+    ListPush {
+        path: String,
+        value: Box<dyn Reflect>,
+    },
+    /// `value` was removed from the end of the [`List`] at `path`.
+    ListPop {
+        path: String,
+        value: Box<dyn Reflect>,
+    },
+    /// The entry of the [`Map`] at `path` with the given `key` changed from `old` to `new`.
+    ///
+    /// `old` is `None` if the entry was inserted, and `new` is `None` if it was removed.
+    MapEntry {
+        path: String,
+        key: Box<dyn Reflect>,
+        old: Option<Box<dyn Reflect>>,
+        new: Option<Box<dyn Reflect>>,
+    },
+    /// `value` was inserted into the [`Set`] at `path`.
+    SetInsert {
+        path: String,
+        value: Box<dyn Reflect>,
+    },
+    /// `value` was removed from the [`Set`] at `path`.
+    SetRemove {
+        path: String,
+        value: Box<dyn Reflect>,
+    },
+}
+
+impl PatchOp {
+    /// Returns the path of the value this operation changes.
+    pub fn path(&self) -> &str {
+        match self {
+            PatchOp::Replace { path, .. }
+            | PatchOp::ListPush { path, .. }
+            | PatchOp::ListPop { path, .. }
+            | PatchOp::MapEntry { path, .. }
+            | PatchOp::SetInsert { path, .. }
+            | PatchOp::SetRemove { path, .. } => path,
+        }
+    }
+
+    /// Returns the operation undoing this one.
+    pub fn inverse(&self) -> PatchOp {
+        match self {
+            PatchOp::Replace { path, old, new } => PatchOp::Replace {
+                path: path.clone(),
+                old: new.clone_value(),
+                new: old.clone_value(),
+            },
+            PatchOp::ListPush { path, value } => PatchOp::ListPop {
+                path: path.clone(),
+                value: value.clone_value(),
+            },
+            PatchOp::ListPop { path, value } => PatchOp::ListPush {
+                path: path.clone(),
+                value: value.clone_value(),
+            },
+            PatchOp::MapEntry {
+                path,
+                key,
+                old,
+                new,
+            } => PatchOp::MapEntry {
+                path: path.clone(),
+                key: key.clone_value(),
+                old: new.as_ref().map(|value| value.clone_value()),
+                new: old.as_ref().map(|value| value.clone_value()),
+            },
+            PatchOp::SetInsert { path, value } => PatchOp::SetRemove {
+                path: path.clone(),
+                value: value.clone_value(),
+            },
+            PatchOp::SetRemove { path, value } => PatchOp::SetInsert {
+                path: path.clone(),
+                value: value.clone_value(),
+            },
+        }
+    }
+
+    /// Applies this operation to `target`.
+    ///
+    /// # Panics
+    ///
+    /// Panics in the same cases as [`Reflect::apply`], if a new value can't be applied to the
+    /// value it replaces.
+    pub fn apply(&self, target: &mut dyn Reflect) -> Result<(), ReflectPatchError> {
+        let element = resolve(target, self.path())?;
+        match self {
+            PatchOp::Replace { new, .. } => element.apply(&**new),
+            PatchOp::ListPush { path, value } => {
+                list_mut(element, path)?.push(value.clone_value());
+            }
+            PatchOp::ListPop { path, .. } => {
+                list_mut(element, path)?.pop();
+            }
+            PatchOp::MapEntry { path, key, new, .. } => {
+                let ReflectMut::Map(map) = element.reflect_mut() else {
+                    return Err(ReflectPatchError::ExpectedMap { path: path.clone() });
+                };
+                match new {
+                    Some(value) => {
+                        map.insert_boxed(key.clone_value(), value.clone_value());
+                    }
+                    None => {
+                        map.remove(&**key);
+                    }
+                }
+            }
+            PatchOp::SetInsert { path, value } => {
+                set_mut(element, path)?.insert_boxed(value.clone_value());
+            }
+            PatchOp::SetRemove { path, value } => {
+                set_mut(element, path)?.remove(&**value);
+            }
+        }
+        Ok(())
+    }
+}
+
+fn resolve<'r>(
+    target: &'r mut dyn Reflect,
+    path: &str,
+) -> Result<&'r mut dyn Reflect, ReflectPatchError> {
+    let invalid_path = |error: &dyn std::error::Error| ReflectPatchError::InvalidPath {
+        path: path.to_string(),
+        error: error.to_string(),
+    };
+    let mut parsed = ParsedPath::parse(path).map_err(|error| invalid_path(&error))?;
+    let element = parsed
+        .reflect_element_mut(target)
+        .map_err(|error| invalid_path(&error))?;
+    Ok(element)
+}
+
+fn list_mut<'r>(
+    element: &'r mut dyn Reflect,
+    path: &str,
+) -> Result<&'r mut dyn List, ReflectPatchError> {
+    match element.reflect_mut() {
+        ReflectMut::List(list) => Ok(list),
+        _ => Err(ReflectPatchError::ExpectedList {
+            path: path.to_string(),
+        }),
+    }
+}
+
+fn set_mut<'r>(
+    element: &'r mut dyn Reflect,
+    path: &str,
+) -> Result<&'r mut dyn Set, ReflectPatchError> {
+    match element.reflect_mut() {
+        ReflectMut::Set(set) => Ok(set),
+        _ => Err(ReflectPatchError::ExpectedSet {
+            path: path.to_string(),
+        }),
+    }
+}
+
+/// The differences between two reflected values, as computed by [`diff`].
+///
+/// Applying the patch to a value equal to the first one makes it equal to the second one,
+/// and applying its [inverse](ReflectPatch::inverse) goes back.
+/// This can be used to implement undo and redo, or to send changes over the network.
+/// Patches can be serialized with [`ReflectPatchSerializer`](crate::serde::ReflectPatchSerializer).
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::{diff, Reflect};
+/// #[derive(Reflect, Debug, PartialEq)]
+/// struct Player {
+///   name: String,
+///   health: f32,
+///   inventory: Vec<u32>,
+/// }
+///
+/// let before = Player { name: "Alice".into(), health: 10.0, inventory: vec![1] };
+/// let after = Player { name: "Alice".into(), health: 5.0, inventory: vec![1, 2] };
+///
+/// let patch = diff(&before, &after);
+/// assert_eq!(patch.len(), 2);
+/// assert_eq!(patch.ops()[0].path(), ".health");
+///
+/// let mut value = Player { name: "Alice".into(), health: 10.0, inventory: vec![1] };
+/// patch.apply(&mut value).unwrap();
+/// assert_eq!(value, after);
+///
+/// patch.inverse().apply(&mut value).unwrap();
+/// assert_eq!(value, before);
+/// ```
+#[derive(Debug, Default)]
+pub struct ReflectPatch {
+    ops: Vec<PatchOp>,
+}
+
+impl ReflectPatch {
+    /// Creates a patch from a list of operations, applied in order.
+    pub fn from_ops(ops: Vec<PatchOp>) -> Self {
+        Self { ops }
+    }
+
+    /// Returns the operations of this patch.
+    pub fn ops(&self) -> &[PatchOp] {
+        &self.ops
+    }
+
+    /// Returns the operations of this patch.
+    pub fn into_ops(self) -> Vec<PatchOp> {
+        self.ops
+    }
+
+    /// Returns the number of operations in this patch.
+    pub fn len(&self) -> usize {
+        self.ops.len()
+    }
+
+    /// Returns `true` if the patch doesn't change anything.
+    pub fn is_empty(&self) -> bool {
+        self.ops.is_empty()
+    }
+
+    /// Applies all the operations of this patch to `target`.
+    ///
+    /// If an operation fails, the operations before it will have been applied.
+    ///
+    /// # Panics
+    ///
+    /// See [`PatchOp::apply`].
+    pub fn apply(&self, target: &mut dyn Reflect) -> Result<(), ReflectPatchError> {
+        self.ops.iter().try_for_each(|op| op.apply(target))
+    }
+
+    /// Returns the patch undoing this one.
+    pub fn inverse(&self) -> ReflectPatch {
+        Self {
+            ops: self.ops.iter().rev().map(PatchOp::inverse).collect(),
+        }
+    }
+}
+
+/// Computes the [`ReflectPatch`] turning `old` into `new`.
+///
+/// Nested [structs](Struct), [tuple structs](TupleStruct), [tuples](Tuple), [arrays](Array),
+/// [lists](List), [maps](Map), [sets](Set) and [enums](Enum) are compared field by field,
+/// so that the patch only contains the values that changed.
+/// Values that can't be compared field by field, such as enums whose variant changed or
+/// [value types](crate::ReflectRef::Value), are replaced entirely when they aren't equal
+/// according to [`Reflect::reflect_partial_eq`].
+pub fn diff(old: &dyn Reflect, new: &dyn Reflect) -> ReflectPatch {
+    let mut ops = Vec::new();
+    diff_at(&mut String::new(), old, new, &mut ops);
+    ReflectPatch { ops }
+}
+
+fn diff_at(path: &mut String, old: &dyn Reflect, new: &dyn Reflect, ops: &mut Vec<PatchOp>) {
+    let diffed = old.type_name() == new.type_name()
+        && match (old.reflect_ref(), new.reflect_ref()) {
+            (ReflectRef::Struct(old), ReflectRef::Struct(new)) => diff_struct(path, old, new, ops),
+            (ReflectRef::TupleStruct(old), ReflectRef::TupleStruct(new)) => {
+                diff_tuple_struct(path, old, new, ops)
+            }
+            (ReflectRef::Tuple(old), ReflectRef::Tuple(new)) => diff_tuple(path, old, new, ops),
+            (ReflectRef::Array(old), ReflectRef::Array(new)) => diff_array(path, old, new, ops),
+            (ReflectRef::List(old), ReflectRef::List(new)) => diff_list(path, old, new, ops),
+            (ReflectRef::Map(old), ReflectRef::Map(new)) => {
+                diff_map(path, old, new, ops);
+                true
+            }
+            (ReflectRef::Set(old), ReflectRef::Set(new)) => {
+                diff_set(path, old, new, ops);
+                true
+            }
+            (ReflectRef::Enum(old), ReflectRef::Enum(new)) => diff_enum(path, old, new, ops),
+            (ReflectRef::Value(old), ReflectRef::Value(new)) => {
+                if old.reflect_partial_eq(new) != Some(true) {
+                    ops.push(PatchOp::Replace {
+                        path: path.clone(),
+                        old: old.clone_value(),
+                        new: new.clone_value(),
+                    });
+                }
+                true
+            }
+            _ => false,
+        };
+
+    if !diffed {
+        ops.push(PatchOp::Replace {
+            path: path.clone(),
+            old: old.clone_value(),
+            new: new.clone_value(),
+        });
+    }
+}
+
+/// Diffs `old` and `new` with `element` appended to the current path.
+fn diff_element(
+    path: &mut String,
+    element: std::fmt::Arguments,
+    old: &dyn Reflect,
+    new: &dyn Reflect,
+    ops: &mut Vec<PatchOp>,
+) {
+    use std::fmt::Write;
+
+    let len = path.len();
+    path.write_fmt(element).unwrap();
+    diff_at(path, old, new, ops);
+    path.truncate(len);
+}
+
+// The functions below diff values of the same type field by field. They return `false` without
+// adding any operation when the values can't be diffed this way, in which case the whole value
+// is replaced.
+
+fn diff_struct(
+    path: &mut String,
+    old: &dyn Struct,
+    new: &dyn Struct,
+    ops: &mut Vec<PatchOp>,
+) -> bool {
+    if old.field_len() != new.field_len()
+        || (0..new.field_len()).any(|index| old.name_at(index) != new.name_at(index))
+    {
+        return false;
+    }
+    for index in 0..new.field_len() {
+        let name = new.name_at(index).unwrap();
+        let (old_field, new_field) = (old.field_at(index).unwrap(), new.field_at(index).unwrap());
+        diff_element(path, format_args!(".{name}"), old_field, new_field, ops);
+    }
+    true
+}
+
+fn diff_tuple_struct(
+    path: &mut String,
+    old: &dyn TupleStruct,
+    new: &dyn TupleStruct,
+    ops: &mut Vec<PatchOp>,
+) -> bool {
+    if old.field_len() != new.field_len() {
+        return false;
+    }
+    for (index, (old_field, new_field)) in old.iter_fields().zip(new.iter_fields()).enumerate() {
+        diff_element(path, format_args!(".{index}"), old_field, new_field, ops);
+    }
+    true
+}
+
+fn diff_tuple(path: &mut String, old: &dyn Tuple, new: &dyn Tuple, ops: &mut Vec<PatchOp>) -> bool {
+    if old.field_len() != new.field_len() {
+        return false;
+    }
+    for (index, (old_field, new_field)) in old.iter_fields().zip(new.iter_fields()).enumerate() {
+        diff_element(path, format_args!(".{index}"), old_field, new_field, ops);
+    }
+    true
+}
+
+fn diff_array(path: &mut String, old: &dyn Array, new: &dyn Array, ops: &mut Vec<PatchOp>) -> bool {
+    if old.len() != new.len() {
+        return false;
+    }
+    for (index, (old_item, new_item)) in old.iter().zip(new.iter()).enumerate() {
+        diff_element(path, format_args!("[{index}]"), old_item, new_item, ops);
+    }
+    true
+}
+
+fn diff_list(path: &mut String, old: &dyn List, new: &dyn List, ops: &mut Vec<PatchOp>) -> bool {
+    for (index, (old_item, new_item)) in old.iter().zip(new.iter()).enumerate() {
+        diff_element(path, format_args!("[{index}]"), old_item, new_item, ops);
+    }
+    // Items are removed from the end first, so that the inverse patch pushes them back in order.
+    for index in (new.len()..old.len()).rev() {
+        ops.push(PatchOp::ListPop {
+            path: path.clone(),
+            value: old.get(index).unwrap().clone_value(),
+        });
+    }
+    for index in old.len()..new.len() {
+        ops.push(PatchOp::ListPush {
+            path: path.clone(),
+            value: new.get(index).unwrap().clone_value(),
+        });
+    }
+    true
+}
+
+fn diff_map(path: &str, old: &dyn Map, new: &dyn Map, ops: &mut Vec<PatchOp>) {
+    // Map entries can't be reached with a path, so changed entries are replaced entirely.
+    for (key, old_value) in old.iter() {
+        let new_value = new.get(key);
+        let changed = match new_value {
+            Some(new_value) => !values_equal(old_value, new_value),
+            None => true,
+        };
+        if changed {
+            ops.push(PatchOp::MapEntry {
+                path: path.to_string(),
+                key: key.clone_value(),
+                old: Some(old_value.clone_value()),
+                new: new_value.map(Reflect::clone_value),
+            });
+        }
+    }
+    for (key, new_value) in new.iter() {
+        if old.get(key).is_none() {
+            ops.push(PatchOp::MapEntry {
+                path: path.to_string(),
+                key: key.clone_value(),
+                old: None,
+                new: Some(new_value.clone_value()),
+            });
+        }
+    }
+}
+
+fn diff_set(path: &str, old: &dyn Set, new: &dyn Set, ops: &mut Vec<PatchOp>) {
+    for value in old.iter() {
+        if !new.contains(value) {
+            ops.push(PatchOp::SetRemove {
+                path: path.to_string(),
+                value: value.clone_value(),
+            });
+        }
+    }
+    for value in new.iter() {
+        if !old.contains(value) {
+            ops.push(PatchOp::SetInsert {
+                path: path.to_string(),
+                value: value.clone_value(),
+            });
+        }
+    }
+}
+
+fn diff_enum(path: &mut String, old: &dyn Enum, new: &dyn Enum, ops: &mut Vec<PatchOp>) -> bool {
+    if old.variant_name() != new.variant_name() || old.field_len() != new.field_len() {
+        return false;
+    }
+    for index in 0..new.field_len() {
+        let (old_field, new_field) = (old.field_at(index).unwrap(), new.field_at(index).unwrap());
+        match new.variant_type() {
+            VariantType::Struct => {
+                let name = new.name_at(index).unwrap();
+                diff_element(path, format_args!(".{name}"), old_field, new_field, ops);
+            }
+            VariantType::Tuple => {
+                diff_element(path, format_args!(".{index}"), old_field, new_field, ops);
+            }
+            VariantType::Unit => {}
+        }
+    }
+    true
+}
+
+fn values_equal(old: &dyn Reflect, new: &dyn Reflect) -> bool {
+    let mut ops = Vec::new();
+    diff_at(&mut String::new(), old, new, &mut ops);
+    ops.is_empty()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as bevy_reflect;
+    use crate::{DynamicEnum, DynamicList, DynamicMap, DynamicStruct, DynamicTuple, FromReflect};
+    use bevy_utils::{HashMap, HashSet};
+
+    #[derive(Reflect, FromReflect, Debug, PartialEq, Clone)]
+    enum Shape {
+        Circle(f32),
+        Rect { width: f32, height: f32 },
+        Empty,
+    }
+
+    #[derive(Reflect, FromReflect, Debug, PartialEq, Clone)]
+    struct Item {
+        name: String,
+        shape: Shape,
+        tags: Vec<String>,
+        stats: HashMap<String, i32>,
+        position: (f32, f32),
+        grid: [u8; 2],
+        flags: HashSet<u32>,
+    }
+
+    fn item() -> Item {
+        Item {
+            name: "box".to_string(),
+            shape: Shape::Rect {
+                width: 1.0,
+                height: 2.0,
+            },
+            tags: vec!["a".to_string(), "b".to_string()],
+            stats: HashMap::from_iter([("hp".to_string(), 3), ("mp".to_string(), 1)]),
+            position: (0.0, 0.0),
+            grid: [0, 0],
+            flags: HashSet::from_iter([1, 2]),
+        }
+    }
+
+    fn assert_round_trip<T: Reflect + FromReflect + PartialEq + Clone + std::fmt::Debug>(
+        old: T,
+        new: T,
+    ) -> ReflectPatch {
+        let patch = diff(&old, &new);
+
+        let mut value = old.clone();
+        patch.apply(&mut value).unwrap();
+        assert_eq!(value, new);
+
+        patch.inverse().apply(&mut value).unwrap();
+        assert_eq!(value, old);
+        patch
+    }
+
+    #[test]
+    fn equal_values_produce_empty_patch() {
+        assert!(diff(&item(), &item()).is_empty());
+    }
+
+    #[test]
+    fn should_diff_nested_fields() {
+        let mut new = item();
+        new.shape = Shape::Rect {
+            width: 1.0,
+            height: 4.0,
+        };
+        new.position.1 = 2.0;
+        new.grid[1] = 7;
+        new.tags[0] = "c".to_string();
+
+        let patch = assert_round_trip(item(), new);
+        let paths: Vec<_> = patch.ops().iter().map(PatchOp::path).collect();
+        assert_eq!(
+            paths,
+            vec![".shape.height", ".tags[0]", ".position.1", ".grid[1]"]
+        );
+
+        let mut value = item();
+        for op in patch.ops() {
+            let PatchOp::Replace { path, new, .. } = op else {
+                panic!("expected `PatchOp::Replace`");
+            };
+            let mut parsed = ParsedPath::parse(path).unwrap();
+            let element = parsed.reflect_element_mut(&mut value).unwrap();
+            element.apply(&**new);
+        }
+        assert_eq!(
+            value.shape,
+            Shape::Rect {
+                width: 1.0,
+                height: 4.0
+            }
+        );
+    }
+
+    #[test]
+    fn should_replace_changed_variant() {
+        let mut new = item();
+        new.shape = Shape::Circle(3.0);
+        let patch = assert_round_trip(item(), new.clone());
+        assert_eq!(patch.len(), 1);
+        assert_eq!(patch.ops()[0].path(), ".shape");
+
+        let mut new_empty = new.clone();
+        new_empty.shape = Shape::Empty;
+        assert_round_trip(new, new_empty);
+    }
+
+    #[test]
+    fn should_diff_list_length() {
+        let mut longer = item();
+        longer.tags.push("c".to_string());
+        longer.tags.push("d".to_string());
+        let patch = assert_round_trip(item(), longer.clone());
+        assert!(matches!(patch.ops()[0], PatchOp::ListPush { .. }));
+
+        let mut shorter = item();
+        shorter.tags.clear();
+        assert_round_trip(longer, shorter);
+    }
+
+    #[test]
+    fn should_diff_map_entries() {
+        let mut new = item();
+        new.stats.remove("mp");
+        new.stats.insert("hp".to_string(), 4);
+        new.stats.insert("xp".to_string(), 10);
+        let patch = assert_round_trip(item(), new);
+        assert_eq!(patch.len(), 3);
+        assert!(patch.ops().iter().all(|op| op.path() == ".stats"));
+    }
+
+    #[test]
+    fn should_diff_set_values() {
+        let mut new = item();
+        new.flags.remove(&1);
+        new.flags.insert(3);
+        let patch = assert_round_trip(item(), new);
+        assert_eq!(patch.len(), 2);
+        assert!(matches!(patch.ops()[0], PatchOp::SetRemove { .. }));
+        assert!(matches!(patch.ops()[1], PatchOp::SetInsert { .. }));
+    }
+
+    #[test]
+    fn should_diff_dynamic_struct() {
+        let mut old = DynamicStruct::default();
+        old.insert("a", 1_u32);
+        old.insert("b", String::from("hello"));
+        let mut list = DynamicList::default();
+        list.push(1_u8);
+        old.insert("c", list);
+
+        let mut new = DynamicStruct::default();
+        new.insert("a", 2_u32);
+        new.insert("b", String::from("hello"));
+        let mut list = DynamicList::default();
+        list.push(1_u8);
+        list.push(2_u8);
+        new.insert("c", list);
+
+        let patch = diff(&old, &new);
+        assert_eq!(patch.len(), 2);
+        assert_eq!(patch.ops()[0].path(), ".a");
+
+        let mut value = old.clone_dynamic();
+        patch.apply(&mut value).unwrap();
+        assert!(value.reflect_partial_eq(&new).unwrap());
+
+        patch.inverse().apply(&mut value).unwrap();
+        assert!(value.reflect_partial_eq(&old).unwrap());
+    }
+
+    #[test]
+    fn should_diff_dynamic_enum() {
+        let mut tuple = DynamicTuple::default();
+        tuple.insert(1.0_f32);
+        let old = DynamicEnum::new(std::any::type_name::<Shape>(), "Circle", tuple);
+        let mut tuple = DynamicTuple::default();
+        tuple.insert(2.0_f32);
+        let new = DynamicEnum::new(std::any::type_name::<Shape>(), "Circle", tuple);
+
+        let patch = diff(&old, &new);
+        assert_eq!(patch.len(), 1);
+        assert_eq!(patch.ops()[0].path(), ".0");
+
+        let mut value = Shape::Circle(1.0);
+        patch.apply(&mut value).unwrap();
+        assert_eq!(value, Shape::Circle(2.0));
+
+        let empty = DynamicEnum::new(std::any::type_name::<Shape>(), "Empty", ());
+        let patch = diff(&new, &empty);
+        assert_eq!(patch.ops()[0].path(), "");
+        patch.apply(&mut value).unwrap();
+        assert_eq!(value, Shape::Empty);
+    }
+
+    #[test]
+    fn should_diff_dynamic_map() {
+        let mut old = DynamicMap::default();
+        old.insert(1_u32, String::from("one"));
+        let mut new = DynamicMap::default();
+        new.insert(2_u32, String::from("two"));
+
+        let patch = diff(&old, &new);
+        let mut value = HashMap::from_iter([(1_u32, String::from("one"))]);
+        patch.apply(&mut value).unwrap();
+        assert_eq!(value, HashMap::from_iter([(2_u32, String::from("two"))]));
+    }
+
+    #[test]
+    fn should_report_invalid_paths() {
+        let patch = ReflectPatch::from_ops(vec![PatchOp::ListPush {
+            path: ".name".to_string(),
+            value: Box::new(1_u32),
+        }]);
+        assert_eq!(
+            patch.apply(&mut item()),
+            Err(ReflectPatchError::ExpectedList {
+                path: ".name".to_string()
+            })
+        );
+
+        let patch = ReflectPatch::from_ops(vec![PatchOp::ListPop {
+            path: ".missing".to_string(),
+            value: Box::new(1_u32),
+        }]);
+        assert!(matches!(
+            patch.apply(&mut item()),
+            Err(ReflectPatchError::InvalidPath { .. })
+        ));
+    }
+}
@@ -0,0 +1,700 @@
+use crate::{FromReflect, Reflect};
+use bevy_utils::HashMap;
+use std::any::{type_name, TypeId};
+use std::borrow::Cow;
+use std::fmt::{Debug, Formatter};
+use std::sync::Arc;
+use thiserror::Error;
+
+/// How an argument is passed to a [`DynamicFunction`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Ownership {
+    /// The argument is passed by value.
+    Owned,
+    /// The argument is passed by shared reference.
+    Ref,
+    /// The argument is passed by mutable reference.
+    Mut,
+}
+
+/// An error returned when calling a [`DynamicFunction`].
+#[derive(Debug, PartialEq, Eq, Error)]
+pub enum FunctionError {
+    #[error("expected {expected} arguments but received {received}")]
+    ArgCount { expected: usize, received: usize },
+    #[error("expected argument {index} to be of type `{expected}` but received `{received}`")]
+    InvalidArg {
+        index: usize,
+        expected: &'static str,
+        received: String,
+    },
+    #[error(
+        "expected argument {index} to be passed as {expected:?} but it was passed as {received:?}"
+    )]
+    InvalidOwnership {
+        index: usize,
+        expected: Ownership,
+        received: Ownership,
+    },
+}
+
+/// An argument passed to a [`DynamicFunction`].
+///
+/// Arguments taken by value by the function are converted using [`FromReflect`], so they can be
+/// passed in any form, including as dynamic types. References, like method receivers,
+/// must be passed as a reference to a value of the concrete type.
+#[derive(Debug)]
+pub enum Arg<'a> {
+    Owned(Box<dyn Reflect>),
+    Ref(&'a dyn Reflect),
+    Mut(&'a mut dyn Reflect),
+}
+
+impl<'a> Arg<'a> {
+    /// Returns the reflected value of this argument.
+    pub fn value(&self) -> &dyn Reflect {
+        match self {
+            Arg::Owned(value) => &**value,
+            Arg::Ref(value) => *value,
+            Arg::Mut(value) => &**value,
+        }
+    }
+
+    /// Returns how this argument is passed.
+    pub fn ownership(&self) -> Ownership {
+        match self {
+            Arg::Owned(_) => Ownership::Owned,
+            Arg::Ref(_) => Ownership::Ref,
+            Arg::Mut(_) => Ownership::Mut,
+        }
+    }
+
+    /// Converts this argument into a `T`, as the argument at `index`.
+    pub fn take_owned<T: FromReflect>(self, index: usize) -> Result<T, FunctionError> {
+        let value = match self {
+            Arg::Owned(value) => {
+                T::take_from_reflect(value).map_err(|value| FunctionError::InvalidArg {
+                    index,
+                    expected: type_name::<T>(),
+                    received: value.type_name().to_string(),
+                })?
+            }
+            Arg::Ref(value) => from_reflect_arg(value, index)?,
+            Arg::Mut(value) => from_reflect_arg(value, index)?,
+        };
+        Ok(value)
+    }
+
+    /// Downcasts this argument into a `&T`, as the argument at `index`.
+    ///
+    /// Owned arguments are rejected, since they wouldn't outlive the call.
+    pub fn take_ref<T: Reflect>(self, index: usize) -> Result<&'a T, FunctionError> {
+        let value: &'a dyn Reflect = match self {
+            Arg::Ref(value) => value,
+            Arg::Mut(value) => value,
+            Arg::Owned(_) => {
+                return Err(FunctionError::InvalidOwnership {
+                    index,
+                    expected: Ownership::Ref,
+                    received: Ownership::Owned,
+                })
+            }
+        };
+        value
+            .downcast_ref()
+            .ok_or_else(|| FunctionError::InvalidArg {
+                index,
+                expected: type_name::<T>(),
+                received: value.type_name().to_string(),
+            })
+    }
+
+    /// Downcasts this argument into a `&mut T`, as the argument at `index`.
+    pub fn take_mut<T: Reflect>(self, index: usize) -> Result<&'a mut T, FunctionError> {
+        let Arg::Mut(value) = self else {
+            return Err(FunctionError::InvalidOwnership {
+                index,
+                expected: Ownership::Mut,
+                received: self.ownership(),
+            });
+        };
+        let received = value.type_name().to_string();
+        value
+            .downcast_mut()
+            .ok_or_else(|| FunctionError::InvalidArg {
+                index,
+                expected: type_name::<T>(),
+                received,
+            })
+    }
+}
+
+fn from_reflect_arg<T: FromReflect>(value: &dyn Reflect, index: usize) -> Result<T, FunctionError> {
+    T::from_reflect(value).ok_or_else(|| FunctionError::InvalidArg {
+        index,
+        expected: type_name::<T>(),
+        received: value.type_name().to_string(),
+    })
+}
+
+/// The list of arguments passed to a [`DynamicFunction`].
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::ArgList;
+/// let mut receiver = vec![1_u32];
+/// let args = ArgList::new().push_mut(&mut receiver).push_owned(2_u32);
+/// assert_eq!(args.len(), 2);
+/// ```
+#[derive(Debug, Default)]
+pub struct ArgList<'a>(Vec<Arg<'a>>);
+
+impl<'a> ArgList<'a> {
+    /// Creates an empty argument list.
+    pub fn new() -> Self {
+        Self(Vec::new())
+    }
+
+    /// Appends an argument to the list.
+    pub fn push(mut self, arg: Arg<'a>) -> Self {
+        self.0.push(arg);
+        self
+    }
+
+    /// Appends an argument passed by value to the list.
+    pub fn push_owned<T: Reflect>(self, value: T) -> Self {
+        self.push(Arg::Owned(Box::new(value)))
+    }
+
+    /// Appends an argument passed by value to the list.
+    pub fn push_boxed(self, value: Box<dyn Reflect>) -> Self {
+        self.push(Arg::Owned(value))
+    }
+
+    /// Appends an argument passed by shared reference to the list.
+    pub fn push_ref(self, value: &'a dyn Reflect) -> Self {
+        self.push(Arg::Ref(value))
+    }
+
+    /// Appends an argument passed by mutable reference to the list.
+    pub fn push_mut(self, value: &'a mut dyn Reflect) -> Self {
+        self.push(Arg::Mut(value))
+    }
+
+    /// Returns the number of arguments in the list.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` if the list contains no arguments.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns the arguments of the list.
+    pub fn into_args(self) -> Vec<Arg<'a>> {
+        self.0
+    }
+}
+
+impl<'a> From<Vec<Arg<'a>>> for ArgList<'a> {
+    fn from(args: Vec<Arg<'a>>) -> Self {
+        Self(args)
+    }
+}
+
+/// Information about an argument of a [`DynamicFunction`].
+#[derive(Debug, Clone)]
+pub struct ArgInfo {
+    index: usize,
+    type_name: &'static str,
+    type_id: TypeId,
+    ownership: Ownership,
+}
+
+impl ArgInfo {
+    /// Creates the information of the argument at `index`, of type `T`.
+    pub fn new<T: 'static>(index: usize, ownership: Ownership) -> Self {
+        Self {
+            index,
+            type_name: type_name::<T>(),
+            type_id: TypeId::of::<T>(),
+            ownership,
+        }
+    }
+
+    /// The position of the argument.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// The [type name] of the argument, without its reference.
+    ///
+    /// [type name]: std::any::type_name
+    pub fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+
+    /// The [`TypeId`] of the argument, without its reference.
+    pub fn type_id(&self) -> TypeId {
+        self.type_id
+    }
+
+    /// How the argument is passed to the function.
+    pub fn ownership(&self) -> Ownership {
+        self.ownership
+    }
+}
+
+/// Information about a [`DynamicFunction`].
+#[derive(Debug, Clone)]
+pub struct FunctionInfo {
+    name: Cow<'static, str>,
+    args: Vec<ArgInfo>,
+    return_type_name: &'static str,
+    return_type_id: TypeId,
+}
+
+impl FunctionInfo {
+    /// Creates the information of a function returning a `R`.
+    pub fn new<R: 'static>(name: impl Into<Cow<'static, str>>, args: Vec<ArgInfo>) -> Self {
+        Self {
+            name: name.into(),
+            args,
+            return_type_name: type_name::<R>(),
+            return_type_id: TypeId::of::<R>(),
+        }
+    }
+
+    /// The name of the function.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The arguments of the function.
+    pub fn args(&self) -> &[ArgInfo] {
+        &self.args
+    }
+
+    /// The [type name] of the value returned by the function.
+    ///
+    /// [type name]: std::any::type_name
+    pub fn return_type_name(&self) -> &'static str {
+        self.return_type_name
+    }
+
+    /// The [`TypeId`] of the value returned by the function.
+    pub fn return_type_id(&self) -> TypeId {
+        self.return_type_id
+    }
+}
+
+type BoxedFunction =
+    dyn for<'a> Fn(ArgList<'a>) -> Result<Box<dyn Reflect>, FunctionError> + Send + Sync;
+
+/// A type-erased function, which can be called with reflected arguments.
+///
+/// A `DynamicFunction` can be created from any function or closure whose arguments implement
+/// [`FromReflect`] and whose return value implements [`Reflect`] using [`IntoFunction`].
+/// Methods taking `&self` or `&mut self` are supported as well, the receiver being passed as
+/// the first argument.
+///
+/// Functions can be stored in a [`TypeRegistry`](crate::TypeRegistry), to be called by name.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::{ArgList, IntoFunction, Reflect};
+/// #[derive(Reflect, Default)]
+/// struct Counter {
+///     count: u32,
+/// }
+///
+/// impl Counter {
+///     fn add(&mut self, amount: u32) -> u32 {
+///         self.count += amount;
+///         self.count
+///     }
+/// }
+///
+/// let add = Counter::add.into_function();
+/// assert_eq!(add.info().args().len(), 2);
+///
+/// let mut counter = Counter::default();
+/// let args = ArgList::new().push_mut(&mut counter).push_owned(3_u32);
+/// let count = add.call(args).unwrap();
+/// assert_eq!(count.downcast_ref::<u32>(), Some(&3));
+/// assert_eq!(counter.count, 3);
+/// ```
+#[derive(Clone)]
+pub struct DynamicFunction {
+    info: FunctionInfo,
+    function: Arc<BoxedFunction>,
+}
+
+impl DynamicFunction {
+    /// Creates a function from its information and a closure taking its reflected arguments.
+    pub fn new<F>(info: FunctionInfo, function: F) -> Self
+    where
+        F: for<'a> Fn(ArgList<'a>) -> Result<Box<dyn Reflect>, FunctionError>
+            + Send
+            + Sync
+            + 'static,
+    {
+        Self {
+            info,
+            function: Arc::new(function),
+        }
+    }
+
+    /// Sets the name of the function.
+    pub fn with_name(mut self, name: impl Into<Cow<'static, str>>) -> Self {
+        self.info.name = name.into();
+        self
+    }
+
+    /// The name of the function.
+    ///
+    /// Unless set with [`DynamicFunction::with_name`], this is the [type name] of the function.
+    ///
+    /// [type name]: std::any::type_name
+    pub fn name(&self) -> &str {
+        self.info.name()
+    }
+
+    /// Returns the information about this function.
+    pub fn info(&self) -> &FunctionInfo {
+        &self.info
+    }
+
+    /// Calls the function with the given arguments, returning its reflected return value.
+    ///
+    /// Functions returning nothing return `()`.
+    pub fn call(&self, args: ArgList) -> Result<Box<dyn Reflect>, FunctionError> {
+        if args.len() != self.info.args.len() {
+            return Err(FunctionError::ArgCount {
+                expected: self.info.args.len(),
+                received: args.len(),
+            });
+        }
+        (self.function)(args)
+    }
+}
+
+impl Debug for DynamicFunction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DynamicFunction")
+            .field("info", &self.info)
+            .finish_non_exhaustive()
+    }
+}
+
+/// A trait for converting functions into a [`DynamicFunction`].
+///
+/// This is implemented for functions and closures of up to 8 arguments, whose arguments
+/// implement [`FromReflect`] and whose return value implements [`Reflect`], and for methods
+/// taking `&self` or `&mut self` along with up to 8 such arguments.
+///
+/// The `Marker` type is only used to distinguish the implementations.
+pub trait IntoFunction<Marker>: Send + Sync + 'static {
+    fn into_function(self) -> DynamicFunction;
+}
+
+/// A trait for converting methods of `T` into a [`DynamicFunction`].
+///
+/// This is implemented for the functions implementing [`IntoFunction`] whose first argument
+/// is `&T` or `&mut T`.
+pub trait IntoMethod<T, Marker>: IntoFunction<Marker> {}
+
+macro_rules! impl_into_function {
+    ($(($arg:ident, $var:ident, $index:tt)),*) => {
+        impl<F, R, $($arg,)*> IntoFunction<fn($($arg,)*) -> R> for F
+        where
+            F: Fn($($arg),*) -> R + Send + Sync + 'static,
+            R: Reflect,
+            $($arg: FromReflect,)*
+        {
+            #[allow(unused_variables, unused_mut)]
+            fn into_function(self) -> DynamicFunction {
+                let info = FunctionInfo::new::<R>(
+                    type_name::<F>(),
+                    vec![$(ArgInfo::new::<$arg>($index, Ownership::Owned)),*],
+                );
+                DynamicFunction::new(info, move |args| {
+                    let mut args = args.into_args().into_iter();
+                    $(let $var = args.next().unwrap().take_owned::<$arg>($index)?;)*
+                    Ok(Box::new((self)($($var),*)))
+                })
+            }
+        }
+
+        impl<F, T, R, $($arg,)*> IntoFunction<(fn(&T), fn($($arg,)*) -> R)> for F
+        where
+            F: Fn(&T, $($arg),*) -> R + Send + Sync + 'static,
+            T: Reflect,
+            R: Reflect,
+            $($arg: FromReflect,)*
+        {
+            #[allow(unused_mut)]
+            fn into_function(self) -> DynamicFunction {
+                let info = FunctionInfo::new::<R>(
+                    type_name::<F>(),
+                    vec![
+                        ArgInfo::new::<T>(0, Ownership::Ref),
+                        $(ArgInfo::new::<$arg>($index + 1, Ownership::Owned)),*
+                    ],
+                );
+                DynamicFunction::new(info, move |args| {
+                    let mut args = args.into_args().into_iter();
+                    let receiver = args.next().unwrap().take_ref::<T>(0)?;
+                    $(let $var = args.next().unwrap().take_owned::<$arg>($index + 1)?;)*
+                    Ok(Box::new((self)(receiver, $($var),*)))
+                })
+            }
+        }
+
+        impl<F, T, R, $($arg,)*> IntoMethod<T, (fn(&T), fn($($arg,)*) -> R)> for F
+        where
+            F: Fn(&T, $($arg),*) -> R + Send + Sync + 'static,
+            T: Reflect,
+            R: Reflect,
+            $($arg: FromReflect,)*
+        {
+        }
+
+        impl<F, T, R, $($arg,)*> IntoFunction<(fn(&mut T), fn($($arg,)*) -> R)> for F
+        where
+            F: Fn(&mut T, $($arg),*) -> R + Send + Sync + 'static,
+            T: Reflect,
+            R: Reflect,
+            $($arg: FromReflect,)*
+        {
+            #[allow(unused_mut)]
+            fn into_function(self) -> DynamicFunction {
+                let info = FunctionInfo::new::<R>(
+                    type_name::<F>(),
+                    vec![
+                        ArgInfo::new::<T>(0, Ownership::Mut),
+                        $(ArgInfo::new::<$arg>($index + 1, Ownership::Owned)),*
+                    ],
+                );
+                DynamicFunction::new(info, move |args| {
+                    let mut args = args.into_args().into_iter();
+                    let receiver = args.next().unwrap().take_mut::<T>(0)?;
+                    $(let $var = args.next().unwrap().take_owned::<$arg>($index + 1)?;)*
+                    Ok(Box::new((self)(receiver, $($var),*)))
+                })
+            }
+        }
+
+        impl<F, T, R, $($arg,)*> IntoMethod<T, (fn(&mut T), fn($($arg,)*) -> R)> for F
+        where
+            F: Fn(&mut T, $($arg),*) -> R + Send + Sync + 'static,
+            T: Reflect,
+            R: Reflect,
+            $($arg: FromReflect,)*
+        {
+        }
+    };
+}
+
+impl_into_function!();
+impl_into_function!((A0, a0, 0));
+impl_into_function!((A0, a0, 0), (A1, a1, 1));
+impl_into_function!((A0, a0, 0), (A1, a1, 1), (A2, a2, 2));
+impl_into_function!((A0, a0, 0), (A1, a1, 1), (A2, a2, 2), (A3, a3, 3));
+impl_into_function!(
+    (A0, a0, 0),
+    (A1, a1, 1),
+    (A2, a2, 2),
+    (A3, a3, 3),
+    (A4, a4, 4)
+);
+impl_into_function!(
+    (A0, a0, 0),
+    (A1, a1, 1),
+    (A2, a2, 2),
+    (A3, a3, 3),
+    (A4, a4, 4),
+    (A5, a5, 5)
+);
+impl_into_function!(
+    (A0, a0, 0),
+    (A1, a1, 1),
+    (A2, a2, 2),
+    (A3, a3, 3),
+    (A4, a4, 4),
+    (A5, a5, 5),
+    (A6, a6, 6)
+);
+impl_into_function!(
+    (A0, a0, 0),
+    (A1, a1, 1),
+    (A2, a2, 2),
+    (A3, a3, 3),
+    (A4, a4, 4),
+    (A5, a5, 5),
+    (A6, a6, 6),
+    (A7, a7, 7)
+);
+
+/// Type data storing the methods registered for a type with
+/// [`TypeRegistry::register_method`](crate::TypeRegistry::register_method).
+#[derive(Clone, Default, Debug)]
+pub struct ReflectMethods {
+    methods: HashMap<String, DynamicFunction>,
+}
+
+impl ReflectMethods {
+    /// Adds a method, replacing any method with the same name.
+    pub fn insert(&mut self, function: DynamicFunction) {
+        self.methods.insert(function.name().to_string(), function);
+    }
+
+    /// Returns the method with the given name.
+    pub fn get(&self, name: &str) -> Option<&DynamicFunction> {
+        self.methods.get(name)
+    }
+
+    /// Returns an iterator over the methods.
+    pub fn iter(&self) -> impl Iterator<Item = &DynamicFunction> {
+        self.methods.values()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as bevy_reflect;
+    use crate::{DynamicStruct, TypeRegistry};
+
+    #[derive(Reflect, FromReflect, Debug, PartialEq, Clone, Default)]
+    struct Vec2 {
+        x: f32,
+        y: f32,
+    }
+
+    impl Vec2 {
+        fn length(&self) -> f32 {
+            (self.x * self.x + self.y * self.y).sqrt()
+        }
+
+        fn scale(&mut self, factor: f32) {
+            self.x *= factor;
+            self.y *= factor;
+        }
+    }
+
+    fn add(a: Vec2, b: Vec2) -> Vec2 {
+        Vec2 {
+            x: a.x + b.x,
+            y: a.y + b.y,
+        }
+    }
+
+    #[test]
+    fn should_call_function() {
+        let function = add.into_function();
+        assert!(function.name().ends_with("add"));
+        assert_eq!(function.info().args()[1].type_id(), TypeId::of::<Vec2>());
+        assert_eq!(function.info().return_type_id(), TypeId::of::<Vec2>());
+
+        let mut dynamic = DynamicStruct::default();
+        dynamic.insert("x", 2.0_f32);
+        dynamic.insert("y", 3.0_f32);
+        let args = ArgList::new()
+            .push_owned(Vec2 { x: 1.0, y: 1.0 })
+            .push_ref(&dynamic);
+        let result = function.call(args).unwrap();
+        assert_eq!(
+            result.downcast_ref::<Vec2>(),
+            Some(&Vec2 { x: 3.0, y: 4.0 })
+        );
+    }
+
+    #[test]
+    fn should_call_closure() {
+        let function = (|| 5_u32).into_function().with_name("five");
+        assert_eq!(function.name(), "five");
+        let result = function.call(ArgList::new()).unwrap();
+        assert_eq!(result.downcast_ref::<u32>(), Some(&5));
+    }
+
+    #[test]
+    fn should_call_methods() {
+        let mut value = Vec2 { x: 3.0, y: 4.0 };
+
+        let length = Vec2::length.into_function();
+        assert_eq!(length.info().args()[0].ownership(), Ownership::Ref);
+        let result = length.call(ArgList::new().push_ref(&value)).unwrap();
+        assert_eq!(result.downcast_ref::<f32>(), Some(&5.0));
+
+        let scale = Vec2::scale.into_function();
+        let result = scale
+            .call(ArgList::new().push_mut(&mut value).push_owned(2.0_f32))
+            .unwrap();
+        assert!(result.is::<()>());
+        assert_eq!(value, Vec2 { x: 6.0, y: 8.0 });
+    }
+
+    #[test]
+    fn should_report_invalid_args() {
+        let scale = Vec2::scale.into_function();
+        let mut value = Vec2::default();
+
+        assert_eq!(
+            scale.call(ArgList::new().push_mut(&mut value)).unwrap_err(),
+            FunctionError::ArgCount {
+                expected: 2,
+                received: 1
+            }
+        );
+        assert_eq!(
+            scale
+                .call(ArgList::new().push_ref(&value).push_owned(2.0_f32))
+                .unwrap_err(),
+            FunctionError::InvalidOwnership {
+                index: 0,
+                expected: Ownership::Mut,
+                received: Ownership::Ref
+            }
+        );
+        assert!(matches!(
+            scale
+                .call(ArgList::new().push_mut(&mut value).push_owned(2_u32))
+                .unwrap_err(),
+            FunctionError::InvalidArg { index: 1, .. }
+        ));
+    }
+
+    #[test]
+    fn should_register_functions() {
+        let mut registry = TypeRegistry::default();
+        registry.register::<Vec2>();
+        registry.register_function("add", add);
+        registry.register_method::<Vec2, _>("scale", Vec2::scale);
+        registry.register_method::<Vec2, _>("length", Vec2::length);
+
+        let mut value = Vec2 { x: 1.0, y: 0.0 };
+        let methods = registry
+            .get_type_data::<ReflectMethods>(TypeId::of::<Vec2>())
+            .unwrap();
+        assert_eq!(methods.iter().count(), 2);
+        methods
+            .get("scale")
+            .unwrap()
+            .call(ArgList::new().push_mut(&mut value).push_owned(3.0_f32))
+            .unwrap();
+        assert_eq!(value, Vec2 { x: 3.0, y: 0.0 });
+
+        let add = registry.get_function("add").unwrap();
+        let result = add
+            .call(ArgList::new().push_ref(&value).push_ref(&value))
+            .unwrap();
+        assert_eq!(
+            result.downcast_ref::<Vec2>(),
+            Some(&Vec2 { x: 6.0, y: 0.0 })
+        );
+    }
+}
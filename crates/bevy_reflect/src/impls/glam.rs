@@ -5,6 +5,7 @@ use bevy_reflect_derive::{impl_from_reflect_value, impl_reflect_struct, impl_ref
 use glam::*;
 
 impl_reflect_struct!(
+    #[type_path = "glam"]
     #[reflect(Debug, Hash, PartialEq, Default)]
     struct IVec2 {
         x: i32,
@@ -12,6 +13,7 @@ impl_reflect_struct!(
     }
 );
 impl_reflect_struct!(
+    #[type_path = "glam"]
     #[reflect(Debug, Hash, PartialEq, Default)]
     struct IVec3 {
         x: i32,
@@ -20,6 +22,7 @@ impl_reflect_struct!(
     }
 );
 impl_reflect_struct!(
+    #[type_path = "glam"]
     #[reflect(Debug, Hash, PartialEq, Default)]
     struct IVec4 {
         x: i32,
@@ -30,6 +33,7 @@ impl_reflect_struct!(
 );
 
 impl_reflect_struct!(
+    #[type_path = "glam"]
     #[reflect(Debug, Hash, PartialEq, Default)]
     struct UVec2 {
         x: u32,
@@ -37,6 +41,7 @@ impl_reflect_struct!(
     }
 );
 impl_reflect_struct!(
+    #[type_path = "glam"]
     #[reflect(Debug, Hash, PartialEq, Default)]
     struct UVec3 {
         x: u32,
@@ -45,6 +50,7 @@ impl_reflect_struct!(
     }
 );
 impl_reflect_struct!(
+    #[type_path = "glam"]
     #[reflect(Debug, Hash, PartialEq, Default)]
     struct UVec4 {
         x: u32,
@@ -55,6 +61,7 @@ impl_reflect_struct!(
 );
 
 impl_reflect_struct!(
+    #[type_path = "glam"]
     #[reflect(Debug, PartialEq, Default)]
     struct Vec2 {
         x: f32,
@@ -62,6 +69,7 @@ impl_reflect_struct!(
     }
 );
 impl_reflect_struct!(
+    #[type_path = "glam"]
     #[reflect(Debug, PartialEq, Default)]
     struct Vec3 {
         x: f32,
@@ -70,6 +78,7 @@ impl_reflect_struct!(
     }
 );
 impl_reflect_struct!(
+    #[type_path = "glam"]
     #[reflect(Debug, PartialEq, Default)]
     struct Vec3A {
         x: f32,
@@ -78,6 +87,7 @@ impl_reflect_struct!(
     }
 );
 impl_reflect_struct!(
+    #[type_path = "glam"]
     #[reflect(Debug, PartialEq, Default)]
     struct Vec4 {
         x: f32,
@@ -88,6 +98,7 @@ impl_reflect_struct!(
 );
 
 impl_reflect_struct!(
+    #[type_path = "glam"]
     #[reflect(Debug, PartialEq, Default)]
     struct BVec2 {
         x: bool,
@@ -95,6 +106,7 @@ impl_reflect_struct!(
     }
 );
 impl_reflect_struct!(
+    #[type_path = "glam"]
     #[reflect(Debug, PartialEq, Default)]
     struct BVec3 {
         x: bool,
@@ -103,6 +115,7 @@ impl_reflect_struct!(
     }
 );
 impl_reflect_struct!(
+    #[type_path = "glam"]
     #[reflect(Debug, PartialEq, Default)]
     struct BVec4 {
         x: bool,
@@ -113,6 +126,7 @@ impl_reflect_struct!(
 );
 
 impl_reflect_struct!(
+    #[type_path = "glam"]
     #[reflect(Debug, PartialEq, Default)]
     struct DVec2 {
         x: f64,
@@ -120,6 +134,7 @@ impl_reflect_struct!(
     }
 );
 impl_reflect_struct!(
+    #[type_path = "glam"]
     #[reflect(Debug, PartialEq, Default)]
     struct DVec3 {
         x: f64,
@@ -128,6 +143,7 @@ impl_reflect_struct!(
     }
 );
 impl_reflect_struct!(
+    #[type_path = "glam"]
     #[reflect(Debug, PartialEq, Default)]
     struct DVec4 {
         x: f64,
@@ -138,6 +154,7 @@ impl_reflect_struct!(
 );
 
 impl_reflect_struct!(
+    #[type_path = "glam"]
     #[reflect(Debug, PartialEq, Default)]
     struct Mat2 {
         x_axis: Vec2,
@@ -145,6 +162,7 @@ impl_reflect_struct!(
     }
 );
 impl_reflect_struct!(
+    #[type_path = "glam"]
     #[reflect(Debug, PartialEq, Default)]
     struct Mat3 {
         x_axis: Vec3,
@@ -153,6 +171,7 @@ impl_reflect_struct!(
     }
 );
 impl_reflect_struct!(
+    #[type_path = "glam"]
     #[reflect(Debug, PartialEq, Default)]
     struct Mat3A {
         x_axis: Vec3A,
@@ -161,6 +180,7 @@ impl_reflect_struct!(
     }
 );
 impl_reflect_struct!(
+    #[type_path = "glam"]
     #[reflect(Debug, PartialEq, Default)]
     struct Mat4 {
         x_axis: Vec4,
@@ -171,6 +191,7 @@ impl_reflect_struct!(
 );
 
 impl_reflect_struct!(
+    #[type_path = "glam"]
     #[reflect(Debug, PartialEq, Default)]
     struct DMat2 {
         x_axis: DVec2,
@@ -178,6 +199,7 @@ impl_reflect_struct!(
     }
 );
 impl_reflect_struct!(
+    #[type_path = "glam"]
     #[reflect(Debug, PartialEq, Default)]
     struct DMat3 {
         x_axis: DVec3,
@@ -186,6 +208,7 @@ impl_reflect_struct!(
     }
 );
 impl_reflect_struct!(
+    #[type_path = "glam"]
     #[reflect(Debug, PartialEq, Default)]
     struct DMat4 {
         x_axis: DVec4,
@@ -196,6 +219,7 @@ impl_reflect_struct!(
 );
 
 impl_reflect_struct!(
+    #[type_path = "glam"]
     #[reflect(Debug, PartialEq, Default)]
     struct Affine2 {
         matrix2: Mat2,
@@ -203,6 +227,7 @@ impl_reflect_struct!(
     }
 );
 impl_reflect_struct!(
+    #[type_path = "glam"]
     #[reflect(Debug, PartialEq, Default)]
     struct Affine3A {
         matrix3: Mat3A,
@@ -211,6 +236,7 @@ impl_reflect_struct!(
 );
 
 impl_reflect_struct!(
+    #[type_path = "glam"]
     #[reflect(Debug, PartialEq, Default)]
     struct DAffine2 {
         matrix2: DMat2,
@@ -218,6 +244,7 @@ impl_reflect_struct!(
     }
 );
 impl_reflect_struct!(
+    #[type_path = "glam"]
     #[reflect(Debug, PartialEq, Default)]
     struct DAffine3 {
         matrix3: DMat3,
@@ -229,12 +256,27 @@ impl_reflect_struct!(
 // mechanisms for read-only fields. I doubt those mechanisms would be added,
 // so for now quaternions will remain as values. They are represented identically
 // to Vec4 and DVec4, so you may use those instead and convert between.
-impl_reflect_value!(Quat(Debug, PartialEq, Serialize, Deserialize, Default));
-impl_reflect_value!(DQuat(Debug, PartialEq, Serialize, Deserialize, Default));
+impl_reflect_value!(
+    #[type_path = "glam"]
+    Quat(Debug, PartialEq, Serialize, Deserialize, Default)
+);
+impl_reflect_value!(
+    #[type_path = "glam"]
+    DQuat(Debug, PartialEq, Serialize, Deserialize, Default)
+);
 
 impl_from_reflect_value!(Quat);
 impl_from_reflect_value!(DQuat);
 
-impl_reflect_value!(EulerRot(Debug, Default));
-impl_reflect_value!(BVec3A(Debug, Default));
-impl_reflect_value!(BVec4A(Debug, Default));
+impl_reflect_value!(
+    #[type_path = "glam"]
+    EulerRot(Debug, Default)
+);
+impl_reflect_value!(
+    #[type_path = "glam"]
+    BVec3A(Debug, Default)
+);
+impl_reflect_value!(
+    #[type_path = "glam"]
+    BVec4A(Debug, Default)
+);
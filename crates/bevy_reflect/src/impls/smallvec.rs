@@ -1,10 +1,10 @@
 use smallvec::SmallVec;
 use std::any::Any;
 
-use crate::utility::GenericTypeInfoCell;
+use crate::utility::{GenericTypeInfoCell, GenericTypePathCell};
 use crate::{
     FromReflect, FromType, GetTypeRegistration, List, ListInfo, ListIter, Reflect, ReflectFromPtr,
-    ReflectMut, ReflectOwned, ReflectRef, TypeInfo, TypeRegistration, Typed,
+    ReflectMut, ReflectOwned, ReflectRef, TypeInfo, TypePath, TypeRegistration, Typed,
 };
 
 impl<T: smallvec::Array + Send + Sync + 'static> List for SmallVec<T>
@@ -167,7 +167,31 @@ where
     }
 }
 
-impl<T: smallvec::Array + Send + Sync + 'static> GetTypeRegistration for SmallVec<T>
+impl<T: smallvec::Array + TypePath> TypePath for SmallVec<T> {
+    fn type_path() -> &'static str {
+        static CELL: GenericTypePathCell = GenericTypePathCell::new();
+        CELL.get_or_insert::<Self, _>(|| format!("smallvec::SmallVec<{}>", T::type_path()))
+    }
+
+    fn short_type_path() -> &'static str {
+        static CELL: GenericTypePathCell = GenericTypePathCell::new();
+        CELL.get_or_insert::<Self, _>(|| format!("SmallVec<{}>", T::short_type_path()))
+    }
+
+    fn type_ident() -> Option<&'static str> {
+        Some("SmallVec")
+    }
+
+    fn crate_name() -> Option<&'static str> {
+        Some("smallvec")
+    }
+
+    fn module_path() -> Option<&'static str> {
+        Some("smallvec")
+    }
+}
+
+impl<T: smallvec::Array + TypePath + Send + Sync> GetTypeRegistration for SmallVec<T>
 where
     T::Item: FromReflect,
 {
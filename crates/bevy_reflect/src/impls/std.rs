@@ -1,21 +1,24 @@
 use crate::std_traits::ReflectDefault;
 use crate::{self as bevy_reflect, ReflectFromPtr, ReflectOwned};
 use crate::{
-    map_apply, map_partial_eq, Array, ArrayInfo, ArrayIter, DynamicEnum, DynamicMap, Enum,
-    EnumInfo, FromReflect, FromType, GetTypeRegistration, List, ListInfo, Map, MapInfo, MapIter,
-    Reflect, ReflectDeserialize, ReflectMut, ReflectRef, ReflectSerialize, TupleVariantInfo,
-    TypeInfo, TypeRegistration, Typed, UnitVariantInfo, UnnamedField, ValueInfo, VariantFieldIter,
+    map_apply, map_partial_eq, set_apply, set_partial_eq, Array, ArrayInfo, ArrayIter, DynamicEnum,
+    DynamicMap, DynamicSet, Enum, EnumInfo, FromReflect, FromType, GetTypeRegistration, List,
+    ListInfo, Map, MapInfo, MapIter, Reflect, ReflectDeserialize, ReflectMut, ReflectRef,
+    ReflectSerialize, Set, SetInfo, SetIter, TupleVariantInfo, TypeInfo, TypePath,
+    TypeRegistration, Typed, UnitVariantInfo, UnnamedField, ValueInfo, VariantFieldIter,
     VariantInfo, VariantType,
 };
 
-use crate::utility::{reflect_hasher, GenericTypeInfoCell, NonGenericTypeInfoCell};
+use crate::utility::{
+    reflect_hasher, GenericTypeInfoCell, GenericTypePathCell, NonGenericTypeInfoCell,
+};
 use bevy_reflect_derive::{impl_from_reflect_value, impl_reflect_value};
 use bevy_utils::{Duration, Instant};
 use bevy_utils::{HashMap, HashSet};
 use std::{
     any::Any,
     borrow::Cow,
-    collections::VecDeque,
+    collections::{BTreeSet, VecDeque},
     ffi::OsString,
     hash::{Hash, Hasher},
     num::{
@@ -84,58 +87,93 @@ impl_reflect_value!(isize(
 ));
 impl_reflect_value!(f32(Debug, PartialEq, Serialize, Deserialize, Default));
 impl_reflect_value!(f64(Debug, PartialEq, Serialize, Deserialize, Default));
-impl_reflect_value!(String(
-    Debug,
-    Hash,
-    PartialEq,
-    Serialize,
-    Deserialize,
-    Default
-));
-impl_reflect_value!(PathBuf(
-    Debug,
-    Hash,
-    PartialEq,
-    Serialize,
-    Deserialize,
-    Default
-));
-impl_reflect_value!(Result<T: Clone + Reflect + 'static, E: Clone + Reflect + 'static>());
-impl_reflect_value!(HashSet<T: Hash + Eq + Clone + Send + Sync + 'static>());
-impl_reflect_value!(Range<T: Clone + Send + Sync + 'static>());
-impl_reflect_value!(RangeInclusive<T: Clone + Send + Sync + 'static>());
-impl_reflect_value!(RangeFrom<T: Clone + Send + Sync + 'static>());
-impl_reflect_value!(RangeTo<T: Clone + Send + Sync + 'static>());
-impl_reflect_value!(RangeToInclusive<T: Clone + Send + Sync + 'static>());
-impl_reflect_value!(RangeFull());
-impl_reflect_value!(Duration(
-    Debug,
-    Hash,
-    PartialEq,
-    Serialize,
-    Deserialize,
-    Default
-));
-impl_reflect_value!(Instant(Debug, Hash, PartialEq));
-impl_reflect_value!(NonZeroI128(Debug, Hash, PartialEq, Serialize, Deserialize));
-impl_reflect_value!(NonZeroU128(Debug, Hash, PartialEq, Serialize, Deserialize));
-impl_reflect_value!(NonZeroIsize(Debug, Hash, PartialEq, Serialize, Deserialize));
-impl_reflect_value!(NonZeroUsize(Debug, Hash, PartialEq, Serialize, Deserialize));
-impl_reflect_value!(NonZeroI64(Debug, Hash, PartialEq, Serialize, Deserialize));
-impl_reflect_value!(NonZeroU64(Debug, Hash, PartialEq, Serialize, Deserialize));
-impl_reflect_value!(NonZeroU32(Debug, Hash, PartialEq, Serialize, Deserialize));
-impl_reflect_value!(NonZeroI32(Debug, Hash, PartialEq, Serialize, Deserialize));
-impl_reflect_value!(NonZeroI16(Debug, Hash, PartialEq, Serialize, Deserialize));
-impl_reflect_value!(NonZeroU16(Debug, Hash, PartialEq, Serialize, Deserialize));
-impl_reflect_value!(NonZeroU8(Debug, Hash, PartialEq, Serialize, Deserialize));
-impl_reflect_value!(NonZeroI8(Debug, Hash, PartialEq, Serialize, Deserialize));
+impl_reflect_value!(
+    #[type_path = "alloc::string"]
+    String(Debug, Hash, PartialEq, Serialize, Deserialize, Default)
+);
+impl_reflect_value!(
+    #[type_path = "std::path"]
+    PathBuf(Debug, Hash, PartialEq, Serialize, Deserialize, Default)
+);
+impl_reflect_value!(#[type_path = "core::result"] Result<T: Clone + Reflect + 'static, E: Clone + Reflect + 'static>());
+impl_reflect_value!(#[type_path = "core::ops"] Range<T: Clone + Send + Sync + 'static>());
+impl_reflect_value!(#[type_path = "core::ops"] RangeInclusive<T: Clone + Send + Sync + 'static>());
+impl_reflect_value!(#[type_path = "core::ops"] RangeFrom<T: Clone + Send + Sync + 'static>());
+impl_reflect_value!(#[type_path = "core::ops"] RangeTo<T: Clone + Send + Sync + 'static>());
+impl_reflect_value!(#[type_path = "core::ops"] RangeToInclusive<T: Clone + Send + Sync + 'static>());
+impl_reflect_value!(
+    #[type_path = "core::ops"]
+    RangeFull()
+);
+impl_reflect_value!(
+    #[type_path = "core::time"]
+    Duration(Debug, Hash, PartialEq, Serialize, Deserialize, Default)
+);
+impl_reflect_value!(
+    #[type_path = "std::time"]
+    Instant(Debug, Hash, PartialEq)
+);
+impl_reflect_value!(
+    #[type_path = "core::num"]
+    NonZeroI128(Debug, Hash, PartialEq, Serialize, Deserialize)
+);
+impl_reflect_value!(
+    #[type_path = "core::num"]
+    NonZeroU128(Debug, Hash, PartialEq, Serialize, Deserialize)
+);
+impl_reflect_value!(
+    #[type_path = "core::num"]
+    NonZeroIsize(Debug, Hash, PartialEq, Serialize, Deserialize)
+);
+impl_reflect_value!(
+    #[type_path = "core::num"]
+    NonZeroUsize(Debug, Hash, PartialEq, Serialize, Deserialize)
+);
+impl_reflect_value!(
+    #[type_path = "core::num"]
+    NonZeroI64(Debug, Hash, PartialEq, Serialize, Deserialize)
+);
+impl_reflect_value!(
+    #[type_path = "core::num"]
+    NonZeroU64(Debug, Hash, PartialEq, Serialize, Deserialize)
+);
+impl_reflect_value!(
+    #[type_path = "core::num"]
+    NonZeroU32(Debug, Hash, PartialEq, Serialize, Deserialize)
+);
+impl_reflect_value!(
+    #[type_path = "core::num"]
+    NonZeroI32(Debug, Hash, PartialEq, Serialize, Deserialize)
+);
+impl_reflect_value!(
+    #[type_path = "core::num"]
+    NonZeroI16(Debug, Hash, PartialEq, Serialize, Deserialize)
+);
+impl_reflect_value!(
+    #[type_path = "core::num"]
+    NonZeroU16(Debug, Hash, PartialEq, Serialize, Deserialize)
+);
+impl_reflect_value!(
+    #[type_path = "core::num"]
+    NonZeroU8(Debug, Hash, PartialEq, Serialize, Deserialize)
+);
+impl_reflect_value!(
+    #[type_path = "core::num"]
+    NonZeroI8(Debug, Hash, PartialEq, Serialize, Deserialize)
+);
 
 // `Serialize` and `Deserialize` only for platforms supported by serde:
 // https://github.com/serde-rs/serde/blob/3ffb86fc70efd3d329519e2dddfa306cc04f167c/serde/src/de/impls.rs#L1732
 #[cfg(any(unix, windows))]
-impl_reflect_value!(OsString(Debug, Hash, PartialEq, Serialize, Deserialize));
+impl_reflect_value!(
+    #[type_path = "std::ffi"]
+    OsString(Debug, Hash, PartialEq, Serialize, Deserialize)
+);
 #[cfg(not(any(unix, windows)))]
-impl_reflect_value!(OsString(Debug, Hash, PartialEq));
+impl_reflect_value!(
+    #[type_path = "std::ffi"]
+    OsString(Debug, Hash, PartialEq)
+);
 
 impl_from_reflect_value!(bool);
 impl_from_reflect_value!(char);
@@ -156,7 +194,6 @@ impl_from_reflect_value!(f64);
 impl_from_reflect_value!(String);
 impl_from_reflect_value!(PathBuf);
 impl_from_reflect_value!(OsString);
-impl_from_reflect_value!(HashSet<T: Hash + Eq + Clone + Send + Sync + 'static>);
 impl_from_reflect_value!(Range<T: Clone + Send + Sync + 'static>);
 impl_from_reflect_value!(RangeInclusive<T: Clone + Send + Sync + 'static>);
 impl_from_reflect_value!(RangeFrom<T: Clone + Send + Sync + 'static>);
@@ -179,7 +216,7 @@ impl_from_reflect_value!(NonZeroU8);
 impl_from_reflect_value!(NonZeroI8);
 
 macro_rules! impl_reflect_for_veclike {
-    ($ty:ty, $insert:expr, $remove:expr, $push:expr, $pop:expr, $sub:ty) => {
+    ($ty:ty, $path:literal, $insert:expr, $remove:expr, $push:expr, $pop:expr, $sub:ty) => {
         impl<T: FromReflect> List for $ty {
             #[inline]
             fn get(&self, index: usize) -> Option<&dyn Reflect> {
@@ -313,7 +350,22 @@ macro_rules! impl_reflect_for_veclike {
             }
         }
 
-        impl<T: FromReflect> GetTypeRegistration for $ty {
+        impl<T: TypePath> TypePath for $ty {
+            fn type_path() -> &'static str {
+                static CELL: GenericTypePathCell = GenericTypePathCell::new();
+                CELL.get_or_insert::<Self, _>(|| format!(concat!($path, "<{}>"), T::type_path()))
+            }
+
+            fn short_type_path() -> &'static str {
+                static CELL: GenericTypePathCell = GenericTypePathCell::new();
+                CELL.get_or_insert::<Self, _>(|| {
+                    let ident = $path.rsplit("::").next().unwrap();
+                    format!("{}<{}>", ident, T::short_type_path())
+                })
+            }
+        }
+
+        impl<T: FromReflect + TypePath> GetTypeRegistration for $ty {
             fn get_type_registration() -> TypeRegistration {
                 let mut registration = TypeRegistration::of::<$ty>();
                 registration.insert::<ReflectFromPtr>(FromType::<$ty>::from_type());
@@ -337,9 +389,18 @@ macro_rules! impl_reflect_for_veclike {
     };
 }
 
-impl_reflect_for_veclike!(Vec<T>, Vec::insert, Vec::remove, Vec::push, Vec::pop, [T]);
+impl_reflect_for_veclike!(
+    Vec<T>,
+    "alloc::vec::Vec",
+    Vec::insert,
+    Vec::remove,
+    Vec::push,
+    Vec::pop,
+    [T]
+);
 impl_reflect_for_veclike!(
     VecDeque<T>,
+    "alloc::collections::VecDeque",
     VecDeque::insert,
     VecDeque::remove,
     VecDeque::push_back,
@@ -347,6 +408,189 @@ impl_reflect_for_veclike!(
     VecDeque::<T>
 );
 
+macro_rules! impl_reflect_for_set {
+    ($ty:ty, $path:literal, $($bound:tt)+) => {
+        impl<T: FromReflect + $($bound)+> Set for $ty {
+            fn get(&self, value: &dyn Reflect) -> Option<&dyn Reflect> {
+                let mut from_reflect = None;
+                value
+                    .downcast_ref::<T>()
+                    .or_else(|| {
+                        from_reflect = T::from_reflect(value);
+                        from_reflect.as_ref()
+                    })
+                    .and_then(|value| <$ty>::get(self, value))
+                    .map(|value| value as &dyn Reflect)
+            }
+
+            fn get_at(&self, index: usize) -> Option<&dyn Reflect> {
+                <$ty>::iter(self)
+                    .nth(index)
+                    .map(|value| value as &dyn Reflect)
+            }
+
+            fn len(&self) -> usize {
+                <$ty>::len(self)
+            }
+
+            fn iter(&self) -> SetIter {
+                SetIter::new(self)
+            }
+
+            fn drain(self: Box<Self>) -> Vec<Box<dyn Reflect>> {
+                self.into_iter()
+                    .map(|value| Box::new(value) as Box<dyn Reflect>)
+                    .collect()
+            }
+
+            fn clone_dynamic(&self) -> DynamicSet {
+                let mut dynamic_set = DynamicSet::default();
+                dynamic_set.set_name(self.type_name().to_string());
+                for value in self {
+                    dynamic_set.insert_boxed(value.clone_value());
+                }
+                dynamic_set
+            }
+
+            fn insert_boxed(&mut self, value: Box<dyn Reflect>) -> bool {
+                let value = T::take_from_reflect(value).unwrap_or_else(|value| {
+                    panic!(
+                        "Attempted to insert invalid value of type {}.",
+                        value.type_name()
+                    )
+                });
+                self.insert(value)
+            }
+
+            fn remove(&mut self, value: &dyn Reflect) -> bool {
+                let mut from_reflect = None;
+                value
+                    .downcast_ref::<T>()
+                    .or_else(|| {
+                        from_reflect = T::from_reflect(value);
+                        from_reflect.as_ref()
+                    })
+                    .map_or(false, |value| <$ty>::remove(self, value))
+            }
+        }
+
+        impl<T: FromReflect + $($bound)+> Reflect for $ty {
+            fn type_name(&self) -> &str {
+                std::any::type_name::<Self>()
+            }
+
+            fn get_type_info(&self) -> &'static TypeInfo {
+                <Self as Typed>::type_info()
+            }
+
+            fn into_any(self: Box<Self>) -> Box<dyn Any> {
+                self
+            }
+
+            fn as_any(&self) -> &dyn Any {
+                self
+            }
+
+            fn as_any_mut(&mut self) -> &mut dyn Any {
+                self
+            }
+
+            fn into_reflect(self: Box<Self>) -> Box<dyn Reflect> {
+                self
+            }
+
+            fn as_reflect(&self) -> &dyn Reflect {
+                self
+            }
+
+            fn as_reflect_mut(&mut self) -> &mut dyn Reflect {
+                self
+            }
+
+            fn apply(&mut self, value: &dyn Reflect) {
+                set_apply(self, value);
+            }
+
+            fn set(&mut self, value: Box<dyn Reflect>) -> Result<(), Box<dyn Reflect>> {
+                *self = value.take()?;
+                Ok(())
+            }
+
+            fn reflect_ref(&self) -> ReflectRef {
+                ReflectRef::Set(self)
+            }
+
+            fn reflect_mut(&mut self) -> ReflectMut {
+                ReflectMut::Set(self)
+            }
+
+            fn reflect_owned(self: Box<Self>) -> ReflectOwned {
+                ReflectOwned::Set(self)
+            }
+
+            fn clone_value(&self) -> Box<dyn Reflect> {
+                Box::new(self.clone_dynamic())
+            }
+
+            fn reflect_partial_eq(&self, value: &dyn Reflect) -> Option<bool> {
+                set_partial_eq(self, value)
+            }
+        }
+
+        impl<T: FromReflect + $($bound)+> Typed for $ty {
+            fn type_info() -> &'static TypeInfo {
+                static CELL: GenericTypeInfoCell = GenericTypeInfoCell::new();
+                CELL.get_or_insert::<Self, _>(|| TypeInfo::Set(SetInfo::new::<Self, T>()))
+            }
+        }
+
+        impl<T: TypePath> TypePath for $ty {
+            fn type_path() -> &'static str {
+                static CELL: GenericTypePathCell = GenericTypePathCell::new();
+                CELL.get_or_insert::<Self, _>(|| format!(concat!($path, "<{}>"), T::type_path()))
+            }
+
+            fn short_type_path() -> &'static str {
+                static CELL: GenericTypePathCell = GenericTypePathCell::new();
+                CELL.get_or_insert::<Self, _>(|| {
+                    let ident = $path.rsplit("::").next().unwrap();
+                    format!("{}<{}>", ident, T::short_type_path())
+                })
+            }
+        }
+
+        impl<T: FromReflect + TypePath + $($bound)+> GetTypeRegistration for $ty {
+            fn get_type_registration() -> TypeRegistration {
+                let mut registration = TypeRegistration::of::<$ty>();
+                registration.insert::<ReflectFromPtr>(FromType::<$ty>::from_type());
+                registration
+            }
+        }
+
+        impl<T: FromReflect + $($bound)+> FromReflect for $ty {
+            fn from_reflect(reflect: &dyn Reflect) -> Option<Self> {
+                if let ReflectRef::Set(ref_set) = reflect.reflect_ref() {
+                    let mut new_set = Self::default();
+                    for value in ref_set.iter() {
+                        new_set.insert(T::from_reflect(value)?);
+                    }
+                    Some(new_set)
+                } else {
+                    None
+                }
+            }
+        }
+    };
+}
+
+impl_reflect_for_set!(HashSet<T>, "bevy_utils::HashSet", Eq + Hash);
+impl_reflect_for_set!(
+    std::collections::HashSet<T>,
+    "std::collections::HashSet",
+    Eq + Hash
+);
+impl_reflect_for_set!(BTreeSet<T>, "alloc::collections::BTreeSet", Ord);
+
 impl<K: FromReflect + Eq + Hash, V: FromReflect> Map for HashMap<K, V> {
     fn get(&self, key: &dyn Reflect) -> Option<&dyn Reflect> {
         key.downcast_ref::<K>()
@@ -501,10 +745,46 @@ impl<K: FromReflect + Eq + Hash, V: FromReflect> Typed for HashMap<K, V> {
     }
 }
 
+impl<K: TypePath, V: TypePath> TypePath for HashMap<K, V> {
+    fn type_path() -> &'static str {
+        static CELL: GenericTypePathCell = GenericTypePathCell::new();
+        CELL.get_or_insert::<Self, _>(|| {
+            format!(
+                "bevy_utils::HashMap<{}, {}>",
+                K::type_path(),
+                V::type_path()
+            )
+        })
+    }
+
+    fn short_type_path() -> &'static str {
+        static CELL: GenericTypePathCell = GenericTypePathCell::new();
+        CELL.get_or_insert::<Self, _>(|| {
+            format!(
+                "HashMap<{}, {}>",
+                K::short_type_path(),
+                V::short_type_path()
+            )
+        })
+    }
+
+    fn type_ident() -> Option<&'static str> {
+        Some("HashMap")
+    }
+
+    fn crate_name() -> Option<&'static str> {
+        Some("bevy_utils")
+    }
+
+    fn module_path() -> Option<&'static str> {
+        Some("bevy_utils")
+    }
+}
+
 impl<K, V> GetTypeRegistration for HashMap<K, V>
 where
-    K: FromReflect + Eq + Hash,
-    V: FromReflect,
+    K: FromReflect + TypePath + Eq + Hash,
+    V: FromReflect + TypePath,
 {
     fn get_type_registration() -> TypeRegistration {
         let mut registration = TypeRegistration::of::<HashMap<K, V>>();
@@ -661,6 +941,18 @@ impl<T: Reflect, const N: usize> Typed for [T; N] {
     }
 }
 
+impl<T: TypePath, const N: usize> TypePath for [T; N] {
+    fn type_path() -> &'static str {
+        static CELL: GenericTypePathCell = GenericTypePathCell::new();
+        CELL.get_or_insert::<Self, _>(|| format!("[{}; {}]", T::type_path(), N))
+    }
+
+    fn short_type_path() -> &'static str {
+        static CELL: GenericTypePathCell = GenericTypePathCell::new();
+        CELL.get_or_insert::<Self, _>(|| format!("[{}; {}]", T::short_type_path(), N))
+    }
+}
+
 // TODO:
 // `FromType::from_type` requires `Deserialize<'de>` to be implemented for `T`.
 // Currently serde only supports `Deserialize<'de>` for arrays up to size 32.
@@ -669,7 +961,7 @@ impl<T: Reflect, const N: usize> Typed for [T; N] {
 macro_rules! impl_array_get_type_registration {
     ($($N:expr)+) => {
         $(
-            impl<T: Reflect > GetTypeRegistration for [T; $N] {
+            impl<T: Reflect + TypePath> GetTypeRegistration for [T; $N] {
                 fn get_type_registration() -> TypeRegistration {
                     TypeRegistration::of::<[T; $N]>()
                 }
@@ -685,7 +977,31 @@ impl_array_get_type_registration! {
     30 31 32
 }
 
-impl<T: FromReflect> GetTypeRegistration for Option<T> {
+impl<T: TypePath> TypePath for Option<T> {
+    fn type_path() -> &'static str {
+        static CELL: GenericTypePathCell = GenericTypePathCell::new();
+        CELL.get_or_insert::<Self, _>(|| format!("core::option::Option<{}>", T::type_path()))
+    }
+
+    fn short_type_path() -> &'static str {
+        static CELL: GenericTypePathCell = GenericTypePathCell::new();
+        CELL.get_or_insert::<Self, _>(|| format!("Option<{}>", T::short_type_path()))
+    }
+
+    fn type_ident() -> Option<&'static str> {
+        Some("Option")
+    }
+
+    fn crate_name() -> Option<&'static str> {
+        Some("core")
+    }
+
+    fn module_path() -> Option<&'static str> {
+        Some("core::option")
+    }
+}
+
+impl<T: FromReflect + TypePath> GetTypeRegistration for Option<T> {
     fn get_type_registration() -> TypeRegistration {
         TypeRegistration::of::<Option<T>>()
     }
@@ -1016,6 +1332,28 @@ impl Typed for Cow<'static, str> {
     }
 }
 
+impl TypePath for Cow<'static, str> {
+    fn type_path() -> &'static str {
+        "alloc::borrow::Cow<str>"
+    }
+
+    fn short_type_path() -> &'static str {
+        "Cow<str>"
+    }
+
+    fn type_ident() -> Option<&'static str> {
+        Some("Cow")
+    }
+
+    fn crate_name() -> Option<&'static str> {
+        Some("alloc")
+    }
+
+    fn module_path() -> Option<&'static str> {
+        Some("alloc::borrow")
+    }
+}
+
 impl GetTypeRegistration for Cow<'static, str> {
     fn get_type_registration() -> TypeRegistration {
         let mut registration = TypeRegistration::of::<Cow<'static, str>>();
@@ -1124,6 +1462,16 @@ impl Typed for &'static Path {
     }
 }
 
+impl TypePath for &'static Path {
+    fn type_path() -> &'static str {
+        "&std::path::Path"
+    }
+
+    fn short_type_path() -> &'static str {
+        "&Path"
+    }
+}
+
 impl GetTypeRegistration for &'static Path {
     fn get_type_registration() -> TypeRegistration {
         let mut registration = TypeRegistration::of::<Self>();
@@ -41,6 +41,7 @@
 //! It will also generate other very important trait implementations used for reflection:
 //! * [`GetTypeRegistration`]
 //! * [`Typed`]
+//! * [`TypePath`]
 //! * [`Struct`], [`TupleStruct`], or [`Enum`] depending on the type
 //!
 //! ## Requirements
@@ -65,6 +66,7 @@
 //! * [`Array`]
 //! * [`List`]
 //! * [`Map`]
+//! * [`Set`]
 //! * [`Struct`]
 //! * [`TupleStruct`]
 //! * [`Enum`]
@@ -125,6 +127,7 @@
 //! * [`DynamicArray`]
 //! * [`DynamicList`]
 //! * [`DynamicMap`]
+//! * [`DynamicSet`]
 //! * [`DynamicStruct`]
 //! * [`DynamicTupleStruct`]
 //! * [`DynamicEnum`]
@@ -286,6 +289,9 @@
 //! ```
 //!
 //! The generated type data can be used to convert a valid `dyn Reflect` into a `dyn MyTrait`.
+//! It implements [`TraitCast`], so the [registry] can also perform the conversion directly with
+//! [`TypeRegistry::cast_ref`], and find every registered type implementing the trait with
+//! [`TypeRegistry::iter_with_data`].
 //! See the [trait reflection example](https://github.com/bevyengine/bevy/blob/latest/examples/reflection/trait_reflection.rs)
 //! for more information and usage details.
 //!
@@ -437,16 +443,22 @@
 //! [derive `Reflect`]: derive@crate::Reflect
 
 mod array;
+mod attributes;
+mod diff;
 mod fields;
 mod from_reflect;
+mod function;
 mod list;
 mod map;
 mod path;
 mod reflect;
+mod set;
 mod struct_trait;
+mod trait_cast;
 mod tuple;
 mod tuple_struct;
 mod type_info;
+mod type_path;
 mod type_registry;
 mod type_uuid;
 mod type_uuid_impl;
@@ -478,23 +490,29 @@ pub mod prelude {
     #[doc(hidden)]
     pub use crate::{
         reflect_trait, FromReflect, GetField, GetTupleStructField, Reflect, ReflectDeserialize,
-        ReflectSerialize, Struct, TupleStruct,
+        ReflectSerialize, Struct, TupleStruct, TypePath,
     };
 }
 
 pub use array::*;
+pub use attributes::*;
+pub use diff::*;
 pub use enums::*;
 pub use fields::*;
 pub use from_reflect::*;
+pub use function::*;
 pub use impls::*;
 pub use list::*;
 pub use map::*;
 pub use path::*;
 pub use reflect::*;
+pub use set::*;
 pub use struct_trait::*;
+pub use trait_cast::*;
 pub use tuple::*;
 pub use tuple_struct::*;
 pub use type_info::*;
+pub use type_path::*;
 pub use type_registry::*;
 pub use type_uuid::*;
 
@@ -976,6 +994,82 @@ mod tests {
         assert!(foo.reflect_partial_eq(&dynamic_struct).unwrap());
     }
 
+    #[test]
+    fn type_path() {
+        #[derive(Reflect)]
+        struct Foo;
+
+        #[derive(Reflect)]
+        #[type_path = "my_crate::things"]
+        #[type_name = "Renamed"]
+        enum Bar {
+            A,
+        }
+
+        #[derive(Reflect)]
+        struct Generic<T: Reflect, const N: usize>([T; N]);
+
+        assert_eq!(Foo::type_path(), "bevy_reflect::tests::Foo");
+        assert_eq!(Foo::short_type_path(), "Foo");
+        assert_eq!(Foo::crate_name(), Some("bevy_reflect"));
+        assert_eq!(Foo::module_path(), Some("bevy_reflect::tests"));
+
+        assert_eq!(Bar::type_path(), "my_crate::things::Renamed");
+        assert_eq!(Bar::type_ident(), Some("Renamed"));
+        assert_eq!(Bar::crate_name(), Some("my_crate"));
+
+        assert_eq!(
+            Generic::<Option<String>, 2>::type_path(),
+            "bevy_reflect::tests::Generic<core::option::Option<alloc::string::String>, 2>"
+        );
+        assert_eq!(
+            Generic::<Option<String>, 2>::short_type_path(),
+            "Generic<Option<String>, 2>"
+        );
+        assert_eq!(<(u8,)>::type_path(), "(u8,)");
+        assert_eq!(<(u8, Vec<f32>)>::short_type_path(), "(u8, Vec<f32>)");
+        assert_eq!(<()>::type_path(), "()");
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<Bar>();
+        let registration = registry
+            .get_with_type_path("my_crate::things::Renamed")
+            .unwrap();
+        assert_eq!(registration.type_id(), TypeId::of::<Bar>());
+        assert_eq!(registration.short_name(), "Renamed");
+    }
+
+    #[test]
+    fn reflect_serialize_type_path() {
+        #[derive(Reflect, Debug, PartialEq)]
+        #[type_path = "my_crate"]
+        struct Foo {
+            a: u32,
+        }
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<u32>();
+        registry.register::<Foo>();
+
+        let serializer = ReflectSerializer::new(&Foo { a: 1 }, &registry);
+        let serialized = ron::to_string(&serializer).unwrap();
+        assert_eq!(serialized, r#"{"my_crate::Foo":(a:1)}"#);
+
+        let mut deserializer = Deserializer::from_str(&serialized).unwrap();
+        let value = UntypedReflectDeserializer::new(&registry)
+            .deserialize(&mut deserializer)
+            .unwrap();
+        assert!(Foo { a: 1 }.reflect_partial_eq(&*value).unwrap());
+
+        // data written with the type name before type paths existed can still be read
+        let legacy = format!(r#"{{"{}":(a:2)}}"#, std::any::type_name::<Foo>());
+        let mut deserializer = Deserializer::from_str(&legacy).unwrap();
+        let value = UntypedReflectDeserializer::new(&registry)
+            .deserialize(&mut deserializer)
+            .unwrap();
+        assert!(Foo { a: 2 }.reflect_partial_eq(&*value).unwrap());
+    }
+
     #[test]
     fn reflect_downcast() {
         #[derive(Reflect, Clone, Debug, PartialEq)]
@@ -1655,7 +1749,7 @@ bevy_reflect::tests::should_reflect_debug::Test {
             let output = to_string_pretty(&ser, config).unwrap();
             let expected = r#"
 {
-    "glam::f32::vec3::Vec3": (
+    "glam::Vec3": (
         x: 12.0,
         y: 3.0,
         z: -6.9,
@@ -1669,7 +1763,7 @@ bevy_reflect::tests::should_reflect_debug::Test {
         fn vec3_deserialization() {
             let data = r#"
 {
-    "glam::f32::vec3::Vec3": (
+    "glam::Vec3": (
         x: 12.0,
         y: 3.0,
         z: -6.9,
@@ -29,6 +29,10 @@ pub enum ReflectPathError<'a> {
     },
     #[error("the current list doesn't have a value at the index {list_index}")]
     InvalidListIndex { index: usize, list_index: usize },
+    #[error("the current set doesn't have a value at the index {set_index}")]
+    InvalidSetIndex { index: usize, set_index: usize },
+    #[error("the values of a set can't be accessed mutably")]
+    ImmutableSetValue { index: usize },
     #[error("encountered an unexpected token `{token}`")]
     UnexpectedToken { index: usize, token: &'a str },
     #[error("expected token `{token}`, but it wasn't there.")]
@@ -118,6 +122,21 @@ pub enum ReflectPathError<'a> {
 /// assert_eq!(my_list.path::<u32>("[2]").unwrap(), &3);
 /// ```
 ///
+/// ## Sets
+///
+/// [`Set`] values can be read with the same syntax, in the iteration order of the set: `[0]`.
+/// Since changing a value could break the invariants of the set,
+/// they can't be accessed mutably.
+///
+/// ### Example
+/// ```
+/// # use bevy_reflect::{GetPath};
+/// # use std::collections::BTreeSet;
+/// let mut my_set = BTreeSet::from([3_u32, 1, 2]);
+/// assert_eq!(my_set.path::<u32>("[0]").unwrap(), &1);
+/// assert!(my_set.path_mut::<u32>("[0]").is_err());
+/// ```
+///
 /// ## Enums
 ///
 /// Pathing for [`Enum`] elements works a bit differently than in normal Rust.
@@ -185,6 +204,7 @@ pub enum ReflectPathError<'a> {
 /// [`TupleStruct`]: crate::TupleStruct
 /// [`List`]: crate::List
 /// [`Array`]: crate::Array
+/// [`Set`]: crate::Set
 /// [`Enum`]: crate::Enum
 pub trait GetPath {
     /// Returns a reference to the value specified by `path`.
@@ -535,6 +555,12 @@ impl<'a> AccessRef<'a> {
                     index: current_index,
                     list_index: *list_index,
                 }),
+            (Self::ListIndex(set_index), ReflectRef::Set(reflect_set)) => reflect_set
+                .get_at(*set_index)
+                .ok_or(ReflectPathError::InvalidSetIndex {
+                    index: current_index,
+                    set_index: *set_index,
+                }),
             (Self::ListIndex(_), _) => Err(ReflectPathError::ExpectedList {
                 index: current_index,
             }),
@@ -629,6 +655,9 @@ impl<'a> AccessRef<'a> {
                     index: current_index,
                     list_index: *list_index,
                 }),
+            (Self::ListIndex(_), ReflectMut::Set(_)) => Err(ReflectPathError::ImmutableSetValue {
+                index: current_index,
+            }),
             (Self::ListIndex(_), _) => Err(ReflectPathError::ExpectedList {
                 index: current_index,
             }),
@@ -0,0 +1,1022 @@
+use crate::serde::de::get_registration;
+use crate::serde::SerializationData;
+use crate::{
+    DynamicArray, DynamicEnum, DynamicList, DynamicMap, DynamicSet, DynamicStruct, DynamicTuple,
+    DynamicTupleStruct, DynamicVariant, EnumInfo, Map, Reflect, ReflectDeserialize, ReflectRef,
+    ReflectSerialize, Set, TypeInfo, TypeRegistration, TypeRegistry, VariantInfo,
+};
+use bevy_utils::HashMap;
+use serde::de::{self, DeserializeSeed, MapAccess, SeqAccess, Visitor};
+use serde::ser::{Error as _, SerializeMap, SerializeSeq, SerializeTuple};
+use serde::{Serialize, Serializer};
+use std::any::TypeId;
+use std::fmt::Formatter;
+use thiserror::Error;
+
+/// An error returned when building [`CompactTypeIds`] from a list of type paths.
+#[derive(Debug, PartialEq, Eq, Error)]
+pub enum CompactTypeIdsError {
+    #[error("no registration found for type path `{0}`")]
+    UnregisteredTypePath(String),
+    #[error("type path `{0}` was listed more than once")]
+    DuplicateTypePath(String),
+}
+
+/// A table of numeric ids used by the compact reflection format to identify registered types.
+///
+/// Both ends of a connection must agree on the same table. It can either be derived from
+/// identical [`TypeRegistry`]s using [`CompactTypeIds::from_registry`], or negotiated by having
+/// one side send its [`type_paths`] and the other side rebuild the table with
+/// [`CompactTypeIds::from_type_paths`].
+///
+/// [`type_paths`]: CompactTypeIds::type_paths
+#[derive(Debug, Clone, Default)]
+pub struct CompactTypeIds {
+    type_ids: Vec<TypeId>,
+    ids: HashMap<TypeId, u32>,
+}
+
+impl CompactTypeIds {
+    /// Creates an empty table.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Assigns an id to every type in the registry, ordered by [type path].
+    ///
+    /// Two registries containing the same types always produce the same table.
+    ///
+    /// [type path]: crate::TypePath
+    pub fn from_registry(registry: &TypeRegistry) -> Self {
+        let mut registrations = registry.iter().collect::<Vec<_>>();
+        registrations.sort_by_key(|registration| registration.type_path());
+
+        let mut type_ids = Self::new();
+        for registration in registrations {
+            type_ids.insert(registration.type_id());
+        }
+        type_ids
+    }
+
+    /// Rebuilds a table from the [type paths] sent by the other side of a connection.
+    ///
+    /// The id of each type is its position in `type_paths`.
+    ///
+    /// [type paths]: crate::TypePath
+    pub fn from_type_paths<'a>(
+        type_paths: impl IntoIterator<Item = &'a str>,
+        registry: &TypeRegistry,
+    ) -> Result<Self, CompactTypeIdsError> {
+        let mut type_ids = Self::new();
+        for type_path in type_paths {
+            let registration = registry
+                .get_with_type_path(type_path)
+                .ok_or_else(|| CompactTypeIdsError::UnregisteredTypePath(type_path.to_string()))?;
+            if type_ids.id(registration.type_id()).is_some() {
+                return Err(CompactTypeIdsError::DuplicateTypePath(
+                    type_path.to_string(),
+                ));
+            }
+            type_ids.insert(registration.type_id());
+        }
+        Ok(type_ids)
+    }
+
+    /// Returns the type paths of all types in the table, ordered by id.
+    ///
+    /// Types missing from `registry` are returned as an empty string.
+    pub fn type_paths(&self, registry: &TypeRegistry) -> Vec<&'static str> {
+        self.type_ids
+            .iter()
+            .map(|type_id| {
+                registry
+                    .get(*type_id)
+                    .map(|registration| registration.type_path())
+                    .unwrap_or_default()
+            })
+            .collect()
+    }
+
+    /// Adds a type to the table, returning its id.
+    ///
+    /// If the type is already present, its existing id is returned.
+    pub fn insert(&mut self, type_id: TypeId) -> u32 {
+        if let Some(id) = self.ids.get(&type_id) {
+            return *id;
+        }
+        let id = self.type_ids.len() as u32;
+        self.type_ids.push(type_id);
+        self.ids.insert(type_id, id);
+        id
+    }
+
+    /// Returns the id of the given type, if present.
+    pub fn id(&self, type_id: TypeId) -> Option<u32> {
+        self.ids.get(&type_id).copied()
+    }
+
+    /// Returns the type with the given id, if present.
+    pub fn type_id(&self, id: u32) -> Option<TypeId> {
+        self.type_ids.get(id as usize).copied()
+    }
+
+    /// Returns the number of types in the table.
+    pub fn len(&self) -> usize {
+        self.type_ids.len()
+    }
+
+    /// Returns true if the table contains no types.
+    pub fn is_empty(&self) -> bool {
+        self.type_ids.is_empty()
+    }
+}
+
+/// A serializer for reflected types using a compact, non-self-describing layout.
+///
+/// This is intended for binary formats such as `bincode`, where the map produced by
+/// [`ReflectSerializer`] is dominated by type and field names.
+/// The serialized data takes the form of a tuple containing:
+/// 1. The id of the value's type in the given [`CompactTypeIds`]
+/// 2. The value, serialized with [`TypedCompactReflectSerializer`]
+///
+/// [`ReflectSerializer`]: crate::serde::ReflectSerializer
+pub struct CompactReflectSerializer<'a> {
+    pub value: &'a dyn Reflect,
+    pub registry: &'a TypeRegistry,
+    pub type_ids: &'a CompactTypeIds,
+}
+
+impl<'a> CompactReflectSerializer<'a> {
+    pub fn new(
+        value: &'a dyn Reflect,
+        registry: &'a TypeRegistry,
+        type_ids: &'a CompactTypeIds,
+    ) -> Self {
+        Self {
+            value,
+            registry,
+            type_ids,
+        }
+    }
+}
+
+impl<'a> Serialize for CompactReflectSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let type_name = self.value.type_name();
+        let registration = self.registry.get_with_name(type_name).ok_or_else(|| {
+            S::Error::custom(format_args!("no registration found for type {type_name}"))
+        })?;
+        let id = self
+            .type_ids
+            .id(registration.type_id())
+            .ok_or_else(|| S::Error::custom(format_args!("no compact id for type {type_name}")))?;
+
+        let mut state = serializer.serialize_tuple(2)?;
+        state.serialize_element(&id)?;
+        state.serialize_element(&TypedCompactReflectSerializer::new(
+            self.value,
+            self.registry,
+        ))?;
+        state.end()
+    }
+}
+
+/// A serializer for reflected types whose type is known to the deserializing side.
+///
+/// Structs, tuple structs and enum variants are written as tuples of their serializable fields,
+/// in [`TypeInfo`] order, without field names.
+/// Enums are written as a tuple of the variant index and the variant's fields.
+/// Types with [`ReflectSerialize`] registered use their own [`Serialize`] implementation.
+pub struct TypedCompactReflectSerializer<'a> {
+    pub value: &'a dyn Reflect,
+    pub registry: &'a TypeRegistry,
+}
+
+impl<'a> TypedCompactReflectSerializer<'a> {
+    pub fn new(value: &'a dyn Reflect, registry: &'a TypeRegistry) -> Self {
+        Self { value, registry }
+    }
+
+    fn fields(&self, fields: Vec<&'a dyn Reflect>) -> CompactFieldsSerializer<'a> {
+        CompactFieldsSerializer {
+            fields,
+            registry: self.registry,
+        }
+    }
+
+    fn serializable_fields(
+        &self,
+        fields: impl Iterator<Item = &'a dyn Reflect>,
+    ) -> CompactFieldsSerializer<'a> {
+        let serialization_data = self
+            .registry
+            .get_with_name(self.value.type_name())
+            .and_then(|registration| registration.data::<SerializationData>());
+        self.fields(
+            fields
+                .enumerate()
+                .filter(|(index, _)| {
+                    !serialization_data
+                        .map(|data| data.is_ignored_field(*index))
+                        .unwrap_or(false)
+                })
+                .map(|(_, field)| field)
+                .collect(),
+        )
+    }
+}
+
+impl<'a> Serialize for TypedCompactReflectSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if let Some(reflect_serialize) = self
+            .registry
+            .get_type_data::<ReflectSerialize>(self.value.type_id())
+        {
+            return reflect_serialize
+                .get_serializable(self.value)
+                .borrow()
+                .serialize(serializer);
+        }
+
+        match self.value.reflect_ref() {
+            ReflectRef::Struct(value) => {
+                // fields are written in `TypeInfo` order, which dynamic structs may not follow
+                let Some(TypeInfo::Struct(struct_info)) = self
+                    .registry
+                    .get_with_name(value.type_name())
+                    .map(TypeRegistration::type_info)
+                else {
+                    return Err(S::Error::custom(format_args!(
+                        "no struct registration found for type {}",
+                        value.type_name()
+                    )));
+                };
+                let fields = struct_info
+                    .iter()
+                    .map(|field| {
+                        value.field(field.name()).ok_or_else(|| {
+                            S::Error::custom(format_args!(
+                                "missing field `{}` of type {}",
+                                field.name(),
+                                value.type_name()
+                            ))
+                        })
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                self.serializable_fields(fields.into_iter())
+                    .serialize(serializer)
+            }
+            ReflectRef::TupleStruct(value) => self
+                .serializable_fields(value.iter_fields())
+                .serialize(serializer),
+            ReflectRef::Tuple(value) => self
+                .fields(value.iter_fields().collect())
+                .serialize(serializer),
+            ReflectRef::Array(value) => self.fields(value.iter().collect()).serialize(serializer),
+            ReflectRef::List(value) => {
+                let mut state = serializer.serialize_seq(Some(value.len()))?;
+                for item in value.iter() {
+                    state.serialize_element(&TypedCompactReflectSerializer::new(
+                        item,
+                        self.registry,
+                    ))?;
+                }
+                state.end()
+            }
+            ReflectRef::Set(value) => {
+                let mut state = serializer.serialize_seq(Some(value.len()))?;
+                for item in value.iter() {
+                    state.serialize_element(&TypedCompactReflectSerializer::new(
+                        item,
+                        self.registry,
+                    ))?;
+                }
+                state.end()
+            }
+            ReflectRef::Map(value) => {
+                let mut state = serializer.serialize_map(Some(value.len()))?;
+                for (key, value) in value.iter() {
+                    state.serialize_entry(
+                        &TypedCompactReflectSerializer::new(key, self.registry),
+                        &TypedCompactReflectSerializer::new(value, self.registry),
+                    )?;
+                }
+                state.end()
+            }
+            ReflectRef::Enum(value) => {
+                let mut state = serializer.serialize_tuple(2)?;
+                state.serialize_element(&(value.variant_index() as u32))?;
+                state.serialize_element(
+                    &self.fields(value.iter_fields().map(|field| field.value()).collect()),
+                )?;
+                state.end()
+            }
+            ReflectRef::Value(value) => Err(S::Error::custom(format_args!(
+                "Type '{}' did not register ReflectSerialize",
+                value.type_name()
+            ))),
+        }
+    }
+}
+
+struct CompactFieldsSerializer<'a> {
+    fields: Vec<&'a dyn Reflect>,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> Serialize for CompactFieldsSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_tuple(self.fields.len())?;
+        for field in &self.fields {
+            state.serialize_element(&TypedCompactReflectSerializer::new(*field, self.registry))?;
+        }
+        state.end()
+    }
+}
+
+/// A deserializer for data written by [`CompactReflectSerializer`].
+///
+/// Like [`UntypedReflectDeserializer`], this returns a [`Box<dyn Reflect>`] containing the
+/// dynamic equivalent of the serialized value, or the actual value for value types.
+///
+/// [`UntypedReflectDeserializer`]: crate::serde::UntypedReflectDeserializer
+/// [`Box<dyn Reflect>`]: crate::Reflect
+pub struct CompactReflectDeserializer<'a> {
+    registry: &'a TypeRegistry,
+    type_ids: &'a CompactTypeIds,
+}
+
+impl<'a> CompactReflectDeserializer<'a> {
+    pub fn new(registry: &'a TypeRegistry, type_ids: &'a CompactTypeIds) -> Self {
+        Self { registry, type_ids }
+    }
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for CompactReflectDeserializer<'a> {
+    type Value = Box<dyn Reflect>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_tuple(2, self)
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for CompactReflectDeserializer<'a> {
+    type Value = Box<dyn Reflect>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        formatter.write_str("tuple containing the compact type id and the reflected value")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let id: u32 = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        let type_id = self
+            .type_ids
+            .type_id(id)
+            .ok_or_else(|| de::Error::custom(format_args!("unknown compact type id {id}")))?;
+        let registration =
+            get_registration(type_id, &format!("with compact id {id}"), self.registry)?;
+        seq.next_element_seed(TypedCompactReflectDeserializer::new(
+            registration,
+            self.registry,
+        ))?
+        .ok_or_else(|| de::Error::invalid_length(1, &self))
+    }
+}
+
+/// A deserializer for data written by [`TypedCompactReflectSerializer`].
+pub struct TypedCompactReflectDeserializer<'a> {
+    registration: &'a TypeRegistration,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> TypedCompactReflectDeserializer<'a> {
+    pub fn new(registration: &'a TypeRegistration, registry: &'a TypeRegistry) -> Self {
+        Self {
+            registration,
+            registry,
+        }
+    }
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for TypedCompactReflectDeserializer<'a> {
+    type Value = Box<dyn Reflect>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let type_name = self.registration.type_name();
+        let registry = self.registry;
+
+        if let Some(deserialize_reflect) = self.registration.data::<ReflectDeserialize>() {
+            return deserialize_reflect.deserialize(deserializer);
+        }
+
+        let serialization_data = self.registration.data::<SerializationData>();
+        let is_serialized = |index: usize| {
+            !serialization_data
+                .map(|data| data.is_ignored_field(index))
+                .unwrap_or(false)
+        };
+
+        match self.registration.type_info() {
+            TypeInfo::Struct(struct_info) => {
+                let fields = struct_info
+                    .iter()
+                    .enumerate()
+                    .filter(|(index, _)| is_serialized(*index))
+                    .map(|(_, field)| field)
+                    .collect::<Vec<_>>();
+                let values = CompactFieldsDeserializer::new(
+                    fields
+                        .iter()
+                        .map(|field| (field.type_id(), field.type_name())),
+                    registry,
+                )?
+                .deserialize(deserializer)?;
+
+                let mut dynamic_struct = DynamicStruct::default();
+                for (field, value) in fields.into_iter().zip(values) {
+                    dynamic_struct.insert_boxed(field.name(), value);
+                }
+                dynamic_struct.set_name(struct_info.type_name().to_string());
+                Ok(Box::new(dynamic_struct))
+            }
+            TypeInfo::TupleStruct(tuple_struct_info) => {
+                let values = CompactFieldsDeserializer::new(
+                    tuple_struct_info
+                        .iter()
+                        .enumerate()
+                        .filter(|(index, _)| is_serialized(*index))
+                        .map(|(_, field)| (field.type_id(), field.type_name())),
+                    registry,
+                )?
+                .deserialize(deserializer)?;
+
+                let mut dynamic_tuple_struct = DynamicTupleStruct::default();
+                for value in values {
+                    dynamic_tuple_struct.insert_boxed(value);
+                }
+                dynamic_tuple_struct.set_name(tuple_struct_info.type_name().to_string());
+                Ok(Box::new(dynamic_tuple_struct))
+            }
+            TypeInfo::Tuple(tuple_info) => {
+                let values = CompactFieldsDeserializer::new(
+                    tuple_info
+                        .iter()
+                        .map(|field| (field.type_id(), field.type_name())),
+                    registry,
+                )?
+                .deserialize(deserializer)?;
+
+                let mut dynamic_tuple = DynamicTuple::default();
+                for value in values {
+                    dynamic_tuple.insert_boxed(value);
+                }
+                dynamic_tuple.set_name(tuple_info.type_name().to_string());
+                Ok(Box::new(dynamic_tuple))
+            }
+            TypeInfo::Array(array_info) => {
+                let values = CompactFieldsDeserializer::new(
+                    (0..array_info.capacity())
+                        .map(|_| (array_info.item_type_id(), array_info.item_type_name())),
+                    registry,
+                )?
+                .deserialize(deserializer)?;
+
+                let mut dynamic_array = DynamicArray::new(values.into_boxed_slice());
+                dynamic_array.set_name(array_info.type_name().to_string());
+                Ok(Box::new(dynamic_array))
+            }
+            TypeInfo::List(list_info) => {
+                let registration = get_registration(
+                    list_info.item_type_id(),
+                    list_info.item_type_name(),
+                    registry,
+                )?;
+                let values = deserializer.deserialize_seq(CompactSeqVisitor {
+                    registration,
+                    registry,
+                })?;
+
+                let mut dynamic_list = DynamicList::default();
+                for value in values {
+                    dynamic_list.push_box(value);
+                }
+                dynamic_list.set_name(list_info.type_name().to_string());
+                Ok(Box::new(dynamic_list))
+            }
+            TypeInfo::Set(set_info) => {
+                let registration = get_registration(
+                    set_info.value_type_id(),
+                    set_info.value_type_name(),
+                    registry,
+                )?;
+                let values = deserializer.deserialize_seq(CompactSeqVisitor {
+                    registration,
+                    registry,
+                })?;
+
+                let mut dynamic_set = DynamicSet::default();
+                for value in values {
+                    dynamic_set.insert_boxed(value);
+                }
+                dynamic_set.set_name(set_info.type_name().to_string());
+                Ok(Box::new(dynamic_set))
+            }
+            TypeInfo::Map(map_info) => {
+                let key_registration =
+                    get_registration(map_info.key_type_id(), map_info.key_type_name(), registry)?;
+                let value_registration = get_registration(
+                    map_info.value_type_id(),
+                    map_info.value_type_name(),
+                    registry,
+                )?;
+                let mut dynamic_map = deserializer.deserialize_map(CompactMapVisitor {
+                    key_registration,
+                    value_registration,
+                    registry,
+                })?;
+                dynamic_map.set_name(map_info.type_name().to_string());
+                Ok(Box::new(dynamic_map))
+            }
+            TypeInfo::Enum(enum_info) => {
+                let dynamic_enum = deserializer.deserialize_tuple(
+                    2,
+                    CompactEnumVisitor {
+                        enum_info,
+                        registry,
+                    },
+                )?;
+                Ok(Box::new(dynamic_enum))
+            }
+            TypeInfo::Value(_) => Err(de::Error::custom(format_args!(
+                "the TypeRegistration for {type_name} doesn't have ReflectDeserialize",
+            ))),
+            TypeInfo::Dynamic(_) => Err(de::Error::custom(format_args!(
+                "cannot deserialize arbitrary dynamic type {type_name}",
+            ))),
+        }
+    }
+}
+
+/// Deserializes a tuple of values whose types are known ahead of time.
+struct CompactFieldsDeserializer<'a> {
+    registrations: Vec<&'a TypeRegistration>,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> CompactFieldsDeserializer<'a> {
+    fn new<E: de::Error>(
+        fields: impl Iterator<Item = (TypeId, &'a str)>,
+        registry: &'a TypeRegistry,
+    ) -> Result<Self, E> {
+        let registrations = fields
+            .map(|(type_id, type_name)| get_registration(type_id, type_name, registry))
+            .collect::<Result<_, _>>()?;
+        Ok(Self {
+            registrations,
+            registry,
+        })
+    }
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for CompactFieldsDeserializer<'a> {
+    type Value = Vec<Box<dyn Reflect>>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_tuple(self.registrations.len(), self)
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for CompactFieldsDeserializer<'a> {
+    type Value = Vec<Box<dyn Reflect>>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        write!(
+            formatter,
+            "tuple of {} reflected fields",
+            self.registrations.len()
+        )
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut values = Vec::with_capacity(self.registrations.len());
+        for (index, registration) in self.registrations.iter().enumerate() {
+            let value = seq
+                .next_element_seed(TypedCompactReflectDeserializer::new(
+                    registration,
+                    self.registry,
+                ))?
+                .ok_or_else(|| de::Error::invalid_length(index, &self))?;
+            values.push(value);
+        }
+        Ok(values)
+    }
+}
+
+struct CompactSeqVisitor<'a> {
+    registration: &'a TypeRegistration,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> Visitor<'de> for CompactSeqVisitor<'a> {
+    type Value = Vec<Box<dyn Reflect>>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        formatter.write_str("sequence of reflected values")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut values = Vec::with_capacity(seq.size_hint().unwrap_or_default());
+        while let Some(value) = seq.next_element_seed(TypedCompactReflectDeserializer::new(
+            self.registration,
+            self.registry,
+        ))? {
+            values.push(value);
+        }
+        Ok(values)
+    }
+}
+
+struct CompactMapVisitor<'a> {
+    key_registration: &'a TypeRegistration,
+    value_registration: &'a TypeRegistration,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> Visitor<'de> for CompactMapVisitor<'a> {
+    type Value = DynamicMap;
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        formatter.write_str("reflected map value")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut dynamic_map = DynamicMap::default();
+        while let Some(key) = map.next_key_seed(TypedCompactReflectDeserializer::new(
+            self.key_registration,
+            self.registry,
+        ))? {
+            let value = map.next_value_seed(TypedCompactReflectDeserializer::new(
+                self.value_registration,
+                self.registry,
+            ))?;
+            dynamic_map.insert_boxed(key, value);
+        }
+        Ok(dynamic_map)
+    }
+}
+
+struct CompactEnumVisitor<'a> {
+    enum_info: &'static EnumInfo,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> Visitor<'de> for CompactEnumVisitor<'a> {
+    type Value = DynamicEnum;
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        formatter.write_str("tuple containing the variant index and the variant's fields")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let variant_index: u32 = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        let variant_info = self
+            .enum_info
+            .variant_at(variant_index as usize)
+            .ok_or_else(|| {
+                de::Error::custom(format_args!(
+                    "no variant at index {variant_index} on enum {}",
+                    self.enum_info.type_name()
+                ))
+            })?;
+
+        let variant: DynamicVariant = match variant_info {
+            VariantInfo::Unit(..) => {
+                seq.next_element_seed(CompactFieldsDeserializer::new(
+                    std::iter::empty(),
+                    self.registry,
+                )?)?
+                .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+                DynamicVariant::Unit
+            }
+            VariantInfo::Struct(struct_info) => {
+                let values = seq
+                    .next_element_seed(CompactFieldsDeserializer::new(
+                        struct_info
+                            .iter()
+                            .map(|field| (field.type_id(), field.type_name())),
+                        self.registry,
+                    )?)?
+                    .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+                let mut dynamic_struct = DynamicStruct::default();
+                for (field, value) in struct_info.iter().zip(values) {
+                    dynamic_struct.insert_boxed(field.name(), value);
+                }
+                dynamic_struct.into()
+            }
+            VariantInfo::Tuple(tuple_info) => {
+                let values = seq
+                    .next_element_seed(CompactFieldsDeserializer::new(
+                        tuple_info
+                            .iter()
+                            .map(|field| (field.type_id(), field.type_name())),
+                        self.registry,
+                    )?)?
+                    .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+                let mut dynamic_tuple = DynamicTuple::default();
+                for value in values {
+                    dynamic_tuple.insert_boxed(value);
+                }
+                dynamic_tuple.into()
+            }
+        };
+
+        Ok(DynamicEnum::new_with_index(
+            self.enum_info.type_name(),
+            variant_index as usize,
+            variant_info.name(),
+            variant,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate as bevy_reflect;
+    use crate::serde::{
+        CompactReflectDeserializer, CompactReflectSerializer, CompactTypeIds, CompactTypeIdsError,
+        ReflectSerializer,
+    };
+    use crate::{DynamicStruct, FromReflect, Reflect, TypeRegistry};
+    use bevy_utils::{HashMap, HashSet};
+    use bincode::Options;
+    use serde::de::DeserializeSeed;
+
+    #[derive(Reflect, FromReflect, Debug, PartialEq)]
+    struct MyStruct {
+        primitive_value: i8,
+        option_value: Option<String>,
+        option_value_complex: Option<SomeStruct>,
+        tuple_value: (f32, usize),
+        list_value: Vec<i32>,
+        array_value: [i32; 5],
+        map_value: HashMap<u8, usize>,
+        set_value: HashSet<u32>,
+        struct_value: SomeStruct,
+        tuple_struct_value: SomeTupleStruct,
+        unit_struct: SomeUnitStruct,
+        unit_enum: SomeEnum,
+        newtype_enum: SomeEnum,
+        tuple_enum: SomeEnum,
+        struct_enum: SomeEnum,
+        #[reflect(skip_serializing)]
+        #[reflect(default)]
+        skipped: u64,
+        ignored_struct: SomeIgnoredStruct,
+    }
+
+    #[derive(Reflect, FromReflect, Debug, PartialEq)]
+    struct SomeStruct {
+        foo: i64,
+    }
+
+    #[derive(Reflect, FromReflect, Debug, PartialEq)]
+    struct SomePairStruct {
+        foo: i64,
+        bar: String,
+    }
+
+    #[derive(Reflect, FromReflect, Debug, PartialEq)]
+    struct SomeTupleStruct(String);
+
+    #[derive(Reflect, FromReflect, Debug, PartialEq)]
+    struct SomeUnitStruct;
+
+    #[derive(Reflect, FromReflect, Debug, PartialEq)]
+    struct SomeIgnoredStruct {
+        #[reflect(ignore)]
+        ignored: i32,
+    }
+
+    #[derive(Reflect, FromReflect, Debug, PartialEq)]
+    enum SomeEnum {
+        Unit,
+        NewType(usize),
+        Tuple(f32, f32),
+        Struct { foo: String },
+    }
+
+    fn get_registry() -> TypeRegistry {
+        let mut registry = TypeRegistry::default();
+        registry.register::<MyStruct>();
+        registry.register::<SomeStruct>();
+        registry.register::<SomePairStruct>();
+        registry.register::<SomeTupleStruct>();
+        registry.register::<SomeUnitStruct>();
+        registry.register::<SomeIgnoredStruct>();
+        registry.register::<SomeEnum>();
+        registry.register::<String>();
+        registry.register::<Option<String>>();
+        registry.register::<Option<SomeStruct>>();
+        registry.register::<(f32, usize)>();
+        registry.register::<Vec<i32>>();
+        registry.register::<[i32; 5]>();
+        registry.register::<HashMap<u8, usize>>();
+        registry.register::<HashSet<u32>>();
+        registry
+    }
+
+    fn get_my_struct() -> MyStruct {
+        let mut map = HashMap::new();
+        map.insert(64, 32);
+
+        MyStruct {
+            primitive_value: 123,
+            option_value: Some(String::from("Hello world!")),
+            option_value_complex: Some(SomeStruct { foo: 123 }),
+            tuple_value: (std::f32::consts::PI, 1337),
+            list_value: vec![-2, -1, 0, 1, 2],
+            array_value: [-2, -1, 0, 1, 2],
+            map_value: map,
+            set_value: HashSet::from_iter([1, 2, 3]),
+            struct_value: SomeStruct { foo: 999999999 },
+            tuple_struct_value: SomeTupleStruct(String::from("Tuple Struct")),
+            unit_struct: SomeUnitStruct,
+            unit_enum: SomeEnum::Unit,
+            newtype_enum: SomeEnum::NewType(123),
+            tuple_enum: SomeEnum::Tuple(1.23, 3.21),
+            struct_enum: SomeEnum::Struct {
+                foo: String::from("Struct variant value"),
+            },
+            skipped: 0,
+            ignored_struct: SomeIgnoredStruct { ignored: 0 },
+        }
+    }
+
+    #[test]
+    fn should_roundtrip_bincode() {
+        let registry = get_registry();
+        let type_ids = CompactTypeIds::from_registry(&registry);
+        let input = get_my_struct();
+
+        let serializer = CompactReflectSerializer::new(&input, &registry, &type_ids);
+        let bytes = bincode::serialize(&serializer).unwrap();
+
+        let reflect_deserializer = CompactReflectDeserializer::new(&registry, &type_ids);
+        let dynamic_output = bincode::DefaultOptions::new()
+            .with_fixint_encoding()
+            .deserialize_seed(reflect_deserializer, &bytes)
+            .unwrap();
+
+        let output = <MyStruct as FromReflect>::from_reflect(dynamic_output.as_ref()).unwrap();
+        assert_eq!(input, output);
+
+        let full_bytes = bincode::serialize(&ReflectSerializer::new(&input, &registry)).unwrap();
+        assert!(bytes.len() < full_bytes.len());
+    }
+
+    #[test]
+    fn should_roundtrip_rmp() {
+        let registry = get_registry();
+        let type_ids = CompactTypeIds::from_registry(&registry);
+        let input = get_my_struct();
+
+        let serializer = CompactReflectSerializer::new(&input, &registry, &type_ids);
+        let bytes = rmp_serde::to_vec(&serializer).unwrap();
+
+        let reflect_deserializer = CompactReflectDeserializer::new(&registry, &type_ids);
+        let mut deserializer = rmp_serde::Deserializer::new(bytes.as_slice());
+        let dynamic_output = reflect_deserializer.deserialize(&mut deserializer).unwrap();
+
+        let output = <MyStruct as FromReflect>::from_reflect(dynamic_output.as_ref()).unwrap();
+        assert_eq!(input, output);
+
+        // Field names are never written
+        let haystack = String::from_utf8_lossy(&bytes);
+        assert!(!haystack.contains("primitive_value"));
+        assert!(!haystack.contains("MyStruct"));
+    }
+
+    #[test]
+    fn should_serialize_struct_positionally() {
+        let registry = get_registry();
+        let mut type_ids = CompactTypeIds::new();
+        let id = type_ids.insert(std::any::TypeId::of::<SomeStruct>());
+
+        let input = SomeStruct { foo: 5 };
+        let serializer = CompactReflectSerializer::new(&input, &registry, &type_ids);
+        let bytes = bincode::serialize(&serializer).unwrap();
+
+        let expected = bincode::serialize(&(id, 5i64)).unwrap();
+        assert_eq!(expected, bytes);
+    }
+
+    #[test]
+    fn should_negotiate_type_ids() {
+        let registry = get_registry();
+        let local = CompactTypeIds::from_registry(&registry);
+
+        let type_paths = local.type_paths(&registry);
+        let remote = CompactTypeIds::from_type_paths(type_paths, &registry).unwrap();
+
+        assert_eq!(local.len(), remote.len());
+        for registration in registry.iter() {
+            assert_eq!(
+                local.id(registration.type_id()),
+                remote.id(registration.type_id())
+            );
+        }
+
+        let result = CompactTypeIds::from_type_paths(["does::not::Exist"], &registry);
+        assert_eq!(
+            Err(CompactTypeIdsError::UnregisteredTypePath(
+                "does::not::Exist".to_string()
+            )),
+            result.map(|_| ())
+        );
+    }
+
+    #[test]
+    fn should_fail_on_unknown_type_id() {
+        let registry = get_registry();
+        let type_ids = CompactTypeIds::from_registry(&registry);
+
+        let bytes = bincode::serialize(&(u32::MAX, 5i64)).unwrap();
+        let reflect_deserializer = CompactReflectDeserializer::new(&registry, &type_ids);
+        let result = bincode::DefaultOptions::new()
+            .with_fixint_encoding()
+            .deserialize_seed(reflect_deserializer, &bytes);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn should_serialize_dynamic_struct_in_type_info_order() {
+        let registry = get_registry();
+        let type_ids = CompactTypeIds::from_registry(&registry);
+
+        let mut input = DynamicStruct::default();
+        input.set_name(std::any::type_name::<SomePairStruct>().to_string());
+        input.insert("bar", String::from("Hello world!"));
+        input.insert("foo", 123i64);
+
+        let serializer = CompactReflectSerializer::new(&input, &registry, &type_ids);
+        let bytes = bincode::serialize(&serializer).unwrap();
+        let reflect_deserializer = CompactReflectDeserializer::new(&registry, &type_ids);
+        let dynamic_output = bincode::DefaultOptions::new()
+            .with_fixint_encoding()
+            .deserialize_seed(reflect_deserializer, &bytes)
+            .unwrap();
+
+        let output = <SomePairStruct as FromReflect>::from_reflect(dynamic_output.as_ref());
+        assert_eq!(
+            Some(SomePairStruct {
+                foo: 123,
+                bar: String::from("Hello world!"),
+            }),
+            output
+        );
+
+        // fields missing from the dynamic struct can't be written
+        let mut input = DynamicStruct::default();
+        input.set_name(std::any::type_name::<SomePairStruct>().to_string());
+        input.insert("foo", 123i64);
+        let serializer = CompactReflectSerializer::new(&input, &registry, &type_ids);
+        assert!(bincode::serialize(&serializer).is_err());
+    }
+}
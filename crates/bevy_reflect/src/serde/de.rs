@@ -1,10 +1,10 @@
 use crate::serde::SerializationData;
 use crate::{
-    ArrayInfo, DynamicArray, DynamicEnum, DynamicList, DynamicMap, DynamicStruct, DynamicTuple,
-    DynamicTupleStruct, DynamicVariant, EnumInfo, ListInfo, Map, MapInfo, NamedField, Reflect,
-    ReflectDeserialize, StructInfo, StructVariantInfo, Tuple, TupleInfo, TupleStruct,
-    TupleStructInfo, TupleVariantInfo, TypeInfo, TypeRegistration, TypeRegistry, UnnamedField,
-    VariantInfo,
+    ArrayInfo, DynamicArray, DynamicEnum, DynamicList, DynamicMap, DynamicSet, DynamicStruct,
+    DynamicTuple, DynamicTupleStruct, DynamicVariant, EnumInfo, ListInfo, Map, MapInfo, NamedField,
+    Reflect, ReflectDeserialize, Set, SetInfo, StructInfo, StructVariantInfo, Tuple, TupleInfo,
+    TupleStruct, TupleStructInfo, TupleVariantInfo, TypeInfo, TypeRegistration, TypeRegistry,
+    UnnamedField, VariantInfo,
 };
 use erased_serde::Deserializer;
 use serde::de::{
@@ -260,10 +260,13 @@ impl<'a, 'de> DeserializeSeed<'de> for UntypedReflectDeserializer<'a> {
 /// A deserializer for type registrations.
 ///
 /// This will return a [`&TypeRegistration`] corresponding to the given type.
-/// This deserializer expects a string containing the _full_ [type name] of the
+/// This deserializer expects a string containing the [type path] of the
 /// type to find the `TypeRegistration` of.
+/// For compatibility with data serialized before type paths existed, the _full_ [type name]
+/// of the type is accepted as well.
 ///
 /// [`&TypeRegistration`]: crate::TypeRegistration
+/// [type path]: crate::TypePath
 /// [type name]: std::any::type_name
 pub struct TypeRegistrationDeserializer<'a> {
     registry: &'a TypeRegistry,
@@ -291,13 +294,16 @@ impl<'a, 'de> DeserializeSeed<'de> for TypeRegistrationDeserializer<'a> {
                 formatter.write_str("string containing `type` entry for the reflected value")
             }
 
-            fn visit_str<E>(self, type_name: &str) -> Result<Self::Value, E>
+            fn visit_str<E>(self, type_path: &str) -> Result<Self::Value, E>
             where
                 E: Error,
             {
-                self.0.get_with_name(type_name).ok_or_else(|| {
-                    Error::custom(format_args!("No registration found for `{type_name}`"))
-                })
+                self.0
+                    .get_with_type_path(type_path)
+                    .or_else(|| self.0.get_with_name(type_path))
+                    .ok_or_else(|| {
+                        Error::custom(format_args!("No registration found for `{type_path}`"))
+                    })
             }
         }
 
@@ -432,6 +438,14 @@ impl<'a, 'de> DeserializeSeed<'de> for TypedReflectDeserializer<'a> {
                 dynamic_map.set_name(map_info.type_name().to_string());
                 Ok(Box::new(dynamic_map))
             }
+            TypeInfo::Set(set_info) => {
+                let mut dynamic_set = deserializer.deserialize_seq(SetVisitor {
+                    set_info,
+                    registry: self.registry,
+                })?;
+                dynamic_set.set_name(set_info.type_name().to_string());
+                Ok(Box::new(dynamic_set))
+            }
             TypeInfo::Tuple(tuple_info) => {
                 let mut dynamic_tuple = deserializer.deserialize_tuple(
                     tuple_info.field_len(),
@@ -745,6 +759,39 @@ impl<'a, 'de> Visitor<'de> for MapVisitor<'a> {
     }
 }
 
+struct SetVisitor<'a> {
+    set_info: &'static SetInfo,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> Visitor<'de> for SetVisitor<'a> {
+    type Value = DynamicSet;
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        formatter.write_str("reflected set value")
+    }
+
+    fn visit_seq<V>(self, mut seq: V) -> Result<Self::Value, V::Error>
+    where
+        V: SeqAccess<'de>,
+    {
+        let mut dynamic_set = DynamicSet::default();
+        let registration = get_registration(
+            self.set_info.value_type_id(),
+            self.set_info.value_type_name(),
+            self.registry,
+        )?;
+        while let Some(value) = seq.next_element_seed(TypedReflectDeserializer {
+            registration,
+            registry: self.registry,
+        })? {
+            dynamic_set.insert_boxed(value);
+        }
+
+        Ok(dynamic_set)
+    }
+}
+
 struct EnumVisitor<'a> {
     enum_info: &'static EnumInfo,
     registration: &'a TypeRegistration,
@@ -1072,7 +1119,7 @@ where
     Ok(tuple)
 }
 
-fn get_registration<'a, E: Error>(
+pub(super) fn get_registration<'a, E: Error>(
     type_id: TypeId,
     type_name: &str,
     registry: &'a TypeRegistry,
@@ -1092,7 +1139,7 @@ mod tests {
     use serde::de::DeserializeSeed;
     use serde::Deserialize;
 
-    use bevy_utils::HashMap;
+    use bevy_utils::{HashMap, HashSet};
 
     use crate as bevy_reflect;
     use crate::serde::{TypedReflectDeserializer, UntypedReflectDeserializer};
@@ -1335,6 +1382,27 @@ mod tests {
         assert_eq!(expected, output);
     }
 
+    #[test]
+    fn should_deserialize_set() {
+        let mut registry = get_registry();
+        registry.register::<HashSet<String>>();
+
+        let input = r#"{
+            "bevy_utils::HashSet<alloc::string::String>": ["foo", "bar"],
+        }"#;
+
+        let reflect_deserializer = UntypedReflectDeserializer::new(&registry);
+        let mut ron_deserializer = ron::de::Deserializer::from_str(input).unwrap();
+        let dynamic_output = reflect_deserializer
+            .deserialize(&mut ron_deserializer)
+            .unwrap();
+
+        let output =
+            <HashSet<String> as FromReflect>::from_reflect(dynamic_output.as_ref()).unwrap();
+        let expected = HashSet::from_iter([String::from("foo"), String::from("bar")]);
+        assert_eq!(expected, output);
+    }
+
     #[test]
     fn should_deserialize_option() {
         #[derive(Reflect, FromReflect, Debug, PartialEq)]
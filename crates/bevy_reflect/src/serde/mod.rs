@@ -1,8 +1,16 @@
+mod compact;
 mod de;
+mod patch;
+#[cfg(feature = "json_schema")]
+mod schema;
 mod ser;
 mod type_data;
 
+pub use compact::*;
 pub use de::*;
+pub use patch::*;
+#[cfg(feature = "json_schema")]
+pub use schema::*;
 pub use ser::*;
 pub use type_data::*;
 
@@ -10,10 +18,15 @@ pub use type_data::*;
 mod tests {
     use crate::{self as bevy_reflect, DynamicTupleStruct};
     use crate::{
-        serde::{ReflectSerializer, UntypedReflectDeserializer},
+        diff,
+        serde::{
+            ReflectPatchDeserializer, ReflectPatchSerializer, ReflectSerializer,
+            UntypedReflectDeserializer,
+        },
         type_registry::TypeRegistry,
-        DynamicStruct, Reflect,
+        DynamicStruct, FromReflect, Reflect,
     };
+    use bevy_utils::HashMap;
     use serde::de::DeserializeSeed;
 
     #[test]
@@ -92,4 +105,50 @@ mod tests {
             "Expected {expected:?} found {deserialized:?}"
         );
     }
+
+    #[test]
+    fn test_serialization_patch() {
+        #[derive(Debug, Reflect, FromReflect, PartialEq, Clone)]
+        struct TestStruct {
+            a: i32,
+            b: Vec<String>,
+            c: HashMap<u32, f32>,
+        }
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<TestStruct>();
+        registry.register::<i32>();
+        registry.register::<u32>();
+        registry.register::<f32>();
+        registry.register::<String>();
+
+        let old = TestStruct {
+            a: 1,
+            b: vec!["x".to_string()],
+            c: HashMap::from_iter([(1, 1.0)]),
+        };
+        let new = TestStruct {
+            a: 2,
+            b: vec!["x".to_string(), "y".to_string()],
+            c: HashMap::from_iter([(2, 2.0)]),
+        };
+        let patch = diff(&old, &new);
+
+        let serializer = ReflectPatchSerializer::new(&patch, &registry);
+        let serialized =
+            ron::ser::to_string_pretty(&serializer, ron::ser::PrettyConfig::default()).unwrap();
+
+        let mut deserializer = ron::de::Deserializer::from_str(&serialized).unwrap();
+        let deserialized = ReflectPatchDeserializer::new(&registry)
+            .deserialize(&mut deserializer)
+            .unwrap();
+        assert_eq!(deserialized.len(), patch.len());
+
+        let mut value = old.clone();
+        deserialized.apply(&mut value).unwrap();
+        assert_eq!(value, new);
+
+        deserialized.inverse().apply(&mut value).unwrap();
+        assert_eq!(value, old);
+    }
 }
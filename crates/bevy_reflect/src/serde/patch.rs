@@ -0,0 +1,458 @@
+use crate::serde::{ReflectSerializer, UntypedReflectDeserializer};
+use crate::{PatchOp, Reflect, ReflectPatch, TypeRegistry};
+use serde::de::{DeserializeSeed, EnumAccess, Error, MapAccess, SeqAccess, VariantAccess, Visitor};
+use serde::ser::{SerializeSeq, SerializeStructVariant};
+use serde::{Deserialize, Serialize, Serializer};
+use std::fmt::Formatter;
+
+const PATCH_OP: &str = "PatchOp";
+const VARIANTS: &[&str] = &[
+    "Replace",
+    "ListPush",
+    "ListPop",
+    "MapEntry",
+    "SetInsert",
+    "SetRemove",
+];
+
+/// A serializer for [`ReflectPatch`]es.
+///
+/// The patch is serialized as a sequence of [`PatchOp`] variants, whose values are serialized
+/// with [`ReflectSerializer`].
+/// The types of all the values in the patch must be registered in the [`TypeRegistry`].
+pub struct ReflectPatchSerializer<'a> {
+    pub patch: &'a ReflectPatch,
+    pub registry: &'a TypeRegistry,
+}
+
+impl<'a> ReflectPatchSerializer<'a> {
+    pub fn new(patch: &'a ReflectPatch, registry: &'a TypeRegistry) -> Self {
+        Self { patch, registry }
+    }
+}
+
+impl<'a> Serialize for ReflectPatchSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_seq(Some(self.patch.len()))?;
+        for op in self.patch.ops() {
+            state.serialize_element(&PatchOpSerializer {
+                op,
+                registry: self.registry,
+            })?;
+        }
+        state.end()
+    }
+}
+
+struct PatchOpSerializer<'a> {
+    op: &'a PatchOp,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> Serialize for PatchOpSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let reflect = |value: &'a dyn Reflect| ReflectSerializer::new(value, self.registry);
+        match self.op {
+            PatchOp::Replace { path, old, new } => {
+                let mut state = serializer.serialize_struct_variant(PATCH_OP, 0, VARIANTS[0], 3)?;
+                state.serialize_field("path", path)?;
+                state.serialize_field("old", &reflect(&**old))?;
+                state.serialize_field("new", &reflect(&**new))?;
+                state.end()
+            }
+            PatchOp::ListPush { path, value }
+            | PatchOp::ListPop { path, value }
+            | PatchOp::SetInsert { path, value }
+            | PatchOp::SetRemove { path, value } => {
+                let index = match self.op {
+                    PatchOp::ListPush { .. } => 1,
+                    PatchOp::ListPop { .. } => 2,
+                    PatchOp::SetInsert { .. } => 4,
+                    _ => 5,
+                };
+                let mut state = serializer.serialize_struct_variant(
+                    PATCH_OP,
+                    index,
+                    VARIANTS[index as usize],
+                    2,
+                )?;
+                state.serialize_field("path", path)?;
+                state.serialize_field("value", &reflect(&**value))?;
+                state.end()
+            }
+            PatchOp::MapEntry {
+                path,
+                key,
+                old,
+                new,
+            } => {
+                let mut state = serializer.serialize_struct_variant(PATCH_OP, 3, VARIANTS[3], 4)?;
+                state.serialize_field("path", path)?;
+                state.serialize_field("key", &reflect(&**key))?;
+                state.serialize_field("old", &old.as_deref().map(reflect))?;
+                state.serialize_field("new", &new.as_deref().map(reflect))?;
+                state.end()
+            }
+        }
+    }
+}
+
+/// A deserializer for [`ReflectPatch`]es serialized with
+/// [`ReflectPatchSerializer`](crate::serde::ReflectPatchSerializer).
+///
+/// Values are deserialized with [`UntypedReflectDeserializer`], so they will generally be
+/// dynamic types, which can still be applied to the patched value.
+pub struct ReflectPatchDeserializer<'a> {
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> ReflectPatchDeserializer<'a> {
+    pub fn new(registry: &'a TypeRegistry) -> Self {
+        Self { registry }
+    }
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for ReflectPatchDeserializer<'a> {
+    type Value = ReflectPatch;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for ReflectPatchDeserializer<'a> {
+    type Value = ReflectPatch;
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        formatter.write_str("sequence of patch operations")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut ops = Vec::with_capacity(seq.size_hint().unwrap_or_default());
+        while let Some(op) = seq.next_element_seed(PatchOpDeserializer {
+            registry: self.registry,
+        })? {
+            ops.push(op);
+        }
+        Ok(ReflectPatch::from_ops(ops))
+    }
+}
+
+#[derive(Clone, Copy)]
+enum PatchOpVariant {
+    Replace,
+    ListPush,
+    ListPop,
+    MapEntry,
+    SetInsert,
+    SetRemove,
+}
+
+impl<'de> Deserialize<'de> for PatchOpVariant {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct PatchOpVariantVisitor;
+
+        impl<'de> Visitor<'de> for PatchOpVariantVisitor {
+            type Value = PatchOpVariant;
+
+            fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+                formatter.write_str("patch operation variant")
+            }
+
+            fn visit_u64<E: Error>(self, index: u64) -> Result<Self::Value, E> {
+                match VARIANTS.get(index as usize) {
+                    Some(name) => self.visit_str(name),
+                    None => Err(Error::invalid_value(
+                        serde::de::Unexpected::Unsigned(index),
+                        &"variant index 0 <= i < 6",
+                    )),
+                }
+            }
+
+            fn visit_str<E: Error>(self, value: &str) -> Result<Self::Value, E> {
+                Ok(match value {
+                    "Replace" => PatchOpVariant::Replace,
+                    "ListPush" => PatchOpVariant::ListPush,
+                    "ListPop" => PatchOpVariant::ListPop,
+                    "MapEntry" => PatchOpVariant::MapEntry,
+                    "SetInsert" => PatchOpVariant::SetInsert,
+                    "SetRemove" => PatchOpVariant::SetRemove,
+                    _ => return Err(Error::unknown_variant(value, VARIANTS)),
+                })
+            }
+        }
+
+        deserializer.deserialize_identifier(PatchOpVariantVisitor)
+    }
+}
+
+#[derive(Clone, Copy)]
+enum PatchOpField {
+    Path,
+    Key,
+    Old,
+    New,
+    Value,
+}
+
+impl<'de> Deserialize<'de> for PatchOpField {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct PatchOpFieldVisitor;
+
+        impl<'de> Visitor<'de> for PatchOpFieldVisitor {
+            type Value = PatchOpField;
+
+            fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+                formatter.write_str("patch operation field")
+            }
+
+            fn visit_str<E: Error>(self, value: &str) -> Result<Self::Value, E> {
+                Ok(match value {
+                    "path" => PatchOpField::Path,
+                    "key" => PatchOpField::Key,
+                    "old" => PatchOpField::Old,
+                    "new" => PatchOpField::New,
+                    "value" => PatchOpField::Value,
+                    _ => {
+                        return Err(Error::unknown_field(
+                            value,
+                            &["path", "key", "old", "new", "value"],
+                        ))
+                    }
+                })
+            }
+        }
+
+        deserializer.deserialize_identifier(PatchOpFieldVisitor)
+    }
+}
+
+#[derive(Clone, Copy)]
+struct PatchOpDeserializer<'a> {
+    registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for PatchOpDeserializer<'a> {
+    type Value = PatchOp;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_enum(PATCH_OP, VARIANTS, self)
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for PatchOpDeserializer<'a> {
+    type Value = PatchOp;
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        formatter.write_str("patch operation")
+    }
+
+    fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+    where
+        A: EnumAccess<'de>,
+    {
+        let (variant, access) = data.variant::<PatchOpVariant>()?;
+        let fields: &[&str] = match variant {
+            PatchOpVariant::Replace => &["path", "old", "new"],
+            PatchOpVariant::ListPush
+            | PatchOpVariant::ListPop
+            | PatchOpVariant::SetInsert
+            | PatchOpVariant::SetRemove => &["path", "value"],
+            PatchOpVariant::MapEntry => &["path", "key", "old", "new"],
+        };
+        access.struct_variant(
+            fields,
+            PatchOpFieldsVisitor {
+                variant,
+                registry: self.registry,
+            },
+        )
+    }
+}
+
+struct PatchOpFieldsVisitor<'a> {
+    variant: PatchOpVariant,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> PatchOpFieldsVisitor<'a> {
+    fn build<E: Error>(self, fields: PatchOpFields) -> Result<PatchOp, E> {
+        let path = fields.path.ok_or_else(|| Error::missing_field("path"))?;
+        Ok(match self.variant {
+            PatchOpVariant::Replace => PatchOp::Replace {
+                path,
+                old: fields
+                    .old
+                    .flatten()
+                    .ok_or_else(|| Error::missing_field("old"))?,
+                new: fields
+                    .new
+                    .flatten()
+                    .ok_or_else(|| Error::missing_field("new"))?,
+            },
+            PatchOpVariant::ListPush => PatchOp::ListPush {
+                path,
+                value: fields.value.ok_or_else(|| Error::missing_field("value"))?,
+            },
+            PatchOpVariant::ListPop => PatchOp::ListPop {
+                path,
+                value: fields.value.ok_or_else(|| Error::missing_field("value"))?,
+            },
+            PatchOpVariant::SetInsert => PatchOp::SetInsert {
+                path,
+                value: fields.value.ok_or_else(|| Error::missing_field("value"))?,
+            },
+            PatchOpVariant::SetRemove => PatchOp::SetRemove {
+                path,
+                value: fields.value.ok_or_else(|| Error::missing_field("value"))?,
+            },
+            PatchOpVariant::MapEntry => PatchOp::MapEntry {
+                path,
+                key: fields.key.ok_or_else(|| Error::missing_field("key"))?,
+                old: fields.old.ok_or_else(|| Error::missing_field("old"))?,
+                new: fields.new.ok_or_else(|| Error::missing_field("new"))?,
+            },
+        })
+    }
+}
+
+#[derive(Default)]
+struct PatchOpFields {
+    path: Option<String>,
+    key: Option<Box<dyn Reflect>>,
+    old: Option<Option<Box<dyn Reflect>>>,
+    new: Option<Option<Box<dyn Reflect>>>,
+    value: Option<Box<dyn Reflect>>,
+}
+
+impl<'a, 'de> Visitor<'de> for PatchOpFieldsVisitor<'a> {
+    type Value = PatchOp;
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        formatter.write_str("patch operation fields")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let value = || UntypedReflectDeserializer::new(self.registry);
+        let optional_value = OptionalReflectDeserializer {
+            registry: self.registry,
+        };
+        let mut fields = PatchOpFields {
+            path: seq.next_element()?,
+            ..Default::default()
+        };
+        match self.variant {
+            PatchOpVariant::Replace => {
+                fields.old = Some(seq.next_element_seed(value())?);
+                fields.new = Some(seq.next_element_seed(value())?);
+            }
+            PatchOpVariant::ListPush
+            | PatchOpVariant::ListPop
+            | PatchOpVariant::SetInsert
+            | PatchOpVariant::SetRemove => {
+                fields.value = seq.next_element_seed(value())?;
+            }
+            PatchOpVariant::MapEntry => {
+                fields.key = seq.next_element_seed(value())?;
+                fields.old = seq.next_element_seed(optional_value)?;
+                fields.new = seq.next_element_seed(optional_value)?;
+            }
+        }
+        self.build(fields)
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let value = || UntypedReflectDeserializer::new(self.registry);
+        let optional_value = OptionalReflectDeserializer {
+            registry: self.registry,
+        };
+        let mut fields = PatchOpFields::default();
+        while let Some(field) = map.next_key()? {
+            match field {
+                PatchOpField::Path => fields.path = Some(map.next_value()?),
+                PatchOpField::Key => fields.key = Some(map.next_value_seed(value())?),
+                PatchOpField::Value => fields.value = Some(map.next_value_seed(value())?),
+                PatchOpField::Old | PatchOpField::New => {
+                    let slot = if matches!(field, PatchOpField::Old) {
+                        &mut fields.old
+                    } else {
+                        &mut fields.new
+                    };
+                    *slot = Some(match self.variant {
+                        PatchOpVariant::MapEntry => map.next_value_seed(optional_value)?,
+                        _ => Some(map.next_value_seed(value())?),
+                    });
+                }
+            }
+        }
+        self.build(fields)
+    }
+}
+
+/// Deserializes an `Option` of a value serialized with [`ReflectSerializer`].
+#[derive(Clone, Copy)]
+struct OptionalReflectDeserializer<'a> {
+    registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for OptionalReflectDeserializer<'a> {
+    type Value = Option<Box<dyn Reflect>>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_option(self)
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for OptionalReflectDeserializer<'a> {
+    type Value = Option<Box<dyn Reflect>>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        formatter.write_str("optional reflected value")
+    }
+
+    fn visit_none<E>(self) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        Ok(None)
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        UntypedReflectDeserializer::new(self.registry)
+            .deserialize(deserializer)
+            .map(Some)
+    }
+}
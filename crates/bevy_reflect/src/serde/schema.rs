@@ -0,0 +1,469 @@
+use crate::serde::{SerializationData, TypedReflectSerializer};
+use crate::std_traits::ReflectDefault;
+use crate::{ReflectSerialize, TypeInfo, TypeRegistration, TypeRegistry, VariantInfo};
+use serde_json::{json, Map, Value};
+use std::any::TypeId;
+use std::borrow::Cow;
+use std::path::PathBuf;
+
+/// The JSON Schema dialect emitted by [`JsonSchemaExporter`].
+pub const JSON_SCHEMA_DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+/// Exports the types of a [`TypeRegistry`] as [JSON Schema].
+///
+/// The schemas describe the exact JSON written by [`ReflectSerializer`] and
+/// [`TypedReflectSerializer`], so external tools can validate and author reflected data, such as
+/// scene files, without linking against the types themselves.
+///
+/// Every registered type gets a definition under `$defs`, keyed by its [type path].
+/// Fields refer to the definitions of their types with `$ref`; fields whose types are not
+/// registered accept any value.
+/// Types with a custom [`ReflectSerialize`] that aren't primitives also accept any value, since
+/// their format can't be inferred from their [`TypeInfo`].
+/// Types that register [`ReflectDefault`] include their default value.
+///
+/// [JSON Schema]: https://json-schema.org
+/// [`ReflectSerializer`]: crate::serde::ReflectSerializer
+/// [type path]: crate::TypePath
+pub struct JsonSchemaExporter<'a> {
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> JsonSchemaExporter<'a> {
+    /// Creates an exporter for the types registered in `registry`.
+    pub fn new(registry: &'a TypeRegistry) -> Self {
+        Self { registry }
+    }
+
+    /// Returns a schema document describing any value written by [`ReflectSerializer`].
+    ///
+    /// The root of the document accepts a single-entry object mapping the type path of a
+    /// registered type to a value of that type.
+    ///
+    /// [`ReflectSerializer`]: crate::serde::ReflectSerializer
+    pub fn export(&self) -> Value {
+        let mut definitions = Map::new();
+        let mut roots = Vec::new();
+        for registration in self.sorted_registrations() {
+            let type_path = registration.type_path();
+            definitions.insert(type_path.to_string(), self.type_schema(registration));
+            roots.push(json!({
+                "type": "object",
+                "properties": { type_path: { "$ref": definition_ref(type_path) } },
+                "required": [type_path],
+                "additionalProperties": false,
+            }));
+        }
+
+        json!({
+            "$schema": JSON_SCHEMA_DIALECT,
+            "oneOf": roots,
+            "$defs": definitions,
+        })
+    }
+
+    /// Returns the schema of a single type, as written by [`TypedReflectSerializer`].
+    ///
+    /// References to other types point into the `$defs` of the document returned by
+    /// [`JsonSchemaExporter::export`].
+    pub fn type_schema(&self, registration: &TypeRegistration) -> Value {
+        let mut schema = self.shape_schema(registration);
+
+        if let Value::Object(schema) = &mut schema {
+            schema.insert("title".to_string(), registration.short_name().into());
+            #[cfg(feature = "documentation")]
+            if let Some(docs) = registration.type_info().docs() {
+                schema.insert("description".to_string(), docs.trim().into());
+            }
+            if let Some(default) = self.default_value(registration) {
+                schema.insert("default".to_string(), default);
+            }
+        }
+
+        schema
+    }
+
+    fn sorted_registrations(&self) -> Vec<&'a TypeRegistration> {
+        let mut registrations = self.registry.iter().collect::<Vec<_>>();
+        registrations.sort_by_key(|registration| registration.type_path());
+        registrations
+    }
+
+    fn shape_schema(&self, registration: &TypeRegistration) -> Value {
+        if let Some(schema) = primitive_schema(registration.type_id()) {
+            return schema;
+        }
+        if registration.data::<ReflectSerialize>().is_some() {
+            return json!({});
+        }
+
+        match registration.type_info() {
+            TypeInfo::Struct(info) => {
+                let serialization_data = registration.data::<SerializationData>();
+                let mut properties = Map::new();
+                let mut required = Vec::new();
+                for (index, field) in info.iter().enumerate() {
+                    if serialization_data
+                        .map(|data| data.is_ignored_field(index))
+                        .unwrap_or(false)
+                    {
+                        continue;
+                    }
+                    properties.insert(field.name().to_string(), self.field_schema(field.type_id()));
+                    required.push(field.name());
+                }
+                json!({
+                    "type": "object",
+                    "properties": properties,
+                    "required": required,
+                    "additionalProperties": false,
+                })
+            }
+            TypeInfo::TupleStruct(info) => {
+                let serialization_data = registration.data::<SerializationData>();
+                let items = info
+                    .iter()
+                    .enumerate()
+                    .filter(|(index, _)| {
+                        !serialization_data
+                            .map(|data| data.is_ignored_field(*index))
+                            .unwrap_or(false)
+                    })
+                    .map(|(_, field)| self.field_schema(field.type_id()))
+                    .collect();
+                tuple_schema(items)
+            }
+            TypeInfo::Tuple(info) => tuple_schema(
+                info.iter()
+                    .map(|field| self.field_schema(field.type_id()))
+                    .collect(),
+            ),
+            TypeInfo::Array(info) => json!({
+                "type": "array",
+                "items": self.field_schema(info.item_type_id()),
+                "minItems": info.capacity(),
+                "maxItems": info.capacity(),
+            }),
+            TypeInfo::List(info) => json!({
+                "type": "array",
+                "items": self.field_schema(info.item_type_id()),
+            }),
+            TypeInfo::Set(info) => json!({
+                "type": "array",
+                "items": self.field_schema(info.value_type_id()),
+                "uniqueItems": true,
+            }),
+            TypeInfo::Map(info) => {
+                let mut schema = json!({
+                    "type": "object",
+                    "additionalProperties": self.field_schema(info.value_type_id()),
+                });
+                // JSON only has string keys, so integer keys are written as their decimal form
+                if matches!(
+                    primitive_schema(info.key_type_id()),
+                    Some(key) if key["type"] == "integer"
+                ) {
+                    schema["propertyNames"] = json!({ "pattern": "^-?[0-9]+$" });
+                }
+                schema
+            }
+            TypeInfo::Enum(info) => {
+                // `Option` is written as its inner value, or as `null` for `None`
+                if registration.type_path().starts_with("core::option::Option<") {
+                    let some = info
+                        .variant("Some")
+                        .and_then(|variant| match variant {
+                            VariantInfo::Tuple(variant) => variant.field_at(0),
+                            _ => None,
+                        })
+                        .map(|field| self.field_schema(field.type_id()))
+                        .unwrap_or_else(|| json!({}));
+                    return json!({ "oneOf": [{ "type": "null" }, some] });
+                }
+
+                let variants = info
+                    .iter()
+                    .map(|variant| self.variant_schema(variant))
+                    .collect::<Vec<_>>();
+                json!({ "oneOf": variants })
+            }
+            TypeInfo::Value(_) | TypeInfo::Dynamic(_) => json!({}),
+        }
+    }
+
+    fn variant_schema(&self, variant: &VariantInfo) -> Value {
+        let value = match variant {
+            VariantInfo::Unit(variant) => return json!({ "const": variant.name() }),
+            VariantInfo::Tuple(variant) if variant.field_len() == 1 => {
+                self.field_schema(variant.field_at(0).unwrap().type_id())
+            }
+            VariantInfo::Tuple(variant) => tuple_schema(
+                variant
+                    .iter()
+                    .map(|field| self.field_schema(field.type_id()))
+                    .collect(),
+            ),
+            VariantInfo::Struct(variant) => {
+                let properties = variant
+                    .iter()
+                    .map(|field| (field.name().to_string(), self.field_schema(field.type_id())))
+                    .collect::<Map<_, _>>();
+                json!({
+                    "type": "object",
+                    "properties": properties,
+                    "required": variant.field_names(),
+                    "additionalProperties": false,
+                })
+            }
+        };
+
+        let name = variant.name();
+        json!({
+            "type": "object",
+            "properties": { name: value },
+            "required": [name],
+            "additionalProperties": false,
+        })
+    }
+
+    fn field_schema(&self, type_id: TypeId) -> Value {
+        match self.registry.get(type_id) {
+            Some(registration) => json!({ "$ref": definition_ref(registration.type_path()) }),
+            None => json!({}),
+        }
+    }
+
+    fn default_value(&self, registration: &TypeRegistration) -> Option<Value> {
+        let value = registration.data::<ReflectDefault>()?.default();
+        serde_json::to_value(TypedReflectSerializer::new(value.as_ref(), self.registry)).ok()
+    }
+}
+
+fn tuple_schema(items: Vec<Value>) -> Value {
+    let len = items.len();
+    json!({
+        "type": "array",
+        "prefixItems": items,
+        "items": false,
+        "minItems": len,
+        "maxItems": len,
+    })
+}
+
+fn primitive_schema(type_id: TypeId) -> Option<Value> {
+    macro_rules! integer {
+        ($($ty:ty),*) => {
+            $(
+                if type_id == TypeId::of::<$ty>() {
+                    return Some(json!({
+                        "type": "integer",
+                        "minimum": <$ty>::MIN,
+                        "maximum": <$ty>::MAX,
+                    }));
+                }
+            )*
+        };
+    }
+
+    if type_id == TypeId::of::<bool>() {
+        return Some(json!({ "type": "boolean" }));
+    }
+    integer!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+    if [TypeId::of::<u128>(), TypeId::of::<i128>()].contains(&type_id) {
+        return Some(json!({ "type": "integer" }));
+    }
+    if [TypeId::of::<f32>(), TypeId::of::<f64>()].contains(&type_id) {
+        return Some(json!({ "type": "number" }));
+    }
+    if type_id == TypeId::of::<char>() {
+        return Some(json!({ "type": "string", "minLength": 1, "maxLength": 1 }));
+    }
+    if [
+        TypeId::of::<String>(),
+        TypeId::of::<&'static str>(),
+        TypeId::of::<Cow<'static, str>>(),
+        TypeId::of::<PathBuf>(),
+    ]
+    .contains(&type_id)
+    {
+        return Some(json!({ "type": "string" }));
+    }
+    None
+}
+
+/// Returns the `$ref` pointing at the definition of the given type path.
+fn definition_ref(type_path: &str) -> String {
+    let mut reference = String::from("#/$defs/");
+    // Escape the JSON pointer token, then percent-encode it for use in a URI fragment
+    let token = type_path.replace('~', "~0").replace('/', "~1");
+    for byte in token.bytes() {
+        if byte.is_ascii_alphanumeric() || b"-._~:!$'()*+,;=@".contains(&byte) {
+            reference.push(byte as char);
+        } else {
+            reference.push_str(&format!("%{byte:02X}"));
+        }
+    }
+    reference
+}
+
+#[cfg(test)]
+mod tests {
+    use crate as bevy_reflect;
+    use crate::serde::{JsonSchemaExporter, ReflectSerializer};
+    use crate::{std_traits::ReflectDefault, Reflect, TypeRegistry};
+    use bevy_utils::HashMap;
+    use serde_json::json;
+
+    #[derive(Reflect, Default)]
+    #[reflect(Default)]
+    struct Player {
+        name: String,
+        health: u8,
+        #[reflect(skip_serializing)]
+        cached: f32,
+        position: (f32, f32),
+        state: State,
+        item: Option<String>,
+        inventory: HashMap<u32, String>,
+    }
+
+    #[derive(Reflect, Default)]
+    enum State {
+        #[default]
+        Idle,
+        Walking(f32),
+        Attacking {
+            target: usize,
+        },
+    }
+
+    fn get_registry() -> TypeRegistry {
+        let mut registry = TypeRegistry::default();
+        registry.register::<Player>();
+        registry.register::<State>();
+        registry.register::<String>();
+        registry.register::<(f32, f32)>();
+        registry.register::<Option<String>>();
+        registry.register::<HashMap<u32, String>>();
+        registry
+    }
+
+    #[test]
+    fn should_export_struct_schema() {
+        let registry = get_registry();
+        let exporter = JsonSchemaExporter::new(&registry);
+        let registration = registry.get(std::any::TypeId::of::<Player>()).unwrap();
+
+        let schema = exporter.type_schema(registration);
+        assert_eq!(schema["type"], "object");
+        assert_eq!(schema["title"], "Player");
+        assert_eq!(
+            schema["required"],
+            json!(["name", "health", "position", "state", "item", "inventory"])
+        );
+        assert_eq!(
+            schema["properties"]["name"],
+            json!({ "$ref": "#/$defs/alloc::string::String" })
+        );
+        assert_eq!(
+            schema["properties"]["health"],
+            json!({ "$ref": "#/$defs/u8" })
+        );
+        assert!(schema["properties"].get("cached").is_none());
+
+        let default = &schema["default"];
+        assert_eq!(default["name"], "");
+        assert_eq!(default["state"], "Idle");
+        assert!(default.get("cached").is_none());
+    }
+
+    #[test]
+    fn should_export_enum_schema() {
+        let registry = get_registry();
+        let exporter = JsonSchemaExporter::new(&registry);
+
+        let state = exporter.type_schema(registry.get(std::any::TypeId::of::<State>()).unwrap());
+        assert_eq!(
+            state["oneOf"],
+            json!([
+                { "const": "Idle" },
+                {
+                    "type": "object",
+                    "properties": { "Walking": { "$ref": "#/$defs/f32" } },
+                    "required": ["Walking"],
+                    "additionalProperties": false,
+                },
+                {
+                    "type": "object",
+                    "properties": {
+                        "Attacking": {
+                            "type": "object",
+                            "properties": { "target": { "$ref": "#/$defs/usize" } },
+                            "required": ["target"],
+                            "additionalProperties": false,
+                        }
+                    },
+                    "required": ["Attacking"],
+                    "additionalProperties": false,
+                },
+            ])
+        );
+
+        let option = exporter.type_schema(
+            registry
+                .get(std::any::TypeId::of::<Option<String>>())
+                .unwrap(),
+        );
+        assert_eq!(
+            option["oneOf"],
+            json!([{ "type": "null" }, { "$ref": "#/$defs/alloc::string::String" }])
+        );
+    }
+
+    #[test]
+    fn should_export_registry() {
+        let registry = get_registry();
+        let schema = JsonSchemaExporter::new(&registry).export();
+
+        let definitions = schema["$defs"].as_object().unwrap();
+        assert_eq!(registry.iter().count(), definitions.len());
+        assert_eq!(
+            definitions["u8"],
+            json!({
+                "type": "integer",
+                "minimum": 0,
+                "maximum": 255,
+                "title": "u8",
+                "default": 0,
+            })
+        );
+        assert_eq!(
+            definitions["(f32, f32)"]["prefixItems"],
+            json!([{ "$ref": "#/$defs/f32" }, { "$ref": "#/$defs/f32" }])
+        );
+        assert_eq!(
+            definitions["bevy_utils::HashMap<u32, alloc::string::String>"]["propertyNames"],
+            json!({ "pattern": "^-?[0-9]+$" })
+        );
+
+        // The root accepts the output of `ReflectSerializer`
+        let player = Player::default();
+        let serialized = serde_json::to_value(ReflectSerializer::new(&player, &registry)).unwrap();
+        let type_path = serialized.as_object().unwrap().keys().next().unwrap();
+        assert!(definitions.contains_key(type_path));
+        assert!(schema["oneOf"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .any(|root| root["required"] == json!([type_path])));
+    }
+
+    #[test]
+    fn should_escape_definition_refs() {
+        assert_eq!(
+            "#/$defs/core::option::Option%3Calloc::string::String%3E",
+            super::definition_ref("core::option::Option<alloc::string::String>")
+        );
+        assert_eq!("#/$defs/a~1b~0c", super::definition_ref("a/b~c"));
+    }
+}
@@ -1,5 +1,5 @@
 use crate::{
-    Array, Enum, List, Map, Reflect, ReflectRef, ReflectSerialize, Struct, Tuple, TupleStruct,
+    Array, Enum, List, Map, Reflect, ReflectRef, ReflectSerialize, Set, Struct, Tuple, TupleStruct,
     TypeInfo, TypeRegistry, VariantInfo, VariantType,
 };
 use serde::ser::{
@@ -66,10 +66,13 @@ fn get_type_info<E: Error>(
 /// A general purpose serializer for reflected types.
 ///
 /// The serialized data will take the form of a map containing the following entries:
-/// 1. `type`: The _full_ [type name]
+/// 1. `type`: The [type path] of the value, as found in its [`TypeRegistration`]
 /// 2. `value`: The serialized value of the reflected type
 ///
-/// [type name]: std::any::type_name
+/// The type of the value must be registered in the [`TypeRegistry`].
+///
+/// [type path]: crate::TypePath
+/// [`TypeRegistration`]: crate::TypeRegistration
 pub struct ReflectSerializer<'a> {
     pub value: &'a dyn Reflect,
     pub registry: &'a TypeRegistry,
@@ -86,9 +89,20 @@ impl<'a> Serialize for ReflectSerializer<'a> {
     where
         S: serde::Serializer,
     {
+        let type_path = self
+            .registry
+            .get_with_name(self.value.type_name())
+            .map(|registration| registration.type_path())
+            .ok_or_else(|| {
+                Error::custom(format_args!(
+                    "no registration found for type {}",
+                    self.value.type_name()
+                ))
+            })?;
+
         let mut state = serializer.serialize_map(Some(1))?;
         state.serialize_entry(
-            self.value.type_name(),
+            type_path,
             &TypedReflectSerializer::new(self.value, self.registry),
         )?;
         state.end()
@@ -150,6 +164,11 @@ impl<'a> Serialize for TypedReflectSerializer<'a> {
                 registry: self.registry,
             }
             .serialize(serializer),
+            ReflectRef::Set(value) => SetSerializer {
+                set: value,
+                registry: self.registry,
+            }
+            .serialize(serializer),
             ReflectRef::Enum(value) => EnumSerializer {
                 enum_value: value,
                 registry: self.registry,
@@ -424,6 +443,24 @@ impl<'a> Serialize for MapSerializer<'a> {
     }
 }
 
+pub struct SetSerializer<'a> {
+    pub set: &'a dyn Set,
+    pub registry: &'a TypeRegistry,
+}
+
+impl<'a> Serialize for SetSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut state = serializer.serialize_seq(Some(self.set.len()))?;
+        for value in self.set.iter() {
+            state.serialize_element(&TypedReflectSerializer::new(value, self.registry))?;
+        }
+        state.end()
+    }
+}
+
 pub struct ListSerializer<'a> {
     pub list: &'a dyn List,
     pub registry: &'a TypeRegistry,
@@ -469,6 +506,7 @@ mod tests {
     use ron::extensions::Extensions;
     use ron::ser::PrettyConfig;
     use serde::Serialize;
+    use std::collections::BTreeSet;
     use std::f32::consts::PI;
 
     #[derive(Reflect, Debug, PartialEq)]
@@ -653,6 +691,20 @@ mod tests {
         assert_eq!(expected, output);
     }
 
+    #[test]
+    fn should_serialize_set() {
+        let value = BTreeSet::from([3_u8, 1, 2]);
+
+        let mut registry = get_registry();
+        registry.register::<BTreeSet<u8>>();
+        let serializer = ReflectSerializer::new(&value, &registry);
+
+        let output = ron::ser::to_string(&serializer).unwrap();
+        let expected = r#"{"alloc::collections::BTreeSet<u8>":[1,2,3]}"#;
+
+        assert_eq!(expected, output);
+    }
+
     #[test]
     fn should_serialize_option() {
         #[derive(Reflect, FromReflect, Debug, PartialEq)]
@@ -668,7 +720,8 @@ mod tests {
             complex: Some(SomeStruct { foo: 123 }),
         };
 
-        let registry = get_registry();
+        let mut registry = get_registry();
+        registry.register::<OptionTest>();
         let serializer = ReflectSerializer::new(&value, &registry);
 
         // === Normal === //
@@ -678,7 +731,7 @@ mod tests {
 
         let output = ron::ser::to_string_pretty(&serializer, config).unwrap();
         let expected = r#"{
-    "bevy_reflect::serde::ser::tests::should_serialize_option::OptionTest": (
+    "bevy_reflect::serde::ser::tests::OptionTest": (
         none: None,
         simple: Some("Hello world!"),
         complex: Some((
@@ -698,7 +751,7 @@ mod tests {
         let output = ron::ser::to_string_pretty(&serializer, config).unwrap();
         let expected = r#"#![enable(implicit_some)]
 {
-    "bevy_reflect::serde::ser::tests::should_serialize_option::OptionTest": (
+    "bevy_reflect::serde::ser::tests::OptionTest": (
         none: None,
         simple: "Hello world!",
         complex: (
@@ -730,7 +783,7 @@ mod tests {
         let serializer = ReflectSerializer::new(&value, &registry);
         let output = ron::ser::to_string_pretty(&serializer, config.clone()).unwrap();
         let expected = r#"{
-    "bevy_reflect::serde::ser::tests::enum_should_serialize::MyEnum": Unit,
+    "bevy_reflect::serde::ser::tests::MyEnum": Unit,
 }"#;
         assert_eq!(expected, output);
 
@@ -739,7 +792,7 @@ mod tests {
         let serializer = ReflectSerializer::new(&value, &registry);
         let output = ron::ser::to_string_pretty(&serializer, config.clone()).unwrap();
         let expected = r#"{
-    "bevy_reflect::serde::ser::tests::enum_should_serialize::MyEnum": NewType(123),
+    "bevy_reflect::serde::ser::tests::MyEnum": NewType(123),
 }"#;
         assert_eq!(expected, output);
 
@@ -748,7 +801,7 @@ mod tests {
         let serializer = ReflectSerializer::new(&value, &registry);
         let output = ron::ser::to_string_pretty(&serializer, config.clone()).unwrap();
         let expected = r#"{
-    "bevy_reflect::serde::ser::tests::enum_should_serialize::MyEnum": Tuple(1.23, 3.21),
+    "bevy_reflect::serde::ser::tests::MyEnum": Tuple(1.23, 3.21),
 }"#;
         assert_eq!(expected, output);
 
@@ -759,7 +812,7 @@ mod tests {
         let serializer = ReflectSerializer::new(&value, &registry);
         let output = ron::ser::to_string_pretty(&serializer, config).unwrap();
         let expected = r#"{
-    "bevy_reflect::serde::ser::tests::enum_should_serialize::MyEnum": Struct(
+    "bevy_reflect::serde::ser::tests::MyEnum": Struct(
         value: "I <3 Enums",
     ),
 }"#;
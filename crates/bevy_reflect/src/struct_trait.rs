@@ -1,6 +1,8 @@
+use crate::attributes::impl_custom_attribute_methods;
 use crate::utility::NonGenericTypeInfoCell;
 use crate::{
-    DynamicInfo, NamedField, Reflect, ReflectMut, ReflectOwned, ReflectRef, TypeInfo, Typed,
+    CustomAttributes, DynamicInfo, NamedField, Reflect, ReflectMut, ReflectOwned, ReflectRef,
+    TypeInfo, Typed,
 };
 use bevy_utils::{Entry, HashMap};
 use std::fmt::{Debug, Formatter};
@@ -8,6 +10,7 @@ use std::{
     any::{Any, TypeId},
     borrow::Cow,
     slice::Iter,
+    sync::Arc,
 };
 
 /// A trait used to power [struct-like] operations via [reflection].
@@ -81,6 +84,7 @@ pub struct StructInfo {
     fields: Box<[NamedField]>,
     field_names: Box<[&'static str]>,
     field_indices: HashMap<&'static str, usize>,
+    custom_attributes: Arc<CustomAttributes>,
     #[cfg(feature = "documentation")]
     docs: Option<&'static str>,
 }
@@ -109,6 +113,7 @@ impl StructInfo {
             fields: fields.to_vec().into_boxed_slice(),
             field_names,
             field_indices,
+            custom_attributes: Arc::default(),
             #[cfg(feature = "documentation")]
             docs: None,
         }
@@ -120,6 +125,8 @@ impl StructInfo {
         Self { docs, ..self }
     }
 
+    impl_custom_attribute_methods!("struct");
+
     /// A slice containing the names of all fields in order.
     pub fn field_names(&self) -> &[&'static str] {
         &self.field_names
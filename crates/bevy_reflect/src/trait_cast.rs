@@ -0,0 +1,169 @@
+use crate::{Reflect, TypeData};
+
+/// [`TypeData`] used to cast reflected values to trait objects.
+///
+/// This is implemented for the `ReflectMyTrait` types generated by the
+/// [`#[reflect_trait]`](crate::reflect_trait) macro, where [`Object`] is `dyn MyTrait`.
+/// It allows [`TypeRegistry::cast_ref`] and friends to cast any registered value to
+/// the trait object, and [`TypeRegistry::iter_with_data`] to find all the types
+/// implementing the trait.
+///
+/// ```
+/// # use bevy_reflect::{reflect_trait, Reflect, TypeRegistry};
+/// #[reflect_trait]
+/// trait Speak {
+///     fn speak(&self) -> String;
+/// }
+///
+/// #[derive(Reflect)]
+/// #[reflect(Speak)]
+/// struct Dog;
+///
+/// impl Speak for Dog {
+///     fn speak(&self) -> String {
+///         "woof".to_string()
+///     }
+/// }
+///
+/// let mut registry = TypeRegistry::new();
+/// registry.register::<Dog>();
+///
+/// let value: Box<dyn Reflect> = Box::new(Dog);
+/// let speaker = registry.cast_ref::<ReflectSpeak>(&*value).unwrap();
+/// assert_eq!("woof", speaker.speak());
+///
+/// let speakers: Vec<_> = registry
+///     .iter_with_data::<ReflectSpeak>()
+///     .map(|(registration, _)| registration.short_name())
+///     .collect();
+/// assert_eq!(vec!["Dog"], speakers);
+/// ```
+///
+/// [`Object`]: TraitCast::Object
+/// [`TypeRegistry::cast_ref`]: crate::TypeRegistry::cast_ref
+/// [`TypeRegistry::iter_with_data`]: crate::TypeRegistry::iter_with_data
+pub trait TraitCast: TypeData {
+    /// The trait object values are cast to.
+    type Object: ?Sized + 'static;
+
+    /// Casts a `&dyn Reflect` to the trait object.
+    ///
+    /// If the value is not of the type this type data was created for, returns `None`.
+    fn cast_ref<'a>(&self, value: &'a dyn Reflect) -> Option<&'a Self::Object>;
+
+    /// Casts a `&mut dyn Reflect` to the trait object.
+    ///
+    /// If the value is not of the type this type data was created for, returns `None`.
+    fn cast_mut<'a>(&self, value: &'a mut dyn Reflect) -> Option<&'a mut Self::Object>;
+
+    /// Casts a `Box<dyn Reflect>` to a boxed trait object.
+    ///
+    /// If the value is not of the type this type data was created for, it is returned as the error.
+    fn cast_boxed(&self, value: Box<dyn Reflect>) -> Result<Box<Self::Object>, Box<dyn Reflect>>;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate as bevy_reflect;
+    use crate::{reflect_trait, Reflect, TraitCast, TypeRegistry};
+
+    #[reflect_trait]
+    trait Shape {
+        fn area(&self) -> f32;
+        fn scale(&mut self, factor: f32);
+    }
+
+    #[derive(Reflect)]
+    #[reflect(Shape)]
+    struct Square(f32);
+
+    impl Shape for Square {
+        fn area(&self) -> f32 {
+            self.0 * self.0
+        }
+
+        fn scale(&mut self, factor: f32) {
+            self.0 *= factor;
+        }
+    }
+
+    #[derive(Reflect)]
+    #[reflect(Shape)]
+    struct Rectangle {
+        width: f32,
+        height: f32,
+    }
+
+    impl Shape for Rectangle {
+        fn area(&self) -> f32 {
+            self.width * self.height
+        }
+
+        fn scale(&mut self, factor: f32) {
+            self.width *= factor;
+            self.height *= factor;
+        }
+    }
+
+    #[derive(Reflect)]
+    struct Point;
+
+    fn registry() -> TypeRegistry {
+        let mut registry = TypeRegistry::new();
+        registry.register::<Square>();
+        registry.register::<Rectangle>();
+        registry.register::<Point>();
+        registry
+    }
+
+    #[test]
+    fn should_cast_to_trait_objects() {
+        let registry = registry();
+
+        let mut value: Box<dyn Reflect> = Box::new(Square(2.0));
+        assert_eq!(
+            4.0,
+            registry.cast_ref::<ReflectShape>(&*value).unwrap().area()
+        );
+
+        registry
+            .cast_mut::<ReflectShape>(&mut *value)
+            .unwrap()
+            .scale(2.0);
+        assert_eq!(4.0, value.downcast_ref::<Square>().unwrap().0);
+
+        let shape = registry.cast_boxed::<ReflectShape>(value).unwrap();
+        assert_eq!(16.0, shape.area());
+
+        let point: Box<dyn Reflect> = Box::new(Point);
+        assert!(registry.cast_ref::<ReflectShape>(&*point).is_none());
+        assert!(registry.cast_boxed::<ReflectShape>(point).is_err());
+    }
+
+    #[test]
+    fn should_not_cast_other_types() {
+        let registry = registry();
+        let square = registry
+            .get_type_data::<ReflectShape>(std::any::TypeId::of::<Square>())
+            .unwrap();
+
+        let rectangle = Rectangle {
+            width: 1.0,
+            height: 2.0,
+        };
+        assert!(square.cast_ref(&rectangle).is_none());
+        assert!(square.cast_ref(&Square(3.0)).is_some());
+    }
+
+    #[test]
+    fn should_find_implementations() {
+        let registry = registry();
+
+        let mut implementors: Vec<_> = registry
+            .iter_with_data::<ReflectShape>()
+            .map(|(registration, _)| registration.short_name())
+            .collect();
+        implementors.sort_unstable();
+        assert_eq!(vec!["Rectangle", "Square"], implementors);
+    }
+}
@@ -1,7 +1,7 @@
-use crate::utility::NonGenericTypeInfoCell;
+use crate::utility::{GenericTypePathCell, NonGenericTypeInfoCell};
 use crate::{
     DynamicInfo, FromReflect, GetTypeRegistration, Reflect, ReflectMut, ReflectOwned, ReflectRef,
-    TypeInfo, TypeRegistration, Typed, UnnamedField,
+    TypeInfo, TypePath, TypeRegistration, Typed, UnnamedField,
 };
 use std::any::{Any, TypeId};
 use std::fmt::{Debug, Formatter};
@@ -454,6 +454,14 @@ pub fn tuple_debug(dyn_tuple: &dyn Tuple, f: &mut std::fmt::Formatter<'_>) -> st
     debug.finish()
 }
 
+/// Formats the path of a tuple from the paths of its fields, such as `(u8,)` or `(u8, String)`.
+fn tuple_path(fields: &[&str]) -> String {
+    match fields {
+        [field] => format!("({field},)"),
+        fields => format!("({})", fields.join(", ")),
+    }
+}
+
 macro_rules! impl_reflect_tuple {
     {$($index:tt : $name:tt),*} => {
         impl<$($name: Reflect),*> Tuple for ($($name,)*) {
@@ -584,7 +592,23 @@ macro_rules! impl_reflect_tuple {
             }
         }
 
-        impl<$($name: Reflect + Typed),*> GetTypeRegistration for ($($name,)*) {
+        impl<$($name: TypePath),*> TypePath for ($($name,)*) {
+            fn type_path() -> &'static str {
+                static CELL: GenericTypePathCell = GenericTypePathCell::new();
+                CELL.get_or_insert::<Self, _>(|| {
+                    tuple_path(&[$(<$name as TypePath>::type_path()),*])
+                })
+            }
+
+            fn short_type_path() -> &'static str {
+                static CELL: GenericTypePathCell = GenericTypePathCell::new();
+                CELL.get_or_insert::<Self, _>(|| {
+                    tuple_path(&[$(<$name as TypePath>::short_type_path()),*])
+                })
+            }
+        }
+
+        impl<$($name: Reflect + Typed + TypePath),*> GetTypeRegistration for ($($name,)*) {
             fn get_type_registration() -> TypeRegistration {
                 TypeRegistration::of::<($($name,)*)>()
             }
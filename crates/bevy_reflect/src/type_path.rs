@@ -0,0 +1,72 @@
+/// A static accessor to stable paths for a type.
+///
+/// Unlike [`std::any::type_name`], which is explicitly not guaranteed to be the same across
+/// compiler versions, the paths returned by this trait are fully controlled by Bevy and by the
+/// implementor. This makes them suitable for identifying types in persisted data, such as saved
+/// scenes, which is why they are used by the [`TypeRegistry`] and the reflection serializers.
+///
+/// This trait is automatically implemented by [`#[derive(Reflect)]`](derive@crate::Reflect),
+/// and may also be derived on its own with `#[derive(TypePath)]`.
+/// By default, the path of a type is made of the [module path] where it is defined and its
+/// identifier. Both parts can be overridden with container attributes, which is useful to keep
+/// the path of a type stable when it is moved or renamed:
+///
+/// ```
+/// # use bevy_reflect::TypePath;
+/// #[derive(TypePath)]
+/// #[type_path = "my_crate::foo"]
+/// #[type_name = "MyType"]
+/// struct Renamed;
+///
+/// assert_eq!(Renamed::type_path(), "my_crate::foo::MyType");
+/// assert_eq!(Renamed::short_type_path(), "MyType");
+/// assert_eq!(Renamed::type_ident(), Some("MyType"));
+/// assert_eq!(Renamed::crate_name(), Some("my_crate"));
+/// assert_eq!(Renamed::module_path(), Some("my_crate::foo"));
+/// ```
+///
+/// The paths of generic types include the paths of their type parameters:
+///
+/// ```
+/// # use bevy_reflect::TypePath;
+/// #[derive(TypePath)]
+/// #[type_path = "my_crate"]
+/// struct Wrapper<T>(T);
+///
+/// assert_eq!(Wrapper::<u32>::type_path(), "my_crate::Wrapper<u32>");
+/// assert_eq!(
+///     Wrapper::<Option<String>>::short_type_path(),
+///     "Wrapper<Option<String>>"
+/// );
+/// ```
+///
+/// [`TypeRegistry`]: crate::TypeRegistry
+/// [module path]: module_path
+pub trait TypePath: 'static {
+    /// Returns the fully qualified path of the type, such as `my_crate::foo::Bar<u32>`.
+    fn type_path() -> &'static str;
+
+    /// Returns the path of the type without any module prefixes, such as `Bar<u32>`.
+    fn short_type_path() -> &'static str;
+
+    /// Returns the name of the type without generic parameters, such as `Bar`.
+    ///
+    /// Returns [`None`] for anonymous types, such as tuples and arrays.
+    fn type_ident() -> Option<&'static str> {
+        None
+    }
+
+    /// Returns the name of the crate the type is in, such as `my_crate`.
+    ///
+    /// Returns [`None`] for primitives and anonymous types.
+    fn crate_name() -> Option<&'static str> {
+        None
+    }
+
+    /// Returns the path of the module the type is in, such as `my_crate::foo`.
+    ///
+    /// Returns [`None`] for primitives and anonymous types.
+    fn module_path() -> Option<&'static str> {
+        None
+    }
+}
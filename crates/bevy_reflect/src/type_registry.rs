@@ -1,6 +1,9 @@
-use crate::{serde::Serializable, Reflect, TypeInfo, Typed};
+use crate::{
+    serde::Serializable, DynamicFunction, IntoFunction, IntoMethod, Reflect, ReflectMethods,
+    TraitCast, TypeInfo, TypePath, Typed,
+};
 use bevy_ptr::{Ptr, PtrMut};
-use bevy_utils::{HashMap, HashSet};
+use bevy_utils::{tracing::warn, HashMap, HashSet};
 use downcast_rs::{impl_downcast, Downcast};
 use parking_lot::{RwLock, RwLockReadGuard, RwLockWriteGuard};
 use serde::Deserialize;
@@ -22,7 +25,10 @@ pub struct TypeRegistry {
     registrations: HashMap<TypeId, TypeRegistration>,
     short_name_to_id: HashMap<String, TypeId>,
     full_name_to_id: HashMap<String, TypeId>,
+    type_path_to_id: HashMap<&'static str, TypeId>,
     ambiguous_names: HashSet<String>,
+    ambiguous_type_paths: HashSet<&'static str>,
+    functions: HashMap<String, DynamicFunction>,
 }
 
 // TODO:  remove this wrapper once we migrate to Atelier Assets and the Scene AssetLoader doesn't
@@ -65,7 +71,10 @@ impl TypeRegistry {
             registrations: Default::default(),
             short_name_to_id: Default::default(),
             full_name_to_id: Default::default(),
+            type_path_to_id: Default::default(),
             ambiguous_names: Default::default(),
+            ambiguous_type_paths: Default::default(),
+            functions: Default::default(),
         }
     }
 
@@ -121,10 +130,43 @@ impl TypeRegistry {
         }
         self.full_name_to_id
             .insert(registration.type_name().to_string(), registration.type_id());
+        let type_path = registration.type_path();
+        if self.type_path_to_id.contains_key(type_path)
+            || self.ambiguous_type_paths.contains(type_path)
+        {
+            // type paths should be unique, so this is most likely a bad `TypePath` implementation
+            warn!(
+                "type path {} is used by several types, they can't be looked up by type path",
+                type_path
+            );
+            self.type_path_to_id.remove(type_path);
+            self.ambiguous_type_paths.insert(type_path);
+        } else {
+            self.type_path_to_id
+                .insert(type_path, registration.type_id());
+        }
         self.registrations
             .insert(registration.type_id(), registration);
     }
 
+    /// Removes the registration of the type with the given [`TypeId`], returning it.
+    ///
+    /// If the type's short name was ambiguous, it remains so: the other types sharing it can
+    /// still only be looked up by their full name.
+    ///
+    /// If the specified type has not been registered, returns `None`.
+    pub fn remove(&mut self, type_id: TypeId) -> Option<TypeRegistration> {
+        let registration = self.registrations.remove(&type_id)?;
+        if self.short_name_to_id.get(registration.short_name()) == Some(&type_id) {
+            self.short_name_to_id.remove(registration.short_name());
+        }
+        self.full_name_to_id.remove(registration.type_name());
+        if self.type_path_to_id.get(registration.type_path()) == Some(&type_id) {
+            self.type_path_to_id.remove(registration.type_path());
+        }
+        Some(registration)
+    }
+
     /// Registers the type data `D` for type `T`.
     ///
     /// Most of the time [`TypeRegistry::register`] can be used instead to register a type you derived [`Reflect`] for.
@@ -152,6 +194,90 @@ impl TypeRegistry {
         data.insert(D::from_type());
     }
 
+    /// Registers `function` under the given name, replacing any function with the same name.
+    ///
+    /// See [`DynamicFunction`] for the functions that can be registered.
+    ///
+    /// # Example
+    /// ```rust
+    /// use bevy_reflect::{ArgList, TypeRegistry};
+    ///
+    /// fn double(value: i32) -> i32 {
+    ///     value * 2
+    /// }
+    ///
+    /// let mut type_registry = TypeRegistry::default();
+    /// type_registry.register_function("double", double);
+    ///
+    /// let double = type_registry.get_function("double").unwrap();
+    /// let result = double.call(ArgList::new().push_owned(4_i32)).unwrap();
+    /// assert_eq!(result.downcast_ref::<i32>(), Some(&8));
+    /// ```
+    pub fn register_function<Marker>(
+        &mut self,
+        name: impl Into<String>,
+        function: impl IntoFunction<Marker>,
+    ) {
+        let name = name.into();
+        let function = function.into_function().with_name(name.clone());
+        self.functions.insert(name, function);
+    }
+
+    /// Returns the function registered under the given name with
+    /// [`TypeRegistry::register_function`].
+    pub fn get_function(&self, name: &str) -> Option<&DynamicFunction> {
+        self.functions.get(name)
+    }
+
+    /// Returns an iterator over the functions registered with
+    /// [`TypeRegistry::register_function`].
+    pub fn functions(&self) -> impl Iterator<Item = &DynamicFunction> {
+        self.functions.values()
+    }
+
+    /// Registers a method of type `T` under the given name, in its [`ReflectMethods`] type data.
+    ///
+    /// The method is called with the receiver as its first argument.
+    ///
+    /// # Example
+    /// ```rust
+    /// use bevy_reflect::{ArgList, Reflect, ReflectMethods, TypeRegistry};
+    /// use std::any::TypeId;
+    ///
+    /// let mut type_registry = TypeRegistry::default();
+    /// type_registry.register::<String>();
+    /// type_registry.register_method::<String, _>("len", |value: &String| value.len());
+    ///
+    /// let methods = type_registry.get_type_data::<ReflectMethods>(TypeId::of::<String>()).unwrap();
+    /// let value = String::from("hello");
+    /// let len = methods.get("len").unwrap().call(ArgList::new().push_ref(&value)).unwrap();
+    /// assert_eq!(len.downcast_ref::<usize>(), Some(&5));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `T` has not been registered.
+    pub fn register_method<T: Reflect, Marker>(
+        &mut self,
+        name: impl Into<String>,
+        method: impl IntoMethod<T, Marker>,
+    ) {
+        let registration = self.get_mut(TypeId::of::<T>()).unwrap_or_else(|| {
+            panic!(
+                "attempted to call `TypeRegistry::register_method` for type `{T}` without registering `{T}` first",
+                T = std::any::type_name::<T>(),
+            )
+        });
+        if registration.data::<ReflectMethods>().is_none() {
+            registration.insert(ReflectMethods::default());
+        }
+        let method = method.into_function().with_name(name.into());
+        registration
+            .data_mut::<ReflectMethods>()
+            .unwrap()
+            .insert(method);
+    }
+
     /// Returns a reference to the [`TypeRegistration`] of the type with the
     /// given [`TypeId`].
     ///
@@ -172,9 +298,41 @@ impl TypeRegistry {
         self.registrations.get_mut(&type_id)
     }
 
+    /// Returns a reference to the [`TypeRegistration`] of the type with the
+    /// given [type path].
+    ///
+    /// Type paths are stable across compiler versions, so this should be preferred over
+    /// [`get_with_name`](Self::get_with_name) to look up types from persisted data.
+    ///
+    /// If no type with the given path has been registered, returns `None`.
+    ///
+    /// [type path]: TypePath::type_path
+    pub fn get_with_type_path(&self, type_path: &str) -> Option<&TypeRegistration> {
+        self.type_path_to_id
+            .get(type_path)
+            .and_then(|id| self.get(*id))
+    }
+
+    /// Returns a mutable reference to the [`TypeRegistration`] of the type with
+    /// the given [type path].
+    ///
+    /// If no type with the given path has been registered, returns `None`.
+    ///
+    /// [type path]: TypePath::type_path
+    pub fn get_with_type_path_mut(&mut self, type_path: &str) -> Option<&mut TypeRegistration> {
+        self.type_path_to_id
+            .get(type_path)
+            .cloned()
+            .and_then(move |id| self.get_mut(id))
+    }
+
     /// Returns a reference to the [`TypeRegistration`] of the type with the
     /// given name.
     ///
+    /// The name of a type is given by [`std::any::type_name`], which may change between
+    /// compiler versions. See [`get_with_type_path`](Self::get_with_type_path) for a stable
+    /// alternative.
+    ///
     /// If no type with the given name has been registered, returns `None`.
     pub fn get_with_name(&self, type_name: &str) -> Option<&TypeRegistration> {
         self.full_name_to_id
@@ -260,6 +418,52 @@ impl TypeRegistry {
     pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut TypeRegistration> {
         self.registrations.values_mut()
     }
+
+    /// Returns an iterator over the registered types that have [`TypeData`] of type `T`,
+    /// along with that type data.
+    ///
+    /// With the type data generated by [`#[reflect_trait]`](crate::reflect_trait), this
+    /// can be used to find all the registered types implementing a trait.
+    pub fn iter_with_data<T: TypeData>(&self) -> impl Iterator<Item = (&TypeRegistration, &T)> {
+        self.registrations
+            .values()
+            .filter_map(|registration| Some((registration, registration.data::<T>()?)))
+    }
+
+    /// Casts a reflected value to the trait object of the [`TraitCast`] type data `C`.
+    ///
+    /// If the value's type has not been registered, or if `C` is not present in its type
+    /// registration, returns `None`.
+    pub fn cast_ref<'a, C: TraitCast>(&self, value: &'a dyn Reflect) -> Option<&'a C::Object> {
+        self.get_type_data::<C>(value.get_type_info().type_id())?
+            .cast_ref(value)
+    }
+
+    /// Casts a mutable reflected value to the trait object of the [`TraitCast`] type data `C`.
+    ///
+    /// If the value's type has not been registered, or if `C` is not present in its type
+    /// registration, returns `None`.
+    pub fn cast_mut<'a, C: TraitCast>(
+        &self,
+        value: &'a mut dyn Reflect,
+    ) -> Option<&'a mut C::Object> {
+        self.get_type_data::<C>(value.get_type_info().type_id())?
+            .cast_mut(value)
+    }
+
+    /// Casts a boxed reflected value to the boxed trait object of the [`TraitCast`] type data `C`.
+    ///
+    /// If the value's type has not been registered, or if `C` is not present in its type
+    /// registration, the value is returned as the error.
+    pub fn cast_boxed<C: TraitCast>(
+        &self,
+        value: Box<dyn Reflect>,
+    ) -> Result<Box<C::Object>, Box<dyn Reflect>> {
+        match self.get_type_data::<C>(value.get_type_info().type_id()) {
+            Some(cast) => cast.cast_boxed(value),
+            None => Err(value),
+        }
+    }
 }
 
 impl TypeRegistryArc {
@@ -280,7 +484,7 @@ impl TypeRegistryArc {
 /// but is more often automatically generated using [`#[derive(Reflect)]`](derive@crate::Reflect) which itself generates
 /// an implementation of the [`GetTypeRegistration`] trait.
 ///
-/// Along with the type's [`TypeInfo`] and [short name],
+/// Along with the type's [`TypeInfo`], [type path] and [short name],
 /// this struct also contains a type's registered [`TypeData`].
 ///
 /// See the [crate-level documentation] for more information on type registration.
@@ -291,17 +495,19 @@ impl TypeRegistryArc {
 /// # use bevy_reflect::{TypeRegistration, std_traits::ReflectDefault, FromType};
 /// let mut registration = TypeRegistration::of::<Option<String>>();
 ///
-/// assert_eq!("core::option::Option<alloc::string::String>", registration.type_name());
+/// assert_eq!("core::option::Option<alloc::string::String>", registration.type_path());
 /// assert_eq!("Option<String>", registration.short_name());
 ///
 /// registration.insert::<ReflectDefault>(FromType::<Option<String>>::from_type());
 /// assert!(registration.data::<ReflectDefault>().is_some())
 /// ```
 ///
-/// [short name]: bevy_utils::get_short_name
+/// [type path]: TypePath
+/// [short name]: TypePath::short_type_path
 /// [crate-level documentation]: crate
 pub struct TypeRegistration {
-    short_name: String,
+    type_path: &'static str,
+    short_name: &'static str,
     data: HashMap<TypeId, Box<dyn TypeData>>,
     type_info: &'static TypeInfo,
 }
@@ -309,7 +515,7 @@ pub struct TypeRegistration {
 impl Debug for TypeRegistration {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("TypeRegistration")
-            .field("short_name", &self.short_name)
+            .field("type_path", &self.type_path)
             .field("type_info", &self.type_info)
             .finish()
     }
@@ -357,20 +563,27 @@ impl TypeRegistration {
     }
 
     /// Creates type registration information for `T`.
-    pub fn of<T: Reflect + Typed>() -> Self {
-        let type_name = std::any::type_name::<T>();
+    pub fn of<T: Reflect + Typed + TypePath>() -> Self {
         Self {
             data: HashMap::default(),
-            short_name: bevy_utils::get_short_name(type_name),
+            type_path: T::type_path(),
+            short_name: T::short_type_path(),
             type_info: T::type_info(),
         }
     }
 
     /// Returns the [short name] of the type.
     ///
-    /// [short name]: bevy_utils::get_short_name
-    pub fn short_name(&self) -> &str {
-        &self.short_name
+    /// [short name]: TypePath::short_type_path
+    pub fn short_name(&self) -> &'static str {
+        self.short_name
+    }
+
+    /// Returns the stable [path] of the type.
+    ///
+    /// [path]: TypePath::type_path
+    pub fn type_path(&self) -> &'static str {
+        self.type_path
     }
 
     /// Returns the [name] of the type.
@@ -390,7 +603,8 @@ impl Clone for TypeRegistration {
 
         TypeRegistration {
             data,
-            short_name: self.short_name.clone(),
+            type_path: self.type_path,
+            short_name: self.short_name,
             type_info: self.type_info,
         }
     }
@@ -573,7 +787,7 @@ impl<T: Reflect> FromType<T> for ReflectFromPtr {
 
 #[cfg(test)]
 mod test {
-    use crate::{GetTypeRegistration, ReflectFromPtr, TypeRegistration};
+    use crate::{GetTypeRegistration, ReflectFromPtr, TypeRegistration, TypeRegistry};
     use bevy_ptr::{Ptr, PtrMut};
     use bevy_utils::HashMap;
 
@@ -653,4 +867,60 @@ mod test {
             "Option<HashMap<Option<String>, (String, Option<String>)>>"
         );
     }
+
+    #[test]
+    fn should_remove_registration() {
+        let mut registry = TypeRegistry::default();
+        registry.register::<Option<f64>>();
+
+        let registration = registry
+            .remove(std::any::TypeId::of::<Option<f64>>())
+            .unwrap();
+        assert_eq!(registration.short_name(), "Option<f64>");
+        assert!(registry
+            .get(std::any::TypeId::of::<Option<f64>>())
+            .is_none());
+        assert!(registry
+            .get_with_name(std::any::type_name::<Option<f64>>())
+            .is_none());
+        assert!(registry.get_with_short_name("Option<f64>").is_none());
+        assert!(registry
+            .remove(std::any::TypeId::of::<Option<f64>>())
+            .is_none());
+
+        // the type can be registered again
+        registry.register::<Option<f64>>();
+        assert!(registry.get_with_short_name("Option<f64>").is_some());
+    }
+
+    #[test]
+    fn should_not_look_up_ambiguous_type_paths() {
+        #[derive(Reflect)]
+        #[type_path = "my_crate"]
+        #[type_name = "Foo"]
+        struct Foo;
+
+        #[derive(Reflect)]
+        #[type_path = "my_crate"]
+        #[type_name = "Foo"]
+        struct Bar;
+
+        #[derive(Reflect)]
+        #[type_path = "my_crate"]
+        #[type_name = "Foo"]
+        struct Baz;
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<Foo>();
+        assert!(registry.get_with_type_path("my_crate::Foo").is_some());
+
+        registry.register::<Bar>();
+        assert!(registry.get_with_type_path("my_crate::Foo").is_none());
+
+        // the path stays ambiguous when another type uses it
+        registry.remove(std::any::TypeId::of::<Bar>());
+        registry.register::<Baz>();
+        assert!(registry.get_with_type_path("my_crate::Foo").is_none());
+        assert!(registry.get(std::any::TypeId::of::<Baz>()).is_some());
+    }
 }
@@ -151,6 +151,74 @@ impl GenericTypeInfoCell {
     }
 }
 
+/// A container for the [type paths] of generic types, allowing them to be stored statically.
+///
+/// The path of a generic type depends on the paths of its type parameters, so it has to be built
+/// at runtime, once for each monomorphization of the type.
+///
+/// ## Example
+///
+/// ```
+/// # use bevy_reflect::TypePath;
+/// use bevy_reflect::utility::GenericTypePathCell;
+///
+/// struct Foo<T>(T);
+///
+/// impl<T: TypePath> TypePath for Foo<T> {
+///   fn type_path() -> &'static str {
+///     static CELL: GenericTypePathCell = GenericTypePathCell::new();
+///     CELL.get_or_insert::<Self, _>(|| format!("my_crate::Foo<{}>", T::type_path()))
+///   }
+///
+///   fn short_type_path() -> &'static str {
+///     static CELL: GenericTypePathCell = GenericTypePathCell::new();
+///     CELL.get_or_insert::<Self, _>(|| format!("Foo<{}>", T::short_type_path()))
+///   }
+/// }
+///
+/// assert_eq!(Foo::<u8>::type_path(), "my_crate::Foo<u8>");
+/// ```
+///
+/// [type paths]: crate::TypePath
+pub struct GenericTypePathCell(OnceBox<RwLock<HashMap<TypeId, &'static str>>>);
+
+impl GenericTypePathCell {
+    /// Initialize a [`GenericTypePathCell`] for generic types.
+    pub const fn new() -> Self {
+        Self(OnceBox::new())
+    }
+
+    /// Returns the path stored in the cell for the type `T`.
+    ///
+    /// If there is no path found, a new one will be generated from the given function.
+    pub fn get_or_insert<T, F>(&self, f: F) -> &'static str
+    where
+        T: Any + ?Sized,
+        F: FnOnce() -> String,
+    {
+        let type_id = TypeId::of::<T>();
+        let mapping = self.0.get_or_init(Box::default);
+        if let Some(path) = mapping.read().get(&type_id) {
+            return path;
+        }
+
+        // The path has to be built before taking the lock: it usually contains the paths of the
+        // type parameters, which may be stored in this same cell, such as for `Option<Option<T>>`.
+        let path = f();
+        // Leaked for the same reason as in `GenericTypeInfoCell`.
+        mapping
+            .write()
+            .entry(type_id)
+            .or_insert_with(|| Box::leak(path.into_boxed_str()))
+    }
+}
+
+impl Default for GenericTypePathCell {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 /// Deterministic fixed state hasher to be used by implementors of [`Reflect::reflect_hash`].
 ///
 /// Hashes should be deterministic across processes so hashes can be used as
@@ -0,0 +1,356 @@
+//! A remote protocol for inspecting and editing a running Bevy app from another process.
+//!
+//! The [`RemotePlugin`] listens on a localhost TCP socket for
+//! [JSON-RPC 2.0](https://www.jsonrpc.org/specification) requests, sent one per line.
+//! Requests are processed by the app once per frame, and can query entities, read and write
+//! reflected components and resources, spawn and despawn entities, and list the registered
+//! types. See [`methods`] for the available methods.
+//!
+//! Only types registered in the [`AppTypeRegistry`](bevy_app::AppTypeRegistry) with
+//! `ReflectComponent` or `ReflectResource` type data can be accessed.
+//!
+//! ```text
+//! --> {"jsonrpc": "2.0", "id": 1, "method": "bevy/get_resource", "params": {"resource": "my_game::Score"}}
+//! <-- {"jsonrpc": "2.0", "id": 1, "result": 42}
+//! ```
+
+pub mod methods;
+mod protocol;
+mod server;
+
+pub use protocol::*;
+
+use bevy_app::{App, CoreSet, Plugin};
+use bevy_ecs::{schedule::IntoSystemConfig, system::Resource, world::World};
+use bevy_utils::tracing::{error, info};
+use crossbeam_channel::{Receiver, Sender};
+use server::PendingRequest;
+use std::net::{IpAddr, Ipv4Addr, SocketAddr, TcpListener};
+
+/// The port the [`RemotePlugin`] listens on by default.
+pub const DEFAULT_PORT: u16 = 15702;
+
+/// Adds a server for the remote protocol to an app.
+///
+/// By default, the server listens on `127.0.0.1:15702`. Only expose it on other addresses in
+/// trusted environments, since any client can read and modify the world.
+#[derive(Debug, Clone)]
+pub struct RemotePlugin {
+    address: IpAddr,
+    port: u16,
+}
+
+impl Default for RemotePlugin {
+    fn default() -> Self {
+        Self {
+            address: IpAddr::V4(Ipv4Addr::LOCALHOST),
+            port: DEFAULT_PORT,
+        }
+    }
+}
+
+impl RemotePlugin {
+    /// Sets the address to listen on.
+    pub fn with_address(mut self, address: impl Into<IpAddr>) -> Self {
+        self.address = address.into();
+        self
+    }
+
+    /// Sets the port to listen on.
+    ///
+    /// If the port is `0`, the operating system picks a free port, which can then be read from
+    /// the [`RemoteServer`] resource.
+    pub fn with_port(mut self, port: u16) -> Self {
+        self.port = port;
+        self
+    }
+}
+
+impl Plugin for RemotePlugin {
+    fn build(&self, app: &mut App) {
+        let listener = match TcpListener::bind((self.address, self.port)) {
+            Ok(listener) => listener,
+            Err(err) => {
+                error!(
+                    "Failed to start the remote protocol server on {}:{}: {err}",
+                    self.address, self.port
+                );
+                return;
+            }
+        };
+        let local_addr = listener
+            .local_addr()
+            .expect("a bound listener has a local address");
+        info!("Remote protocol server listening on {local_addr}");
+
+        let (request_sender, requests) = crossbeam_channel::unbounded();
+        let (shutdown, shutdown_receiver) = crossbeam_channel::bounded(0);
+        std::thread::Builder::new()
+            .name("remote protocol server".to_string())
+            .spawn(move || server::listen(listener, request_sender, shutdown_receiver))
+            .expect("failed to spawn the remote protocol server thread");
+
+        app.insert_resource(RemoteServer {
+            local_addr,
+            requests,
+            _shutdown: shutdown,
+        })
+        .add_system(process_remote_requests.in_base_set(CoreSet::Last));
+    }
+}
+
+/// The server started by the [`RemotePlugin`].
+#[derive(Resource)]
+pub struct RemoteServer {
+    local_addr: SocketAddr,
+    requests: Receiver<PendingRequest>,
+    /// Stops the server when dropped.
+    _shutdown: Sender<()>,
+}
+
+impl RemoteServer {
+    /// The address the server is listening on.
+    pub fn local_addr(&self) -> SocketAddr {
+        self.local_addr
+    }
+}
+
+/// Processes the requests received since the last frame.
+pub fn process_remote_requests(world: &mut World) {
+    let Some(requests) = world
+        .get_resource::<RemoteServer>()
+        .map(|server| server.requests.clone())
+    else {
+        return;
+    };
+
+    while let Ok(PendingRequest { request, response }) = requests.try_recv() {
+        let result = methods::process_request(world, &request.method, request.params);
+        let id = request.id.unwrap_or(serde_json::Value::Null);
+        // The client may have disconnected in the meantime.
+        let _ = response.send(RemoteResponse::new(id, result));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{error_codes, methods, RemotePlugin, RemoteRequest, RemoteResponse, RemoteServer};
+    use bevy_app::App;
+    use bevy_ecs::prelude::*;
+    use bevy_reflect::{Reflect, TypePath};
+    use serde_json::{json, Value};
+    use std::{
+        io::{BufRead, BufReader, Write},
+        net::{TcpListener, TcpStream},
+        time::{Duration, Instant},
+    };
+
+    #[derive(Component, Reflect, Default, Debug, PartialEq)]
+    #[reflect(Component)]
+    struct Position {
+        x: f32,
+        y: f32,
+    }
+
+    #[derive(Component, Reflect, Default, Debug, PartialEq)]
+    #[reflect(Component)]
+    struct Name(String);
+
+    #[derive(Resource, Reflect, Default, Debug, PartialEq)]
+    #[reflect(Resource)]
+    struct Score(u32);
+
+    fn app() -> App {
+        let mut app = App::new();
+        app.add_plugin(RemotePlugin::default().with_port(0))
+            .register_type::<String>()
+            .register_type::<Position>()
+            .register_type::<Name>()
+            .register_type::<Score>();
+        app
+    }
+
+    /// Sends a request from a client thread, updating the app until it is answered.
+    fn request(app: &mut App, method: &str, params: Value) -> RemoteResponse {
+        let request = RemoteRequest::new(1, method, params);
+        send(app, serde_json::to_string(&request).unwrap())
+    }
+
+    /// Sends a line from a client thread, updating the app until it is answered.
+    fn send(app: &mut App, mut line: String) -> RemoteResponse {
+        let addr = app.world.resource::<RemoteServer>().local_addr();
+        let client = std::thread::spawn(move || {
+            let mut stream = TcpStream::connect(addr).unwrap();
+            line.push('\n');
+            stream.write_all(line.as_bytes()).unwrap();
+
+            let mut response = String::new();
+            BufReader::new(stream).read_line(&mut response).unwrap();
+            serde_json::from_str::<RemoteResponse>(&response).unwrap()
+        });
+        while !client.is_finished() {
+            app.update();
+        }
+        client.join().unwrap()
+    }
+
+    fn result(app: &mut App, method: &str, params: Value) -> Value {
+        let response = request(app, method, params);
+        assert_eq!(json!(1), response.id);
+        if let Some(error) = response.error {
+            panic!("{method} failed: {error:?}");
+        }
+        // A `null` result is deserialized as `None`.
+        response.result.unwrap_or(Value::Null)
+    }
+
+    #[test]
+    fn spawn_get_insert_query_despawn() {
+        let mut app = app();
+
+        let spawned = result(
+            &mut app,
+            methods::BEVY_SPAWN,
+            json!({ "components": { Position::type_path(): { "x": 1.0, "y": 2.0 } } }),
+        );
+        let entity = Entity::from_bits(spawned["entity"].as_u64().unwrap());
+        assert_eq!(
+            Some(&Position { x: 1.0, y: 2.0 }),
+            app.world.get::<Position>(entity)
+        );
+
+        result(
+            &mut app,
+            methods::BEVY_INSERT,
+            json!({
+                "entity": entity.to_bits(),
+                "components": {
+                    Position::type_path(): { "x": 3.0, "y": 4.0 },
+                    Name::type_path(): ["player"],
+                },
+            }),
+        );
+        let components = result(
+            &mut app,
+            methods::BEVY_GET,
+            json!({ "entity": entity.to_bits(), "components": [Position::type_path(), Name::type_path()] }),
+        );
+        assert_eq!(
+            json!({
+                Position::type_path(): { "x": 3.0, "y": 4.0 },
+                Name::type_path(): ["player"],
+            }),
+            components
+        );
+
+        app.world.spawn(Position::default());
+        let entities = result(
+            &mut app,
+            methods::BEVY_QUERY,
+            json!({ "components": [Name::type_path()] }),
+        );
+        assert_eq!(
+            json!([{
+                "entity": entity.to_bits(),
+                "components": { Name::type_path(): ["player"] },
+            }]),
+            entities
+        );
+
+        result(
+            &mut app,
+            methods::BEVY_REMOVE,
+            json!({ "entity": entity.to_bits(), "components": [Name::type_path()] }),
+        );
+        assert!(app.world.get::<Name>(entity).is_none());
+
+        result(
+            &mut app,
+            methods::BEVY_DESPAWN,
+            json!({ "entity": entity.to_bits() }),
+        );
+        assert!(app.world.get_entity(entity).is_none());
+    }
+
+    #[test]
+    fn resources_and_types() {
+        let mut app = app();
+
+        result(
+            &mut app,
+            methods::BEVY_INSERT_RESOURCE,
+            json!({ "resource": Score::type_path(), "value": [42] }),
+        );
+        assert_eq!(&Score(42), app.world.resource::<Score>());
+        assert_eq!(
+            json!([42]),
+            result(
+                &mut app,
+                methods::BEVY_GET_RESOURCE,
+                json!({ "resource": Score::type_path() }),
+            )
+        );
+
+        let types = result(&mut app, methods::BEVY_LIST, Value::Null);
+        let score = types
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|registration| registration["type_path"] == Score::type_path())
+            .unwrap();
+        assert_eq!(json!(false), score["component"]);
+        assert_eq!(json!(true), score["resource"]);
+    }
+
+    #[test]
+    fn errors() {
+        let mut app = app();
+
+        let response = request(&mut app, "bevy/unknown", Value::Null);
+        assert_eq!(error_codes::METHOD_NOT_FOUND, response.error.unwrap().code);
+
+        let response = request(
+            &mut app,
+            methods::BEVY_GET,
+            json!({ "entity": 12345, "components": [] }),
+        );
+        assert_eq!(error_codes::ENTITY_NOT_FOUND, response.error.unwrap().code);
+
+        let response = request(
+            &mut app,
+            methods::BEVY_SPAWN,
+            json!({ "components": { "my_game::Unknown": {} } }),
+        );
+        assert_eq!(error_codes::UNREGISTERED_TYPE, response.error.unwrap().code);
+
+        let response = request(&mut app, methods::BEVY_DESPAWN, json!({}));
+        assert_eq!(error_codes::INVALID_PARAMS, response.error.unwrap().code);
+    }
+
+    #[test]
+    fn null_id() {
+        let mut app = app();
+
+        // unlike requests without an id, requests with a `null` id are answered
+        let response = send(
+            &mut app,
+            json!({ "jsonrpc": "2.0", "id": null, "method": methods::BEVY_LIST }).to_string(),
+        );
+        assert_eq!(Value::Null, response.id);
+        assert!(response.result.is_some());
+    }
+
+    #[test]
+    fn shutdown() {
+        let app = app();
+        let addr = app.world.resource::<RemoteServer>().local_addr();
+        drop(app);
+
+        // the port is released once the app is dropped
+        let start = Instant::now();
+        while TcpListener::bind(addr).is_err() {
+            assert!(start.elapsed() < Duration::from_secs(10), "timed out");
+            std::thread::sleep(Duration::from_millis(10));
+        }
+    }
+}
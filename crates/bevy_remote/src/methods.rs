@@ -0,0 +1,410 @@
+//! The methods of the remote protocol.
+//!
+//! Entities are identified by the `u64` returned by [`Entity::to_bits`], and types by their
+//! type path, or by their type name if no type with that path is registered.
+//! Component and resource values use the format of
+//! [`TypedReflectSerializer`], and are grouped in JSON objects keyed by type path, like the
+//! values written by `ReflectSerializer`.
+
+use crate::protocol::{error_codes, RemoteError};
+use bevy_app::AppTypeRegistry;
+use bevy_ecs::{
+    entity::Entity,
+    reflect::{ReflectComponent, ReflectResource},
+    world::World,
+};
+use bevy_reflect::{
+    serde::{TypedReflectDeserializer, TypedReflectSerializer},
+    Reflect, TypeRegistration, TypeRegistry,
+};
+use serde::{de::DeserializeSeed, Deserialize};
+use serde_json::{json, Map, Value};
+
+/// Lists the registered types.
+///
+/// Takes no parameters, and returns an array of objects with the `type_path` and `short_name`
+/// of each type, and whether it is registered as a `component` and as a `resource`.
+pub const BEVY_LIST: &str = "bevy/list";
+
+/// Queries the entities which have all of the given components.
+///
+/// Takes `{ "components": [type path] }`, and returns an array of
+/// `{ "entity": entity, "components": { type path: value } }`, sorted by entity.
+pub const BEVY_QUERY: &str = "bevy/query";
+
+/// Reads components of an entity.
+///
+/// Takes `{ "entity": entity, "components": [type path] }`, and returns
+/// `{ type path: value }`.
+pub const BEVY_GET: &str = "bevy/get";
+
+/// Inserts components on an entity, replacing the values of existing ones.
+///
+/// Takes `{ "entity": entity, "components": { type path: value } }`, and returns `null`.
+pub const BEVY_INSERT: &str = "bevy/insert";
+
+/// Removes components from an entity.
+///
+/// Takes `{ "entity": entity, "components": [type path] }`, and returns `null`.
+pub const BEVY_REMOVE: &str = "bevy/remove";
+
+/// Spawns an entity with the given components.
+///
+/// Takes `{ "components": { type path: value } }`, and returns `{ "entity": entity }`.
+pub const BEVY_SPAWN: &str = "bevy/spawn";
+
+/// Despawns an entity.
+///
+/// Takes `{ "entity": entity }`, and returns `null`.
+pub const BEVY_DESPAWN: &str = "bevy/despawn";
+
+/// Reads a resource.
+///
+/// Takes `{ "resource": type path }`, and returns the value of the resource.
+pub const BEVY_GET_RESOURCE: &str = "bevy/get_resource";
+
+/// Inserts a resource, replacing its value if it exists.
+///
+/// Takes `{ "resource": type path, "value": value }`, and returns `null`.
+pub const BEVY_INSERT_RESOURCE: &str = "bevy/insert_resource";
+
+#[derive(Deserialize)]
+struct QueryParams {
+    components: Vec<String>,
+}
+
+#[derive(Deserialize)]
+struct EntityParams {
+    entity: u64,
+}
+
+#[derive(Deserialize)]
+struct EntityComponentsParams {
+    entity: u64,
+    components: Vec<String>,
+}
+
+#[derive(Deserialize)]
+struct InsertParams {
+    entity: u64,
+    components: Map<String, Value>,
+}
+
+#[derive(Deserialize)]
+struct SpawnParams {
+    components: Map<String, Value>,
+}
+
+#[derive(Deserialize)]
+struct ResourceParams {
+    resource: String,
+}
+
+#[derive(Deserialize)]
+struct InsertResourceParams {
+    resource: String,
+    value: Value,
+}
+
+/// Runs the method with the given name on `world`.
+pub(crate) fn process_request(
+    world: &mut World,
+    method: &str,
+    params: Value,
+) -> Result<Value, RemoteError> {
+    let registry = world.resource::<AppTypeRegistry>().clone();
+    let registry = registry.read();
+
+    match method {
+        BEVY_LIST => Ok(list(&registry)),
+        BEVY_QUERY => query(world, &registry, parse_params(params)?),
+        BEVY_GET => get(world, &registry, parse_params(params)?),
+        BEVY_INSERT => insert(world, &registry, parse_params(params)?),
+        BEVY_REMOVE => remove(world, &registry, parse_params(params)?),
+        BEVY_SPAWN => spawn(world, &registry, parse_params(params)?),
+        BEVY_DESPAWN => despawn(world, parse_params(params)?),
+        BEVY_GET_RESOURCE => get_resource(world, &registry, parse_params(params)?),
+        BEVY_INSERT_RESOURCE => insert_resource(world, &registry, parse_params(params)?),
+        _ => Err(RemoteError::new(
+            error_codes::METHOD_NOT_FOUND,
+            format!("unknown method `{method}`"),
+        )),
+    }
+}
+
+fn list(registry: &TypeRegistry) -> Value {
+    let mut types = registry.iter().collect::<Vec<_>>();
+    types.sort_by_key(|registration| registration.type_path());
+    types
+        .into_iter()
+        .map(|registration| {
+            json!({
+                "type_path": registration.type_path(),
+                "short_name": registration.short_name(),
+                "component": registration.data::<ReflectComponent>().is_some(),
+                "resource": registration.data::<ReflectResource>().is_some(),
+            })
+        })
+        .collect()
+}
+
+fn query(
+    world: &World,
+    registry: &TypeRegistry,
+    params: QueryParams,
+) -> Result<Value, RemoteError> {
+    let components = params
+        .components
+        .iter()
+        .map(|type_path| {
+            let registration = registration(registry, type_path)?;
+            Ok((registration, component_data(registration)?))
+        })
+        .collect::<Result<Vec<_>, RemoteError>>()?;
+
+    let mut entities = world
+        .iter_entities()
+        .filter(|entity| {
+            components
+                .iter()
+                .all(|(_, reflect_component)| reflect_component.contains(*entity))
+        })
+        .collect::<Vec<_>>();
+    entities.sort_by_key(|entity| entity.id());
+
+    entities
+        .into_iter()
+        .map(|entity| {
+            let values = components
+                .iter()
+                .map(|(registration, reflect_component)| {
+                    let value = reflect_component.reflect(entity).unwrap();
+                    Ok((
+                        registration.type_path().to_string(),
+                        serialize(value, registry)?,
+                    ))
+                })
+                .collect::<Result<Map<_, _>, RemoteError>>()?;
+            Ok(json!({
+                "entity": entity.id().to_bits(),
+                "components": values,
+            }))
+        })
+        .collect()
+}
+
+fn get(
+    world: &World,
+    registry: &TypeRegistry,
+    params: EntityComponentsParams,
+) -> Result<Value, RemoteError> {
+    let entity = Entity::from_bits(params.entity);
+    let entity_ref = world.get_entity(entity).ok_or_else(|| no_entity(entity))?;
+
+    params
+        .components
+        .iter()
+        .map(|type_path| {
+            let registration = registration(registry, type_path)?;
+            let value = component_data(registration)?
+                .reflect(entity_ref)
+                .ok_or_else(|| missing_component(entity, type_path))?;
+            Ok((
+                registration.type_path().to_string(),
+                serialize(value, registry)?,
+            ))
+        })
+        .collect::<Result<Map<_, _>, RemoteError>>()
+        .map(Value::Object)
+}
+
+fn insert(
+    world: &mut World,
+    registry: &TypeRegistry,
+    params: InsertParams,
+) -> Result<Value, RemoteError> {
+    let entity = Entity::from_bits(params.entity);
+    let components = deserialize_components(registry, params.components)?;
+    let mut entity_mut = world
+        .get_entity_mut(entity)
+        .ok_or_else(|| no_entity(entity))?;
+    for (reflect_component, value) in components {
+        reflect_component.apply_or_insert(&mut entity_mut, &*value);
+    }
+    Ok(Value::Null)
+}
+
+fn remove(
+    world: &mut World,
+    registry: &TypeRegistry,
+    params: EntityComponentsParams,
+) -> Result<Value, RemoteError> {
+    let entity = Entity::from_bits(params.entity);
+    let components = params
+        .components
+        .iter()
+        .map(|type_path| component_data(registration(registry, type_path)?))
+        .collect::<Result<Vec<_>, RemoteError>>()?;
+
+    let mut entity_mut = world
+        .get_entity_mut(entity)
+        .ok_or_else(|| no_entity(entity))?;
+    for reflect_component in components {
+        reflect_component.remove(&mut entity_mut);
+    }
+    Ok(Value::Null)
+}
+
+fn spawn(
+    world: &mut World,
+    registry: &TypeRegistry,
+    params: SpawnParams,
+) -> Result<Value, RemoteError> {
+    // Deserialize everything first, so that no entity is spawned if a value is invalid.
+    let components = deserialize_components(registry, params.components)?;
+    let entity = world.spawn_empty().id();
+    for (reflect_component, value) in components {
+        reflect_component.insert(&mut world.entity_mut(entity), &*value);
+    }
+    Ok(json!({ "entity": entity.to_bits() }))
+}
+
+fn despawn(world: &mut World, params: EntityParams) -> Result<Value, RemoteError> {
+    let entity = Entity::from_bits(params.entity);
+    if !world.despawn(entity) {
+        return Err(no_entity(entity));
+    }
+    Ok(Value::Null)
+}
+
+fn get_resource(
+    world: &World,
+    registry: &TypeRegistry,
+    params: ResourceParams,
+) -> Result<Value, RemoteError> {
+    let value = resource_data(registration(registry, &params.resource)?)?
+        .reflect(world)
+        .ok_or_else(|| {
+            RemoteError::new(
+                error_codes::MISSING_VALUE,
+                format!("the resource `{}` does not exist", params.resource),
+            )
+        })?;
+    serialize(value, registry)
+}
+
+fn insert_resource(
+    world: &mut World,
+    registry: &TypeRegistry,
+    params: InsertResourceParams,
+) -> Result<Value, RemoteError> {
+    let registration = registration(registry, &params.resource)?;
+    let reflect_resource = resource_data(registration)?;
+    let value = deserialize(registration, registry, params.value)?;
+    reflect_resource.apply_or_insert(world, &*value);
+    Ok(Value::Null)
+}
+
+/// Deserialized components, along with the type data used to insert them.
+type ComponentValues<'a> = Vec<(&'a ReflectComponent, Box<dyn Reflect>)>;
+
+fn deserialize_components(
+    registry: &TypeRegistry,
+    components: Map<String, Value>,
+) -> Result<ComponentValues<'_>, RemoteError> {
+    components
+        .into_iter()
+        .map(|(type_path, value)| {
+            let registration = registration(registry, &type_path)?;
+            Ok((
+                component_data(registration)?,
+                deserialize(registration, registry, value)?,
+            ))
+        })
+        .collect()
+}
+
+fn parse_params<T: for<'de> Deserialize<'de>>(params: Value) -> Result<T, RemoteError> {
+    serde_json::from_value(params)
+        .map_err(|error| RemoteError::new(error_codes::INVALID_PARAMS, error.to_string()))
+}
+
+fn serialize(value: &dyn Reflect, registry: &TypeRegistry) -> Result<Value, RemoteError> {
+    serde_json::to_value(TypedReflectSerializer::new(value, registry)).map_err(|error| {
+        RemoteError::new(
+            error_codes::INTERNAL_ERROR,
+            format!("failed to serialize `{}`: {error}", value.type_name()),
+        )
+    })
+}
+
+fn deserialize(
+    registration: &TypeRegistration,
+    registry: &TypeRegistry,
+    value: Value,
+) -> Result<Box<dyn Reflect>, RemoteError> {
+    TypedReflectDeserializer::new(registration, registry)
+        .deserialize(value)
+        .map_err(|error| {
+            RemoteError::new(
+                error_codes::INVALID_PARAMS,
+                format!(
+                    "failed to deserialize `{}`: {error}",
+                    registration.type_path()
+                ),
+            )
+        })
+}
+
+fn registration<'a>(
+    registry: &'a TypeRegistry,
+    type_path: &str,
+) -> Result<&'a TypeRegistration, RemoteError> {
+    registry
+        .get_with_type_path(type_path)
+        .or_else(|| registry.get_with_name(type_path))
+        .ok_or_else(|| {
+            RemoteError::new(
+                error_codes::UNREGISTERED_TYPE,
+                format!("`{type_path}` is not registered"),
+            )
+        })
+}
+
+fn component_data(registration: &TypeRegistration) -> Result<&ReflectComponent, RemoteError> {
+    registration.data::<ReflectComponent>().ok_or_else(|| {
+        RemoteError::new(
+            error_codes::UNREGISTERED_TYPE,
+            format!(
+                "`{}` is not registered with `ReflectComponent`",
+                registration.type_path()
+            ),
+        )
+    })
+}
+
+fn resource_data(registration: &TypeRegistration) -> Result<&ReflectResource, RemoteError> {
+    registration.data::<ReflectResource>().ok_or_else(|| {
+        RemoteError::new(
+            error_codes::UNREGISTERED_TYPE,
+            format!(
+                "`{}` is not registered with `ReflectResource`",
+                registration.type_path()
+            ),
+        )
+    })
+}
+
+fn no_entity(entity: Entity) -> RemoteError {
+    RemoteError::new(
+        error_codes::ENTITY_NOT_FOUND,
+        format!("entity {entity:?} does not exist"),
+    )
+}
+
+fn missing_component(entity: Entity, type_path: &str) -> RemoteError {
+    RemoteError::new(
+        error_codes::MISSING_VALUE,
+        format!("entity {entity:?} does not have the component `{type_path}`"),
+    )
+}
@@ -0,0 +1,126 @@
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::Value;
+
+/// The version of JSON-RPC implemented by the remote protocol.
+pub const JSONRPC_VERSION: &str = "2.0";
+
+/// A request sent by a remote client.
+///
+/// Requests are JSON objects sent on a single line, following the
+/// [JSON-RPC 2.0](https://www.jsonrpc.org/specification) specification.
+/// Requests without an `id` are notifications, and are not answered. Requests with a `null` id
+/// are still answered.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct RemoteRequest {
+    /// The JSON-RPC version, which must be `"2.0"`.
+    pub jsonrpc: String,
+    /// The identifier of the request, echoed back in the response.
+    ///
+    /// This is `None` if the `id` is missing, and `Some(Value::Null)` if it is `null`.
+    #[serde(
+        default,
+        deserialize_with = "deserialize_id",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub id: Option<Value>,
+    /// The name of the method to call, such as `bevy/get`.
+    pub method: String,
+    /// The parameters of the method.
+    #[serde(default, skip_serializing_if = "Value::is_null")]
+    pub params: Value,
+}
+
+impl RemoteRequest {
+    /// Creates a new request for `method` with the given `id` and `params`.
+    pub fn new(id: impl Into<Value>, method: impl Into<String>, params: Value) -> Self {
+        Self {
+            jsonrpc: JSONRPC_VERSION.to_string(),
+            id: Some(id.into()),
+            method: method.into(),
+            params,
+        }
+    }
+}
+
+/// Deserializes a present `id`, keeping `null` ids apart from missing ones.
+fn deserialize_id<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<Value>, D::Error> {
+    Value::deserialize(deserializer).map(Some)
+}
+
+/// The response to a [`RemoteRequest`].
+///
+/// Exactly one of `result` and `error` is set.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct RemoteResponse {
+    /// The JSON-RPC version, which is always `"2.0"`.
+    pub jsonrpc: String,
+    /// The identifier of the request this is a response to.
+    ///
+    /// This is `null` if the request could not be parsed.
+    pub id: Value,
+    /// The result of a successful request.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub result: Option<Value>,
+    /// The error of a failed request.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<RemoteError>,
+}
+
+impl RemoteResponse {
+    /// Creates a response for the request with the given `id` from the outcome of the request.
+    pub fn new(id: Value, outcome: Result<Value, RemoteError>) -> Self {
+        let (result, error) = match outcome {
+            Ok(result) => (Some(result), None),
+            Err(error) => (None, Some(error)),
+        };
+        Self {
+            jsonrpc: JSONRPC_VERSION.to_string(),
+            id,
+            result,
+            error,
+        }
+    }
+}
+
+/// An error returned in a [`RemoteResponse`].
+///
+/// See [`error_codes`] for the codes used by the remote protocol.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct RemoteError {
+    /// The kind of error.
+    pub code: i64,
+    /// A description of the error.
+    pub message: String,
+}
+
+impl RemoteError {
+    /// Creates a new error with the given code and message.
+    pub fn new(code: i64, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+        }
+    }
+}
+
+/// The error codes used in [`RemoteError`]s.
+///
+/// Codes from `-32768` to `-32000` are reserved by JSON-RPC.
+pub mod error_codes {
+    /// The request is not valid JSON.
+    pub const PARSE_ERROR: i64 = -32700;
+    /// The request is not a valid JSON-RPC request.
+    pub const INVALID_REQUEST: i64 = -32600;
+    /// The method does not exist.
+    pub const METHOD_NOT_FOUND: i64 = -32601;
+    /// The parameters of the method are invalid.
+    pub const INVALID_PARAMS: i64 = -32602;
+    /// The request could not be processed.
+    pub const INTERNAL_ERROR: i64 = -32603;
+    /// The entity does not exist.
+    pub const ENTITY_NOT_FOUND: i64 = -23401;
+    /// The type is not registered, or lacks the type data needed for the method.
+    pub const UNREGISTERED_TYPE: i64 = -23402;
+    /// The entity does not have the component, or the resource does not exist.
+    pub const MISSING_VALUE: i64 = -23403;
+}
@@ -0,0 +1,134 @@
+use crate::protocol::{error_codes, RemoteError, RemoteRequest, RemoteResponse, JSONRPC_VERSION};
+use bevy_utils::tracing::{debug, warn};
+use crossbeam_channel::{Receiver, Sender, TryRecvError};
+use serde_json::Value;
+use std::{
+    io::{BufRead, BufReader, ErrorKind, Write},
+    net::{TcpListener, TcpStream},
+    thread,
+    time::Duration,
+};
+
+/// A request waiting to be processed by the app, along with the channel to answer it on.
+pub(crate) struct PendingRequest {
+    pub request: RemoteRequest,
+    pub response: Sender<RemoteResponse>,
+}
+
+/// How long the listener waits between checks for new connections.
+const ACCEPT_INTERVAL: Duration = Duration::from_millis(10);
+
+/// Accepts connections on `listener`, handling each one on its own thread.
+///
+/// The listener is closed, releasing its port, once `shutdown` is disconnected by dropping the
+/// [`RemoteServer`](crate::RemoteServer). Connections are closed on their next request once the
+/// app has been dropped.
+pub(crate) fn listen(
+    listener: TcpListener,
+    requests: Sender<PendingRequest>,
+    shutdown: Receiver<()>,
+) {
+    if let Err(error) = listener.set_nonblocking(true) {
+        warn!("failed to start the remote protocol server: {error}");
+        return;
+    }
+    loop {
+        match listener.accept() {
+            Ok((stream, _)) => {
+                // Accepted streams may inherit the non-blocking mode of the listener.
+                if let Err(error) = stream.set_nonblocking(false) {
+                    warn!("failed to accept remote connection: {error}");
+                    continue;
+                }
+                let requests = requests.clone();
+                thread::spawn(move || handle_connection(stream, requests));
+            }
+            Err(error) if error.kind() == ErrorKind::WouldBlock => {
+                if let Err(TryRecvError::Disconnected) = shutdown.try_recv() {
+                    break;
+                }
+                thread::sleep(ACCEPT_INTERVAL);
+            }
+            Err(error) => warn!("failed to accept remote connection: {error}"),
+        }
+    }
+}
+
+/// Answers the newline-delimited requests of a client until it disconnects.
+fn handle_connection(stream: TcpStream, requests: Sender<PendingRequest>) {
+    let peer = stream.peer_addr().ok();
+    debug!("remote client {peer:?} connected");
+
+    let Ok(mut writer) = stream.try_clone() else {
+        return;
+    };
+    for line in BufReader::new(stream).lines() {
+        let Ok(line) = line else {
+            break;
+        };
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let response = match parse_request(&line) {
+            Ok(request) => {
+                let is_notification = request.id.is_none();
+                let (response_sender, response_receiver) = crossbeam_channel::bounded(1);
+                let pending = PendingRequest {
+                    request,
+                    response: response_sender,
+                };
+                // The app has been dropped.
+                if requests.send(pending).is_err() {
+                    break;
+                }
+                let Ok(response) = response_receiver.recv() else {
+                    break;
+                };
+                if is_notification {
+                    continue;
+                }
+                response
+            }
+            Err(response) => response,
+        };
+
+        let mut response =
+            serde_json::to_string(&response).expect("remote responses can always be serialized");
+        response.push('\n');
+        if writer.write_all(response.as_bytes()).is_err() {
+            break;
+        }
+    }
+
+    debug!("remote client {peer:?} disconnected");
+}
+
+fn parse_request(line: &str) -> Result<RemoteRequest, RemoteResponse> {
+    let value = serde_json::from_str::<Value>(line).map_err(|error| {
+        RemoteResponse::new(
+            Value::Null,
+            Err(RemoteError::new(
+                error_codes::PARSE_ERROR,
+                error.to_string(),
+            )),
+        )
+    })?;
+    let id = value.get("id").cloned().unwrap_or(Value::Null);
+    let invalid_request = |message: String| {
+        RemoteResponse::new(
+            id.clone(),
+            Err(RemoteError::new(error_codes::INVALID_REQUEST, message)),
+        )
+    };
+
+    let request = serde_json::from_value::<RemoteRequest>(value)
+        .map_err(|error| invalid_request(error.to_string()))?;
+    if request.jsonrpc != JSONRPC_VERSION {
+        return Err(invalid_request(format!(
+            "unsupported JSON-RPC version `{}`",
+            request.jsonrpc
+        )));
+    }
+    Ok(request)
+}
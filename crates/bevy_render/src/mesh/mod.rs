@@ -1,9 +1,11 @@
 #[allow(clippy::module_inception)]
 mod mesh;
+mod ron_mesh;
 /// Generation for some primitive shape meshes.
 pub mod shape;
 
 pub use mesh::*;
+pub use ron_mesh::*;
 
 use crate::render_asset::RenderAssetPlugin;
 use bevy_app::{App, Plugin};
@@ -16,6 +18,8 @@ pub struct MeshPlugin;
 impl Plugin for MeshPlugin {
     fn build(&self, app: &mut App) {
         app.add_asset::<Mesh>()
+            .init_asset_loader::<RonMeshLoader>()
+            .add_asset_saver(RonMeshSaver)
             .add_asset::<skinning::SkinnedMeshInverseBindposes>()
             .register_type::<skinning::SkinnedMesh>()
             .register_type::<Vec<Entity>>()
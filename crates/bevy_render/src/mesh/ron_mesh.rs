@@ -0,0 +1,198 @@
+use crate::mesh::{Indices, Mesh, MeshVertexAttribute, VertexAttributeValues};
+use anyhow::Result;
+use bevy_asset::{AssetLoader, AssetSaver, LoadContext, LoadedAsset};
+use bevy_utils::BoxedFuture;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use wgpu::{PrimitiveTopology, VertexFormat};
+
+/// The vertex attributes which can be saved, identified by their name in `.mesh.ron` files.
+const ATTRIBUTES: [MeshVertexAttribute; 7] = [
+    Mesh::ATTRIBUTE_POSITION,
+    Mesh::ATTRIBUTE_NORMAL,
+    Mesh::ATTRIBUTE_UV_0,
+    Mesh::ATTRIBUTE_TANGENT,
+    Mesh::ATTRIBUTE_COLOR,
+    Mesh::ATTRIBUTE_JOINT_WEIGHT,
+    Mesh::ATTRIBUTE_JOINT_INDEX,
+];
+
+/// An error when saving or loading a `.mesh.ron` file.
+#[derive(Error, Debug)]
+pub enum RonMeshError {
+    #[error("custom vertex attributes can't be saved")]
+    CustomAttribute,
+    #[error("unknown vertex attribute {0}")]
+    UnknownAttribute(String),
+    #[error("vertex attribute {0} has an invalid format")]
+    InvalidFormat(String),
+}
+
+/// The content of a `.mesh.ron` file.
+#[derive(Serialize, Deserialize)]
+struct SerializedMesh {
+    primitive_topology: SerializedTopology,
+    attributes: Vec<(String, VertexAttributeValues)>,
+    indices: Option<Indices>,
+}
+
+/// Mirrors [`PrimitiveTopology`], which doesn't implement the serde traits.
+#[derive(Serialize, Deserialize)]
+enum SerializedTopology {
+    PointList,
+    LineList,
+    LineStrip,
+    TriangleList,
+    TriangleStrip,
+}
+
+impl From<PrimitiveTopology> for SerializedTopology {
+    fn from(topology: PrimitiveTopology) -> Self {
+        match topology {
+            PrimitiveTopology::PointList => SerializedTopology::PointList,
+            PrimitiveTopology::LineList => SerializedTopology::LineList,
+            PrimitiveTopology::LineStrip => SerializedTopology::LineStrip,
+            PrimitiveTopology::TriangleList => SerializedTopology::TriangleList,
+            PrimitiveTopology::TriangleStrip => SerializedTopology::TriangleStrip,
+        }
+    }
+}
+
+impl From<SerializedTopology> for PrimitiveTopology {
+    fn from(topology: SerializedTopology) -> Self {
+        match topology {
+            SerializedTopology::PointList => PrimitiveTopology::PointList,
+            SerializedTopology::LineList => PrimitiveTopology::LineList,
+            SerializedTopology::LineStrip => PrimitiveTopology::LineStrip,
+            SerializedTopology::TriangleList => PrimitiveTopology::TriangleList,
+            SerializedTopology::TriangleStrip => PrimitiveTopology::TriangleStrip,
+        }
+    }
+}
+
+/// Saves meshes as `.mesh.ron` files, which can be loaded back with the [`RonMeshLoader`].
+///
+/// Only the vertex attributes defined on [`Mesh`], such as [`Mesh::ATTRIBUTE_POSITION`], can be
+/// saved.
+#[derive(Clone, Default)]
+pub struct RonMeshSaver;
+
+impl AssetSaver for RonMeshSaver {
+    type Asset = Mesh;
+
+    fn save(&self, mesh: &Mesh) -> Result<Vec<u8>> {
+        let attributes = mesh
+            .attributes()
+            .map(|(id, values)| {
+                let attribute = ATTRIBUTES
+                    .iter()
+                    .find(|attribute| attribute.id == id)
+                    .ok_or(RonMeshError::CustomAttribute)?;
+                Ok((attribute.name.to_string(), values.clone()))
+            })
+            .collect::<Result<_, RonMeshError>>()?;
+        let serialized = SerializedMesh {
+            primitive_topology: mesh.primitive_topology().into(),
+            attributes,
+            indices: mesh.indices().cloned(),
+        };
+        let ron = ron::ser::to_string_pretty(&serialized, ron::ser::PrettyConfig::default())?;
+        Ok(ron.into_bytes())
+    }
+
+    fn extension(&self) -> &str {
+        "mesh.ron"
+    }
+}
+
+/// Loads the `.mesh.ron` files written by the [`RonMeshSaver`].
+#[derive(Clone, Default)]
+pub struct RonMeshLoader;
+
+impl AssetLoader for RonMeshLoader {
+    fn load<'a>(
+        &'a self,
+        bytes: &'a [u8],
+        load_context: &'a mut LoadContext,
+    ) -> BoxedFuture<'a, Result<()>> {
+        Box::pin(async move {
+            load_context.set_default_asset(LoadedAsset::new(deserialize_mesh(bytes)?));
+            Ok(())
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["mesh.ron"]
+    }
+}
+
+fn deserialize_mesh(bytes: &[u8]) -> Result<Mesh> {
+    let serialized: SerializedMesh = ron::de::from_bytes(bytes)?;
+    let mut mesh = Mesh::new(serialized.primitive_topology.into());
+    for (name, values) in serialized.attributes {
+        let attribute = ATTRIBUTES
+            .iter()
+            .find(|attribute| attribute.name == name)
+            .ok_or_else(|| RonMeshError::UnknownAttribute(name.clone()))?;
+        // `insert_attribute` panics on mismatched formats
+        if attribute.format != VertexFormat::from(&values) {
+            return Err(RonMeshError::InvalidFormat(name).into());
+        }
+        mesh.insert_attribute(attribute.clone(), values);
+    }
+    mesh.set_indices(serialized.indices);
+    Ok(mesh)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{deserialize_mesh, RonMeshSaver};
+    use crate::mesh::{shape, Mesh, MeshVertexAttribute, VertexAttributeValues};
+    use bevy_asset::AssetSaver;
+    use wgpu::{PrimitiveTopology, VertexFormat};
+
+    #[test]
+    fn save_and_load_mesh() {
+        let mut mesh = Mesh::from(shape::Cube::default());
+        mesh.insert_attribute(
+            Mesh::ATTRIBUTE_JOINT_INDEX,
+            VertexAttributeValues::Uint16x4(vec![[0, 1, 2, 3]; mesh.count_vertices()]),
+        );
+        let bytes = RonMeshSaver.save(&mesh).unwrap();
+        let loaded = deserialize_mesh(&bytes).unwrap();
+
+        assert_eq!(mesh.primitive_topology(), loaded.primitive_topology());
+        assert_eq!(
+            mesh.get_index_buffer_bytes(),
+            loaded.get_index_buffer_bytes()
+        );
+        assert_eq!(
+            mesh.get_mesh_vertex_buffer_layout(),
+            loaded.get_mesh_vertex_buffer_layout()
+        );
+        assert_eq!(
+            mesh.get_vertex_buffer_data(),
+            loaded.get_vertex_buffer_data()
+        );
+    }
+
+    #[test]
+    fn save_custom_attribute() {
+        const ATTRIBUTE_CUSTOM: MeshVertexAttribute =
+            MeshVertexAttribute::new("Vertex_Custom", 1000, VertexFormat::Float32);
+
+        let mut mesh = Mesh::new(PrimitiveTopology::PointList);
+        mesh.insert_attribute(ATTRIBUTE_CUSTOM, vec![1.0]);
+        assert!(RonMeshSaver.save(&mesh).is_err());
+    }
+
+    #[test]
+    fn load_invalid_format() {
+        let bytes = b"(
+            primitive_topology: PointList,
+            attributes: [(\"Vertex_Position\", Float32([1.0]))],
+            indices: None,
+        )";
+        assert!(deserialize_mesh(bytes).is_err());
+    }
+}
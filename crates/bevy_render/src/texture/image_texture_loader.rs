@@ -2,11 +2,12 @@ use anyhow::Result;
 use bevy_asset::{AssetLoader, LoadContext, LoadedAsset};
 use bevy_ecs::prelude::{FromWorld, World};
 use bevy_utils::BoxedFuture;
+use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
 use crate::{
     renderer::RenderDevice,
-    texture::{Image, ImageType, TextureError},
+    texture::{Image, ImageSampler, ImageType, TextureError},
 };
 
 use super::CompressedImageFormats;
@@ -36,6 +37,56 @@ const FILE_EXTENSIONS: &[&str] = &[
     "ktx2",
 ];
 
+/// Settings of the [`ImageTextureLoader`], read from the `.meta` file of an image.
+///
+/// ```ron
+/// (
+///     settings: (
+///         is_srgb: false,
+///         sampler: Nearest,
+///     ),
+/// )
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(default)]
+pub struct ImageLoaderSettings {
+    /// Whether the image holds sRGB colors, rather than linear data such as normals.
+    pub is_srgb: bool,
+    /// The sampler to use for the image.
+    pub sampler: ImageLoaderSampler,
+}
+
+impl Default for ImageLoaderSettings {
+    fn default() -> Self {
+        Self {
+            is_srgb: true,
+            sampler: ImageLoaderSampler::Default,
+        }
+    }
+}
+
+/// The sampler of an image in its [`ImageLoaderSettings`].
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ImageLoaderSampler {
+    /// Uses [`ImageSampler::Default`].
+    #[default]
+    Default,
+    /// Uses [`ImageSampler::linear`].
+    Linear,
+    /// Uses [`ImageSampler::nearest`].
+    Nearest,
+}
+
+impl From<ImageLoaderSampler> for ImageSampler {
+    fn from(sampler: ImageLoaderSampler) -> Self {
+        match sampler {
+            ImageLoaderSampler::Default => ImageSampler::Default,
+            ImageLoaderSampler::Linear => ImageSampler::linear(),
+            ImageLoaderSampler::Nearest => ImageSampler::nearest(),
+        }
+    }
+}
+
 impl AssetLoader for ImageTextureLoader {
     fn load<'a>(
         &'a self,
@@ -44,18 +95,25 @@ impl AssetLoader for ImageTextureLoader {
     ) -> BoxedFuture<'a, Result<()>> {
         Box::pin(async move {
             // use the file extension for the image type
-            let ext = load_context.path().extension().unwrap().to_str().unwrap();
+            let ext = load_context
+                .processed_path()
+                .extension()
+                .unwrap()
+                .to_str()
+                .unwrap();
+            let settings = load_context.settings::<ImageLoaderSettings>()?;
 
-            let dyn_img = Image::from_buffer(
+            let mut dyn_img = Image::from_buffer(
                 bytes,
                 ImageType::Extension(ext),
                 self.supported_compressed_formats,
-                true,
+                settings.is_srgb,
             )
             .map_err(|err| FileTextureError {
                 error: err,
                 path: format!("{}", load_context.path().display()),
             })?;
+            dyn_img.sampler_descriptor = settings.sampler.into();
 
             load_context.set_default_asset(LoadedAsset::new(dyn_img));
             Ok(())
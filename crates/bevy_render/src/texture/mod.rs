@@ -12,6 +12,8 @@ mod image;
 mod image_texture_loader;
 #[cfg(feature = "ktx2")]
 mod ktx2;
+#[cfg(feature = "png")]
+mod png_texture_saver;
 mod texture_cache;
 
 pub(crate) mod image_texture_conversion;
@@ -25,6 +27,8 @@ pub use dds::*;
 pub use exr_texture_loader::*;
 #[cfg(feature = "hdr")]
 pub use hdr_texture_loader::*;
+#[cfg(feature = "png")]
+pub use png_texture_saver::*;
 
 pub use fallback_image::*;
 pub use image_texture_loader::*;
@@ -99,6 +103,10 @@ impl Plugin for ImagePlugin {
         .register_type::<Image>()
         .add_asset::<Image>()
         .register_asset_reflect::<Image>();
+        #[cfg(feature = "png")]
+        {
+            app.add_asset_saver(PngTextureSaver);
+        }
         app.world
             .resource_mut::<Assets<Image>>()
             .set_untracked(DEFAULT_IMAGE_HANDLE, Image::default());
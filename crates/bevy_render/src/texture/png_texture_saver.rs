@@ -0,0 +1,28 @@
+use crate::texture::Image;
+use anyhow::Result;
+use bevy_asset::AssetSaver;
+use std::io::Cursor;
+
+/// Saves images as PNG files.
+///
+/// The image is converted with [`Image::try_into_dynamic`], so only the formats supported by
+/// this conversion can be saved.
+#[derive(Clone, Default)]
+pub struct PngTextureSaver;
+
+impl AssetSaver for PngTextureSaver {
+    type Asset = Image;
+
+    fn save(&self, image: &Image) -> Result<Vec<u8>> {
+        let mut bytes = Cursor::new(Vec::new());
+        image
+            .clone()
+            .try_into_dynamic()?
+            .write_to(&mut bytes, image::ImageOutputFormat::Png)?;
+        Ok(bytes.into_inner())
+    }
+
+    fn extension(&self) -> &str {
+        "png"
+    }
+}
@@ -3,6 +3,7 @@ mod dynamic_scene;
 mod dynamic_scene_builder;
 mod scene;
 mod scene_loader;
+mod scene_saver;
 mod scene_spawner;
 
 #[cfg(feature = "serialize")]
@@ -13,6 +14,7 @@ pub use dynamic_scene::*;
 pub use dynamic_scene_builder::*;
 pub use scene::*;
 pub use scene_loader::*;
+pub use scene_saver::*;
 pub use scene_spawner::*;
 
 pub mod prelude {
@@ -35,6 +37,7 @@ impl Plugin for ScenePlugin {
         app.add_asset::<DynamicScene>()
             .add_asset::<Scene>()
             .init_asset_loader::<SceneLoader>()
+            .init_asset_saver::<SceneSaver>()
             .init_resource::<SceneSpawner>()
             .add_system(scene_spawner_system)
             // Systems `*_bundle_spawner` must run before `scene_spawner_system`
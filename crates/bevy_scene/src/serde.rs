@@ -104,11 +104,20 @@ impl<'a> Serialize for ComponentsSerializer<'a> {
     where
         S: serde::Serializer,
     {
+        let registry = self.registry.read();
         let mut state = serializer.serialize_map(Some(self.components.len()))?;
         for component in self.components {
+            let registration = registry
+                .get_with_name(component.type_name())
+                .ok_or_else(|| {
+                    serde::ser::Error::custom(format_args!(
+                        "no registration found for component {}",
+                        component.type_name()
+                    ))
+                })?;
             state.serialize_entry(
-                component.type_name(),
-                &TypedReflectSerializer::new(&**component, &self.registry.read()),
+                registration.type_path(),
+                &TypedReflectSerializer::new(&**component, &registry),
             )?;
         }
         state.end()
@@ -53,9 +53,12 @@ impl Plugin for TimePlugin {
 pub enum TimeUpdateStrategy {
     #[default]
     Automatic,
-    // Update [`Time`] with an exact `Instant` value
+    /// Update [`Time`] with an exact `Instant` value
     ManualInstant(Instant),
-    // Update [`Time`] with the current time + a specified `Duration`
+    /// Update [`Time`] with the last update time + a specified `Duration`
+    ///
+    /// Every frame advances [`Time`] by exactly the given amount, independently of the wall clock,
+    /// which makes this the strategy of choice for deterministic tests.
     ManualDuration(Duration),
 }
 
@@ -102,7 +105,36 @@ fn time_system(
         TimeUpdateStrategy::Automatic => time.update_with_instant(new_time),
         TimeUpdateStrategy::ManualInstant(instant) => time.update_with_instant(*instant),
         TimeUpdateStrategy::ManualDuration(duration) => {
-            time.update_with_instant(Instant::now() + *duration);
+            let last_update = time.last_update().unwrap_or_else(|| time.startup());
+            time.update_with_instant(last_update + *duration);
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{Time, TimePlugin, TimeUpdateStrategy};
+    use bevy_app::ci_testing::TestApp;
+    use bevy_utils::Duration;
+
+    #[test]
+    fn manual_duration_advances_time_deterministically() {
+        let mut app = TestApp::new();
+        app.add_plugin(TimePlugin)
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+                250,
+            )));
+
+        app.step();
+        let startup = app.world.resource::<Time>().startup();
+        assert_eq!(
+            app.world.resource::<Time>().last_update(),
+            Some(startup + Duration::from_millis(250))
+        );
+
+        app.step_frames(4);
+        let time = app.world.resource::<Time>();
+        assert_eq!(time.delta(), Duration::from_millis(250));
+        assert_eq!(time.elapsed(), Duration::from_millis(1250));
+    }
+}
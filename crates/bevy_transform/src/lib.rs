@@ -92,6 +92,9 @@ impl Plugin for TransformPlugin {
     fn build(&self, app: &mut App) {
         app.register_type::<Transform>()
             .register_type::<GlobalTransform>()
+            .register_method::<Transform, _>("look_at", Transform::look_at)
+            .register_method::<Transform, _>("rotate_around", Transform::rotate_around)
+            .register_method::<Transform, _>("translate_around", Transform::translate_around)
             .add_plugin(ValidParentCheckPlugin::<GlobalTransform>::default())
             // add transform systems to startup so the first update is "correct"
             .configure_set(TransformSystem::TransformPropagate.in_base_set(CoreSet::PostUpdate))